@@ -0,0 +1,44 @@
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
+
+fn find_result(numbers: &[u32]) -> Option<u32> {
+    for i in 0..numbers.len() {
+        for j in i + 1..numbers.len() {
+            if numbers[i] + numbers[j] == 2020 {
+                return Some(numbers[i] * numbers[j]);
+            }
+        }
+    }
+    None
+}
+
+fn find_result2(numbers: &[u32]) -> Option<u32> {
+    for i in 0..numbers.len() {
+        for j in i + 1..numbers.len() {
+            for k in j + 1..numbers.len() {
+                if numbers[i] + numbers[j] + numbers[k] == 2020 {
+                    return Some(numbers[i] * numbers[j] * numbers[k]);
+                }
+            }
+        }
+    }
+    None
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<u32>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(|l| l.parse_as()).collect()
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> impl Into<Answer> {
+        find_result(numbers).unwrap()
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> impl Into<Answer> {
+        find_result2(numbers).unwrap()
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+use regex::Regex;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(usize, usize, char, String)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let re = Regex::new(r"^(\d+)-(\d+) (.): (.+)$").unwrap();
+        input
+            .lines()
+            .map(|l| {
+                let m = re.captures(l).ok_or_else(|| {
+                    ParseError::new(l, "expected `<number>-<number> <letter>: <password>`")
+                })?;
+                Ok((
+                    m[1].parse_as()?,
+                    m[2].parse_as()?,
+                    m[3].parse_as()?,
+                    m[4].to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        input
+            .iter()
+            .filter(|(b, t, c, p)| {
+                let count = p.matches(*c).count();
+                count >= *b && count <= *t
+            })
+            .count()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        input
+            .iter()
+            .filter(|(l1, l2, c, p)| {
+                let b1 = p.as_bytes()[l1 - 1] as char == *c;
+                let b2 = p.as_bytes()[l2 - 1] as char == *c;
+                b1 != b2
+            })
+            .count()
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+
+fn check_slope(map: &[Vec<bool>], slope: (usize, usize)) -> usize {
+    let map_y = map.len();
+    let map_x = map[0].len();
+    let (slope_x, slope_y) = slope;
+    (0..(map.len() / slope_y))
+        .scan((0, 0), |pos, i| {
+            *pos = (slope_x * i, slope_y * i);
+            Some(*pos)
+        })
+        .filter(|(x, y)| map[*y % map_y][*x % map_x])
+        .count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Vec<bool>>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                l.char_indices()
+                    .map(|(i, c)| match c {
+                        '#' => Ok(true),
+                        '.' => Ok(false),
+                        _ => Err(ParseError::new(&l[i..], "expected `#` or `.`")),
+                    })
+                    .collect::<ParseResult<Vec<bool>>>()
+            })
+            .collect()
+    }
+
+    fn part1(map: &Self::Input<'_>) -> impl Into<Answer> {
+        check_slope(map, (3, 1))
+    }
+
+    fn part2(map: &Self::Input<'_>) -> impl Into<Answer> {
+        [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .into_iter()
+            .map(|slope| check_slope(map, slope))
+            .reduce(|a, i| a * i)
+            .unwrap()
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, InputExt, ParseResult, Solution};
+use regex::Regex;
+
+fn check_passport(p: &str) -> bool {
+    let mut test = HashMap::from([
+        ("byr", false),
+        ("iyr", false),
+        ("eyr", false),
+        ("hgt", false),
+        ("hcl", false),
+        ("ecl", false),
+        ("pid", false),
+        ("cid", false),
+    ]);
+
+    p.split_whitespace().for_each(|t| {
+        let i: Vec<&str> = t.split(':').collect();
+        let key = i[0];
+        *test.get_mut(key).unwrap() = true;
+    });
+
+    ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
+        .into_iter()
+        .all(|i| *test.get(i).unwrap())
+}
+
+fn check_passport2(p: &str) -> bool {
+    let mut test = HashMap::from([
+        ("byr", false),
+        ("iyr", false),
+        ("eyr", false),
+        ("hgt", false),
+        ("hcl", false),
+        ("ecl", false),
+        ("pid", false),
+        ("cid", false),
+    ]);
+
+    let re_hgt = Regex::new(r"^(\d+)(in|cm)$").unwrap();
+    let re_hcl = Regex::new(r"^#[0-9a-f]{6}$").unwrap();
+    let re_pid = Regex::new(r"^[0-9]{9}$").unwrap();
+
+    p.split_whitespace().for_each(|t| {
+        let i: Vec<&str> = t.split(':').collect();
+        let key = i[0];
+        *test.get_mut(key).unwrap() = match key {
+            "byr" => {
+                let byr = i[1].parse::<u32>();
+                match byr {
+                    Ok(v) => (1920..=2002).contains(&v),
+                    _ => false,
+                }
+            }
+            "iyr" => {
+                let iyr = i[1].parse::<u32>();
+                match iyr {
+                    Ok(v) => (2010..=2020).contains(&v),
+                    _ => false,
+                }
+            }
+            "eyr" => {
+                let eyr = i[1].parse::<u32>();
+                match eyr {
+                    Ok(v) => (2020..=2030).contains(&v),
+                    _ => false,
+                }
+            }
+            "hgt" => {
+                let m = re_hgt.captures(i[1]);
+                match m {
+                    Some(m) => match (m.get(1), m.get(2)) {
+                        (Some(v), Some(u)) => match v.as_str().parse::<u32>() {
+                            Ok(n) => match u.as_str() {
+                                "cm" => (150..=193).contains(&n),
+                                "in" => (59..=76).contains(&n),
+                                _ => false,
+                            },
+                            _ => false,
+                        },
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+            "hcl" => re_hcl.is_match(i[1]),
+            "ecl" => matches!(i[1], "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth"),
+            "pid" => re_pid.is_match(i[1]),
+            "cid" => true,
+            _ => false,
+        };
+    });
+
+    ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
+        .into_iter()
+        .all(|i| *test.get(i).unwrap())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input.paragraphs().collect())
+    }
+
+    fn part1(v: &Self::Input<'_>) -> impl Into<Answer> {
+        v.iter().filter(|p| check_passport(p)).count()
+    }
+
+    fn part2(v: &Self::Input<'_>) -> impl Into<Answer> {
+        v.iter().filter(|p| check_passport2(p)).count()
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+use itertools::Itertools;
+
+fn find((l, u): (u32, u32), input: &[u8], (c_l, c_u): (char, char)) -> u32 {
+    if l == u {
+        return l;
+    }
+    match input[0] as char {
+        c if c == c_l => find((l, l + (u - l) / 2), &input[1..], (c_l, c_u)),
+        c if c == c_u => find((l + (u - l) / 2 + 1, u), &input[1..], (c_l, c_u)),
+        _ => panic!("invalid input"),
+    }
+}
+
+fn find_row(input: &[u8]) -> u32 {
+    find((0, 127), input, ('F', 'B'))
+}
+
+fn find_column(input: &[u8]) -> u32 {
+    find((0, 7), input, ('L', 'R'))
+}
+
+/// Checks that `pass` is 7 of `F`/`B` followed by 3 of `L`/`R`.
+fn check_pass(pass: &str) -> ParseResult<()> {
+    if pass.len() != 10 || !pass.is_ascii() {
+        return Err(ParseError::new(pass, "expected 10 letters"));
+    }
+    for (i, c) in pass.char_indices() {
+        let (a, b) = if i < 7 { ('F', 'B') } else { ('L', 'R') };
+        if c != a && c != b {
+            return Err(ParseError::new(
+                &pass[i..i + 1],
+                format!("expected `{a}` or `{b}`"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = HashSet<u32>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                check_pass(l)?;
+                let b = l.as_bytes();
+                let row = find_row(b);
+                let column = find_column(&b[7..]);
+                Ok(row * 8 + column)
+            })
+            .collect()
+    }
+
+    fn part1(ids: &Self::Input<'_>) -> impl Into<Answer> {
+        *ids.iter().max().unwrap()
+    }
+
+    fn part2(ids: &Self::Input<'_>) -> impl Into<Answer> {
+        let available_seats: HashSet<_> = (9..120)
+            .cartesian_product(0..8)
+            .map(|(r, c)| r * 8 + c)
+            .collect();
+        let free_seats: Vec<_> = available_seats.difference(ids).sorted().collect();
+        assert!(free_seats.len() == 1);
+        *free_seats[0]
+    }
+}
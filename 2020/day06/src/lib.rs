@@ -0,0 +1,35 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, InputExt, ParseResult, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input.paragraphs().collect())
+    }
+
+    fn part1(groups: &Self::Input<'_>) -> impl Into<Answer> {
+        groups
+            .iter()
+            .map(|g| g.lines().flat_map(str::bytes).collect::<HashSet<_>>().len())
+            .sum::<usize>()
+    }
+
+    fn part2(groups: &Self::Input<'_>) -> impl Into<Answer> {
+        groups
+            .iter()
+            .map(|g| {
+                let mut answers = HashMap::<u8, usize>::new();
+                for c in g.lines().flat_map(str::bytes) {
+                    *answers.entry(c).or_insert(0) += 1;
+                }
+                let people = g.lines().count();
+                answers.values().filter(|&&c| c == people).count()
+            })
+            .sum::<usize>()
+    }
+}
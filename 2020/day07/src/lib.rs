@@ -0,0 +1,82 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{scan, Answer, ParseResult, Solution};
+
+fn find_parent(
+    color: &str,
+    bag_map: &HashMap<String, HashSet<String>>,
+    parents: &mut HashSet<String>,
+) {
+    if parents.contains(color) {
+        return;
+    }
+    parents.insert(color.to_string());
+
+    if let Some(bags) = bag_map.get(color) {
+        bags.iter().for_each(|b| {
+            find_parent(b, bag_map, parents);
+        });
+    }
+}
+
+fn count_children(color: &str, bag_map: &HashMap<String, Vec<(u32, String)>>) -> u32 {
+    bag_map
+        .get(color)
+        .unwrap()
+        .iter()
+        .fold(0u32, |acc, (count, color)| {
+            acc + count + count * count_children(color, bag_map)
+        })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (
+        HashMap<String, HashSet<String>>,
+        HashMap<String, Vec<(u32, String)>>,
+    );
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let mut bag_map = HashMap::<String, HashSet<String>>::new();
+        let mut bag_map2 = HashMap::<String, Vec<(u32, String)>>::new();
+        for l in input.lines() {
+            let (bag, contents) = scan!(l, "{} bags contain {}." => &str, &str)?;
+            let bags = match contents {
+                "no other bags" => vec![],
+                _ => contents
+                    .split(", ")
+                    .map(|s| {
+                        let (count, color, _) = scan!(s, "{} {} bag{}" => u32, String, &str)?;
+                        Ok((count, color))
+                    })
+                    .collect::<ParseResult<Vec<_>>>()?,
+            };
+            bag_map2.insert(bag.to_string(), bags.clone());
+
+            bags.iter()
+                .for_each(|(_, c)| match bag_map.get_mut(c.as_str()) {
+                    Some(s) => {
+                        s.insert(bag.to_string());
+                    }
+                    None => {
+                        let mut s = HashSet::<String>::new();
+                        s.insert(bag.to_string());
+                        bag_map.insert(c.to_string(), s);
+                    }
+                });
+        }
+        Ok((bag_map, bag_map2))
+    }
+
+    fn part1((bag_map, _): &Self::Input<'_>) -> impl Into<Answer> {
+        let mut bags = HashSet::<String>::new();
+        find_parent("shiny gold", bag_map, &mut bags);
+        bags.len() - 1
+    }
+
+    fn part2((_, bag_map2): &Self::Input<'_>) -> impl Into<Answer> {
+        count_children("shiny gold", bag_map2)
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+struct Program {
+    instructions: Vec<Instruction>,
+    acc: i32,
+    idx: usize,
+}
+
+impl Program {
+    pub fn new(instructions: Vec<Instruction>) -> Program {
+        Program {
+            instructions,
+            acc: 0,
+            idx: 0,
+        }
+    }
+
+    pub fn execute(&mut self) {
+        match self.instructions[self.idx] {
+            Instruction::Acc(arg) => {
+                self.acc += arg;
+                self.idx += 1;
+            }
+            Instruction::Jmp(arg) => {
+                self.idx = (self.idx as i32 + arg) as usize;
+            }
+            Instruction::Nop(_) => {
+                self.idx += 1;
+            }
+        }
+    }
+
+    pub fn detect_loop(&mut self) -> bool {
+        let mut visited = vec![false; self.instructions.len()];
+        while self.idx < self.instructions.len() {
+            if visited[self.idx] {
+                return true;
+            }
+            visited[self.idx] = true;
+            self.execute();
+        }
+        false
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Instruction>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                let (op, arg) = l.split_once_or_err(" ")?;
+                let arg = arg.parse_as::<i32>()?;
+                match op {
+                    "acc" => Ok(Instruction::Acc(arg)),
+                    "jmp" => Ok(Instruction::Jmp(arg)),
+                    "nop" => Ok(Instruction::Nop(arg)),
+                    _ => Err(ParseError::new(op, "expected `acc`, `jmp` or `nop`")),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut program = Program::new(instructions.clone());
+        program.detect_loop();
+        program.acc
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> impl Into<Answer> {
+        (0..instructions.len())
+            .filter(|&i| matches!(instructions[i], Instruction::Jmp(_) | Instruction::Nop(_)))
+            .map(|i| {
+                let mut instructions = instructions.clone();
+                instructions[i] = match instructions[i] {
+                    Instruction::Jmp(arg) => Instruction::Nop(arg),
+                    Instruction::Nop(arg) => Instruction::Jmp(arg),
+                    _ => panic!("invalid instruction"),
+                };
+                instructions
+            })
+            .find_map(|i| {
+                let mut p = Program::new(i);
+                if !p.detect_loop() {
+                    Some(p.acc)
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use aoc_common::{params, Answer, ParseExt, ParseResult, Solution};
+
+/// The first number after the preamble which is not the sum of two different
+/// numbers among the `preamble` before it.
+fn find_invalid(values: &[i64], preamble: usize) -> Option<i64> {
+    values.windows(preamble + 1).find_map(|w| {
+        let (previous, x) = (&w[..preamble], w[preamble]);
+        let h = previous.iter().collect::<HashSet<_>>();
+        let is_sum = previous.iter().any(|&a| a != x - a && h.contains(&(x - a)));
+        (!is_sum).then_some(x)
+    })
+}
+
+/// The contiguous range of at least two numbers summing to `target`, found
+/// with a window sliding over the numbers, which are all positive.
+fn find_range(values: &[i64], target: i64) -> Option<&[i64]> {
+    let (mut start, mut sum) = (0, 0);
+    for (end, &x) in values.iter().enumerate() {
+        sum += x;
+        while sum > target && start < end {
+            sum -= values[start];
+            start += 1;
+        }
+        if sum == target && end > start {
+            return Some(&values[start..=end]);
+        }
+    }
+    None
+}
+
+params! {
+    pub struct Params {
+        /// Number of values each number may be the sum of two of
+        pub preamble: usize = 25,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<i64>, Params);
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> ParseResult<Self::Input<'a>> {
+        let values = input
+            .lines()
+            .map(|l| l.parse_as())
+            .collect::<ParseResult<_>>()?;
+        Ok((values, *params))
+    }
+
+    fn part1((values, params): &Self::Input<'_>) -> impl Into<Answer> {
+        find_invalid(values, params.preamble).expect("some number should not be a sum of two")
+    }
+
+    fn part2((values, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let invalid =
+            find_invalid(values, params.preamble).expect("some number should not be a sum of two");
+        let range = find_range(values, invalid).expect("a range should sum to the invalid number");
+        range.iter().min().unwrap() + range.iter().max().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(find_invalid(&[1, 2, 3, 5, 8], 2), None);
+        assert_eq!(find_invalid(&[1, 2, 3, 5, 9], 2), Some(9));
+        // The two numbers of a sum have to be different.
+        assert_eq!(find_invalid(&[3, 1, 6], 2), Some(6));
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(find_range(&[10, 1, 2, 3], 5), Some(&[2, 3][..]));
+        // A single number is not a range.
+        assert_eq!(find_range(&[5, 1, 4], 5), Some(&[1, 4][..]));
+        assert_eq!(find_range(&[1, 5], 5), None);
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
+use itertools::Itertools;
+
+fn keep_max3(mut v: Vec<u32>, i: u32) -> Vec<u32> {
+    let idx = v.binary_search(&i).unwrap_or_else(|idx| idx);
+    v.insert(idx, i);
+    if v.len() > 3 {
+        v.remove(0);
+    }
+    v
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<u32>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let calories = input
+            .lines()
+            .map(|l| if l.is_empty() { Ok(0) } else { l.parse_as() })
+            .collect::<ParseResult<Vec<u32>>>()?;
+        Ok(calories
+            .into_iter()
+            .coalesce(|a, b| if b > 0 { Ok(a + b) } else { Err((a, 0)) })
+            .fold(Vec::<u32>::with_capacity(4), keep_max3))
+    }
+
+    fn part1(top3: &Self::Input<'_>) -> impl Into<Answer> {
+        *top3.last().unwrap()
+    }
+
+    fn part2(top3: &Self::Input<'_>) -> impl Into<Answer> {
+        top3.iter().sum::<u32>()
+    }
+}
@@ -1,4 +1,4 @@
-use std::io;
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
 
 // opponent: A for Rock, B for Paper, and C for Scissors
 // myself:   X for Rock, Y for Paper, and Z for Scissors
@@ -63,17 +63,41 @@ fn points_for_round2(opponent: char, result: char) -> u32 {
     points_for_outcome(a, b) + points_for_shape(b)
 }
 
-fn main() {
-    let (r1, r2) = io::stdin()
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .split(' ')
-                .map(|s| s.chars().next().unwrap())
-                .collect()
-        })
-        .map(|t: Vec<char>| (points_for_round(t[0], t[1]), points_for_round2(t[0], t[1])))
-        .fold((0, 0), |a, c| (a.0 + c.0, a.1 + c.1));
-    println!("{}", r1);
-    println!("{}", r2);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(char, char)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                let (a, b) = l.split_once_or_err(" ")?;
+                let a = a.parse_as::<char>()?;
+                let b = b.parse_as::<char>()?;
+                if !('A'..='C').contains(&a) {
+                    return Err(ParseError::new(l, "expected `A`, `B` or `C`"));
+                }
+                if !('X'..='Z').contains(&b) {
+                    return Err(ParseError::new(&l[2..], "expected `X`, `Y` or `Z`"));
+                }
+                Ok((a, b))
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input<'_>) -> impl Into<Answer> {
+        rounds
+            .iter()
+            .map(|&(a, b)| points_for_round(a, b))
+            .sum::<u32>()
+    }
+
+    fn part2(rounds: &Self::Input<'_>) -> impl Into<Answer> {
+        rounds
+            .iter()
+            .map(|&(a, b)| points_for_round2(a, b))
+            .sum::<u32>()
+    }
 }
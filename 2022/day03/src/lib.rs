@@ -0,0 +1,75 @@
+use aoc_common::{Answer, ParseResult, Solution};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+fn item_priority(i: char) -> u32 {
+    let c = i as u32;
+    if c >= 'a' as u32 {
+        c - 'a' as u32 + 1
+    } else {
+        c - 'A' as u32 + 27
+    }
+}
+
+fn find_common_items(a: &str, b: &str) -> HashSet<char> {
+    let r1 = a.chars().fold(HashSet::new(), |mut a, c| {
+        a.insert(c);
+        a
+    });
+    b.chars().fold(HashSet::new(), |mut a, c| {
+        if r1.contains(&c) {
+            a.insert(c);
+        }
+        a
+    })
+}
+
+fn note_items(items: &str) -> Vec<i32> {
+    items.chars().fold(vec![0; 52], |mut a, c| {
+        a[(item_priority(c) - 1) as usize] = 1;
+        a
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<String>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input.lines().map(|l| l.to_string()).collect_vec())
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        lines
+            .iter()
+            .map(|l| {
+                let (a, b) = l.split_at(l.len() / 2);
+                find_common_items(a, b)
+                    .iter()
+                    .map(|xx| item_priority(*xx))
+                    .sum::<u32>()
+            })
+            .sum::<u32>()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        let chunks = &lines.iter().chunks(3);
+        chunks
+            .into_iter()
+            .map(|chunks| {
+                let zz = chunks
+                    .map(|c| note_items(c))
+                    .reduce(|a, b| {
+                        a.iter()
+                            .zip(b.iter())
+                            .map(|(ia, ib)| *ia + *ib)
+                            .collect_vec()
+                    })
+                    .unwrap();
+                (zz.iter().position(|i| *i == 3).unwrap() + 1) as u32
+            })
+            .sum::<u32>()
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_common::{Answer, Interval, ParseExt, ParseResult, Solution};
+
+type Section = Interval<u8>;
+
+fn parse_sections(s: &str) -> ParseResult<Section> {
+    let (a, b) = s.split_once_or_err("-")?;
+    Ok(Interval::inclusive(a.parse_as()?, b.parse_as()?))
+}
+
+fn sections_overlap_fully(s1: Section, s2: Section) -> bool {
+    s1.contains_interval(&s2) || s2.contains_interval(&s1)
+}
+
+fn sections_overlap_at_all(s1: Section, s2: Section) -> bool {
+    s1.overlaps(&s2)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(Section, Section)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                let (a, b) = l.split_once_or_err(",")?;
+                Ok((parse_sections(a)?, parse_sections(b)?))
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> impl Into<Answer> {
+        pairs
+            .iter()
+            .filter(|&&(s1, s2)| sections_overlap_fully(s1, s2))
+            .count()
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> impl Into<Answer> {
+        pairs
+            .iter()
+            .filter(|&&(s1, s2)| sections_overlap_at_all(s1, s2))
+            .count()
+    }
+}
@@ -0,0 +1,101 @@
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+type Stack = Vec<char>;
+
+fn parse_config_line(s: &str, stack_count: usize) -> ParseResult<Vec<char>> {
+    (0..stack_count)
+        .map(|n| 4 * n + 1)
+        .map(|idx| {
+            s.chars()
+                .nth(idx)
+                .ok_or_else(|| ParseError::after(s, format!("expected {stack_count} stacks")))
+        })
+        .collect()
+}
+
+fn parse_procedure_line(s: &str) -> ParseResult<(usize, usize, usize)> {
+    let (count, rest) = s
+        .strip_prefix_or_err("move ")?
+        .split_once_or_err(" from ")?;
+    let (from, to) = rest.split_once_or_err(" to ")?;
+    let stack = |t: &str| match t.parse_as::<usize>()? {
+        0 => Err(ParseError::new(t, "stacks are numbered from 1")),
+        n => Ok(n - 1),
+    };
+    Ok((count.parse_as()?, stack(from)?, stack(to)?))
+}
+
+fn rearrange(stacks: &mut [Stack], count: usize, from_idx: usize, to_idx: usize) {
+    (0..count).for_each(|_| {
+        let c = stacks[from_idx].pop().unwrap();
+        stacks[to_idx].push(c);
+    });
+}
+
+fn rearrange2(stacks: &mut [Stack], count: usize, from_idx: usize, to_idx: usize) {
+    let mut temp = Stack::new();
+    (0..count).for_each(|_| {
+        let c = stacks[from_idx].pop().unwrap();
+        temp.push(c);
+    });
+    (0..count).for_each(|_| {
+        let c = temp.pop().unwrap();
+        stacks[to_idx].push(c);
+    });
+}
+
+fn top_crates(stacks: &[Stack]) -> String {
+    stacks.iter().map(|s| s.last().unwrap()).collect::<String>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<Stack>, Vec<(usize, usize, usize)>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let idx = lines
+            .iter()
+            .position(|l| l.is_empty())
+            .filter(|&idx| idx >= 2)
+            .ok_or_else(|| ParseError::new(input, "expected stacks, then an empty line"))?;
+        let stack_count = (lines[idx - 1].len() + 1) / 4;
+
+        let stacks = (0..=(idx - 2))
+            .rev()
+            .map(|i| lines[i])
+            .map(|l| parse_config_line(l, stack_count))
+            .collect::<ParseResult<Vec<_>>>()?
+            .into_iter()
+            .fold(vec![Stack::new(); stack_count], |mut a, s| {
+                s.iter().enumerate().for_each(|(idx, c)| {
+                    if *c != ' ' {
+                        a[idx].push(*c);
+                    }
+                });
+                a
+            });
+        let procedure = ((idx + 1)..lines.len())
+            .map(|idx| parse_procedure_line(lines[idx]))
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok((stacks, procedure))
+    }
+
+    fn part1((stacks, procedure): &Self::Input<'_>) -> impl Into<Answer> {
+        let mut stacks1 = stacks.to_owned();
+        procedure.iter().for_each(|(count, from_idx, to_idx)| {
+            rearrange(&mut stacks1, *count, *from_idx, *to_idx);
+        });
+        top_crates(&stacks1)
+    }
+
+    fn part2((stacks, procedure): &Self::Input<'_>) -> impl Into<Answer> {
+        let mut stacks2 = stacks.to_owned();
+        procedure.iter().for_each(|(count, from_idx, to_idx)| {
+            rearrange2(&mut stacks2, *count, *from_idx, *to_idx);
+        });
+        top_crates(&stacks2)
+    }
+}
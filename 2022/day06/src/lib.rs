@@ -0,0 +1,44 @@
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+use itertools::{Itertools, MultiPeek};
+use std::str::Chars;
+
+fn are_characters_distinct(iter: &mut MultiPeek<Chars>, size: usize) -> bool {
+    let v = (0..size)
+        .filter_map(|_| iter.peek().copied())
+        .collect::<Vec<char>>();
+    v.len() == size && v.iter().all_unique()
+}
+
+fn find_marker(iter: &mut MultiPeek<Chars>, size: usize) -> usize {
+    let mut idx = 0;
+    while let Some(_) = iter.next() {
+        idx += 1;
+        if are_characters_distinct(iter, size) {
+            return idx + size;
+        }
+    }
+    0
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = String;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .next()
+            .map(|l| l.to_string())
+            .ok_or_else(|| ParseError::new(input, "expected a datastream"))
+    }
+
+    fn part1(buf: &Self::Input<'_>) -> impl Into<Answer> {
+        find_marker(&mut buf.chars().multipeek(), 4)
+    }
+
+    fn part2(buf: &Self::Input<'_>) -> impl Into<Answer> {
+        find_marker(&mut buf.chars().multipeek(), 14)
+    }
+}
@@ -0,0 +1,184 @@
+use std::{collections::HashMap, iter::Peekable};
+
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+enum Command<'a> {
+    ChangeDirectory(String),
+    List(Vec<&'a str>),
+}
+
+fn parse_command<'a, I>(iter: &mut Peekable<I>) -> Option<ParseResult<Command<'a>>>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let c = iter.next()?;
+    let mut data = Vec::new();
+    while let Some(s) = iter.next_if(|s| !s.starts_with("$ ")) {
+        data.push(s);
+    }
+    if c == "$ ls" {
+        Some(Ok(Command::List(data)))
+    } else if let Some(dir) = c.strip_prefix("$ cd ") {
+        Some(Ok(Command::ChangeDirectory(dir.to_string())))
+    } else {
+        Some(Err(ParseError::new(c, "expected `$ ls` or `$ cd <dir>`")))
+    }
+}
+
+fn parse_input<'a, I>(iter: &mut I) -> ParseResult<Vec<Command<'a>>>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut commands = Vec::<Command>::new();
+    let mut peekable = iter.peekable();
+    while let Some(c) = parse_command(&mut peekable) {
+        commands.push(c?);
+    }
+    Ok(commands)
+}
+
+enum Item {
+    File(u64),
+    Dir(String),
+}
+type Items = HashMap<String, Item>;
+type FileSystem = HashMap<String, Items>;
+
+#[derive(Default)]
+struct Shell {
+    filesystem: FileSystem,
+    current_path: Vec<String>,
+}
+
+impl Shell {
+    fn change_dir(&mut self, dir: &str) {
+        match dir {
+            "/" => {
+                self.current_path = vec![];
+                self.ensure_current_dir_exists();
+            }
+            ".." => {
+                self.current_path.pop();
+            }
+            _ => {
+                self.current_path.push(dir.to_string());
+                self.ensure_current_dir_exists();
+            }
+        }
+    }
+
+    fn ensure_current_dir_exists(&mut self) {
+        let path = self.current_dir();
+        self.ensure_dir_exists(path);
+    }
+
+    fn ensure_dir_exists(&mut self, path: String) {
+        self.filesystem.entry(path).or_default();
+    }
+
+    fn current_dir(&self) -> String {
+        format!("/{}", self.current_path.join("/"))
+    }
+
+    fn sub_dir(&self, dir: &str) -> String {
+        let cd = self.current_dir();
+        match cd.as_str() {
+            "/" => format!("/{}", dir),
+            _ => format!("{}/{}", self.current_dir(), dir),
+        }
+    }
+
+    fn add_file(&mut self, file: String, size: u64) {
+        let path = self.current_dir();
+        let dir = self.filesystem.get_mut(&path).unwrap();
+        dir.insert(file, Item::File(size));
+    }
+
+    fn add_dir(&mut self, name: String) {
+        let path = self.current_dir();
+        let sub_path = self.sub_dir(name.as_str());
+
+        self.ensure_dir_exists(sub_path.clone());
+
+        let parent = self.filesystem.get_mut(&path).unwrap();
+        parent.insert(name, Item::Dir(sub_path));
+    }
+
+    fn list(&mut self, lines: &[&str]) -> ParseResult<()> {
+        for l in lines {
+            let (t, name) = l.split_once_or_err(" ")?;
+            match t {
+                "dir" => self.add_dir(name.to_string()),
+                _ => self.add_file(name.to_string(), t.parse_as::<u64>()?),
+            }
+        }
+        Ok(())
+    }
+
+    fn execute(&mut self, c: &Command) -> ParseResult<()> {
+        match c {
+            Command::ChangeDirectory(dir) => self.change_dir(dir),
+            Command::List(list) => self.list(list)?,
+        }
+        Ok(())
+    }
+}
+
+fn dir_size(fs: &FileSystem, path: &String) -> u64 {
+    let items = fs.get(path).unwrap().values();
+    items.fold(0_u64, |a, item| {
+        let size = match item {
+            Item::File(s) => *s,
+            Item::Dir(path) => dir_size(fs, path),
+        };
+        a + size
+    })
+}
+
+fn dir_sizes(commands: &[Command]) -> ParseResult<HashMap<String, u64>> {
+    let mut shell = Shell::default();
+    for c in commands {
+        shell.execute(c)?;
+    }
+    Ok(shell
+        .filesystem
+        .keys()
+        .map(|d| (d.clone(), dir_size(&shell.filesystem, d)))
+        .collect())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = HashMap<String, u64>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let mut lines = input.lines();
+        dir_sizes(&parse_input(&mut lines)?)
+    }
+
+    fn part1(dirs_with_sizes: &Self::Input<'_>) -> impl Into<Answer> {
+        dirs_with_sizes
+            .values()
+            .copied()
+            .filter(|s| *s <= 100000_u64)
+            .sum::<u64>()
+    }
+
+    fn part2(dirs_with_sizes: &Self::Input<'_>) -> impl Into<Answer> {
+        let disk_size = 70000000_u64;
+        let required_space = 30000000_u64;
+        let used_space = *dirs_with_sizes.get("/").unwrap();
+
+        let free_space = disk_size - used_space;
+        let required_to_delete = required_space - free_space;
+
+        dirs_with_sizes
+            .values()
+            .copied()
+            .filter(|s| *s >= required_to_delete)
+            .min()
+            .unwrap()
+    }
+}
@@ -1,6 +1,8 @@
-use std::{collections::HashSet, io};
+use std::collections::HashSet;
 
-type Grid = Vec<Vec<i8>>;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+
+pub type Grid = Vec<Vec<i8>>;
 
 #[derive(PartialEq, Eq, Hash)]
 struct Coord {
@@ -8,18 +10,26 @@ struct Coord {
     y: usize,
 }
 
-fn read_grid() -> Grid {
+fn read_grid(input: &str) -> ParseResult<Grid> {
     let mut grid = Grid::new();
-    for line in io::stdin().lines().map(|l| l.unwrap()) {
+    for line in input.lines() {
         let mut v = Vec::new();
-        for c in line.chars() {
-            let x = c.to_digit(10).unwrap();
-            let d = i8::try_from(x).unwrap();
-            v.push(d);
+        for (i, c) in line.char_indices() {
+            let x = c
+                .to_digit(10)
+                .ok_or_else(|| ParseError::new(&line[i..], "expected a digit"))?;
+            v.push(x as i8);
+        }
+        if grid
+            .first()
+            .is_some_and(|row: &Vec<i8>| row.len() != v.len())
+        {
+            let width = grid[0].len();
+            return Err(ParseError::new(line, format!("expected {width} trees")));
         }
         grid.push(v);
     }
-    grid
+    Ok(grid)
 }
 
 fn filter_visible<'a, I>(iter: I) -> Vec<usize>
@@ -61,6 +71,7 @@ fn filter_visible_from_right(grid: &Grid) -> HashSet<Coord> {
     m
 }
 
+#[allow(clippy::needless_range_loop)]
 fn filter_visible_from_top(grid: &Grid) -> HashSet<Coord> {
     let height = grid.len();
     let width = grid.first().unwrap().len();
@@ -75,6 +86,7 @@ fn filter_visible_from_top(grid: &Grid) -> HashSet<Coord> {
     m
 }
 
+#[allow(clippy::needless_range_loop)]
 fn filter_visible_from_bottom(grid: &Grid) -> HashSet<Coord> {
     let height = grid.len();
     let width = grid.first().unwrap().len();
@@ -119,29 +131,43 @@ fn get_scenic_score(c: Coord, grid: &Grid, width: usize, height: usize) -> u64 {
         * count_visibe_trees(t, right)
 }
 
-fn main() {
-    let grid = read_grid();
+pub struct Day;
 
-    let from_left = filter_visible_from_left(&grid);
-    let from_right = filter_visible_from_right(&grid);
-    let from_top = filter_visible_from_top(&grid);
-    let from_bottom = filter_visible_from_bottom(&grid);
+impl Solution for Day {
+    type Input<'a> = Grid;
+    type Params = ();
 
-    let mut visible = HashSet::<Coord>::new();
-    visible.extend(from_left);
-    visible.extend(from_right);
-    visible.extend(from_top);
-    visible.extend(from_bottom);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        read_grid(input)
+    }
 
-    let r1 = visible.len();
-    println!("{}", r1);
+    fn part1(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut visible = HashSet::<Coord>::new();
+        visible.extend(filter_visible_from_left(grid));
+        visible.extend(filter_visible_from_right(grid));
+        visible.extend(filter_visible_from_top(grid));
+        visible.extend(filter_visible_from_bottom(grid));
+        visible.len()
+    }
 
-    let height = grid.len();
-    let width = grid.first().unwrap().len();
-    let r2 = (0..height)
-        .flat_map(move |y| (0..width).map(move |x| Coord { x, y }))
-        .map(|c| get_scenic_score(c, &grid, width, height))
-        .max()
-        .unwrap();
-    println!("{}", r2);
+    fn part2(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        let height = grid.len();
+        let width = grid.first().unwrap().len();
+        (0..height)
+            .flat_map(move |y| (0..width).map(move |x| Coord { x, y }))
+            .map(|c| get_scenic_score(c, grid, width, height))
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ragged_grid() {
+        let e = Day::parse("303\n25\n653\n").unwrap_err();
+        assert_eq!(e.to_string(), "expected 3 trees, found `25`");
+    }
 }
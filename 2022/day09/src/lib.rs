@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Coord {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Clone, Default)]
+struct Rope {
+    head: Coord,
+    tail: Coord,
+}
+
+impl Rope {
+    fn move_head(&mut self, direction: &Coord) {
+        self.head.x += direction.x;
+        self.head.y += direction.y;
+        self.adjust_tail_pos();
+    }
+
+    fn update_head(&mut self, pos: &Coord) {
+        self.head.x = pos.x;
+        self.head.y = pos.y;
+        self.adjust_tail_pos();
+    }
+
+    fn adjust_tail_pos(&mut self) {
+        let dx = self.head.x.abs_diff(self.tail.x);
+        let dy = self.head.y.abs_diff(self.tail.y);
+        if dx <= 1 && dy <= 1 {
+            return;
+        }
+
+        if self.head.x == self.tail.x {
+            self.tail.y += (self.head.y - self.tail.y).signum();
+        } else if self.head.y == self.tail.y {
+            self.tail.x += (self.head.x - self.tail.x).signum();
+        } else {
+            self.tail.y += (self.head.y - self.tail.y).signum();
+            self.tail.x += (self.head.x - self.tail.x).signum();
+        }
+    }
+}
+
+struct LargeRope {
+    segments: Vec<Rope>,
+}
+
+impl LargeRope {
+    fn move_head(&mut self, direction: &Coord) {
+        let len = self.segments.len();
+        let head = self.segments.get_mut(0);
+        head.unwrap().move_head(direction);
+
+        for i in 1..len {
+            let t0 = self.segments.get(i - 1).unwrap().tail;
+            let t = self.segments.get_mut(i).unwrap();
+            t.update_head(&t0);
+        }
+    }
+
+    fn tail(&self) -> Coord {
+        self.segments.last().unwrap().tail
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(Coord, u32)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                let (dir_code, distance) = l.split_once_or_err(" ")?;
+                let distance = distance.parse_as::<u32>()?;
+
+                let dir_vec = match dir_code {
+                    "U" => Coord { x: 0, y: 1 },
+                    "D" => Coord { x: 0, y: -1 },
+                    "R" => Coord { x: 1, y: 0 },
+                    "L" => Coord { x: -1, y: 0 },
+                    _ => return Err(ParseError::new(dir_code, "expected `U`, `D`, `R` or `L`")),
+                };
+                Ok((dir_vec, distance))
+            })
+            .collect()
+    }
+
+    fn part1(motions: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut rope = Rope::default();
+        let mut tail_positions = HashSet::<Coord>::new();
+        for (dir_vec, distance) in motions {
+            for _ in 0..*distance {
+                rope.move_head(dir_vec);
+                tail_positions.insert(rope.tail);
+            }
+        }
+        tail_positions.len()
+    }
+
+    fn part2(motions: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut rope2 = LargeRope {
+            segments: vec![Rope::default(); 9],
+        };
+        let mut tail_positions2 = HashSet::<Coord>::new();
+        for (dir_vec, distance) in motions {
+            for _ in 0..*distance {
+                rope2.move_head(dir_vec);
+                tail_positions2.insert(rope2.tail());
+            }
+        }
+        tail_positions2.len()
+    }
+}
@@ -0,0 +1,91 @@
+use aoc_common::{ocr, Answer, Grid, ParseError, ParseExt, ParseResult, Solution};
+
+enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+fn parse_instruction(s: &str) -> ParseResult<Instruction> {
+    if s == "noop" {
+        return Ok(Instruction::Noop);
+    }
+    match s.split_once(' ') {
+        Some(("addx", v)) => Ok(Instruction::AddX(v.parse_as()?)),
+        _ => Err(ParseError::new(s, "expected `noop` or `addx <number>`")),
+    }
+}
+
+pub struct Cpu {
+    cycle: i32,
+    reg_x: i32,
+}
+
+impl Cpu {
+    fn execute(&self, ins: &Instruction) -> Vec<Cpu> {
+        match ins {
+            Instruction::Noop => vec![Cpu {
+                cycle: self.cycle + 1,
+                reg_x: self.reg_x,
+            }],
+            Instruction::AddX(v) => vec![
+                Cpu {
+                    cycle: self.cycle + 1,
+                    reg_x: self.reg_x,
+                },
+                Cpu {
+                    cycle: self.cycle + 2,
+                    reg_x: self.reg_x + v,
+                },
+            ],
+        }
+    }
+
+    fn signal_strength(&self) -> i32 {
+        self.cycle * self.reg_x
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Cpu>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input
+            .lines()
+            .map(parse_instruction)
+            .collect::<ParseResult<Vec<_>>>()?
+            .into_iter()
+            .fold(vec![Cpu { cycle: 1, reg_x: 1 }], |mut a, ins| {
+                let new_cycles = a.last().unwrap().execute(&ins);
+                a.extend(new_cycles);
+                a
+            }))
+    }
+
+    fn part1(cycles: &Self::Input<'_>) -> impl Into<Answer> {
+        let interesting_cycle_numbers = [20, 60, 100, 140, 180, 220];
+        cycles
+            .iter()
+            .filter(|c| interesting_cycle_numbers.contains(&c.cycle))
+            .map(|c| c.signal_strength())
+            .sum::<i32>()
+    }
+
+    /// Reads the letters on the CRT screen, or draws the screen one line per
+    /// row of pixels if they aren't letters. The pixels drawn after the
+    /// program ends stay dark.
+    fn part2(cycles: &Self::Input<'_>) -> impl Into<Answer> {
+        let (width, height) = (40, 6);
+        let screen = Grid::from_fn(width, height, |p| {
+            cycles
+                .get((p.y * width + p.x) as usize)
+                .is_some_and(|c| (p.x as i32 - c.reg_x).abs() <= 1)
+        });
+        ocr::read(&screen).unwrap_or_else(|| {
+            let drawing = screen.map(|&lit| if lit { '#' } else { '.' }).to_string();
+            drawing.trim_end().to_string()
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    id: usize,
+    items: Vec<u64>,
+    op: Operation,
+    test: u64,
+    throw_true: usize,
+    throw_false: usize,
+}
+
+impl Monkey {
+    fn inspect(&self, i: usize, relief: Option<u64>) -> (usize, u64) {
+        let old = self.items[i];
+        // inspect
+        let new0 = match self.op {
+            Operation::Add(v) => old + v,
+            Operation::Mul(v) => old * v,
+            Operation::Square => old * old,
+        };
+        let new = match relief {
+            None => new0 / 3,
+            Some(v) => new0 % v,
+        };
+        // worry level
+        let throw_to = match new % self.test {
+            0 => self.throw_true,
+            _ => self.throw_false,
+        };
+        (throw_to, new)
+    }
+}
+
+fn parse_operation(s: &str) -> ParseResult<Operation> {
+    let op = s.trim().strip_prefix_or_err("Operation: new = old ")?;
+    match op.split_once(' ') {
+        Some(("+", v)) => Ok(Operation::Add(v.parse_as()?)),
+        Some(("*", "old")) => Ok(Operation::Square),
+        Some(("*", v)) => Ok(Operation::Mul(v.parse_as()?)),
+        _ => Err(ParseError::new(op, "expected `+ <number>` or `* <number>`")),
+    }
+}
+
+fn parse_only_number<T: FromStr>(s: &str) -> ParseResult<T> {
+    s.trim_matches(|c: char| !c.is_ascii_digit()).parse_as()
+}
+
+fn parse_monkey(lines: &[&str]) -> ParseResult<Monkey> {
+    let [id, items, op, test, throw_true, throw_false] = lines else {
+        let last = lines.last().copied().unwrap_or_default();
+        return Err(ParseError::after(last, "expected 6 lines per monkey"));
+    };
+    let items = items
+        .trim_matches(|c: char| !c.is_ascii_digit())
+        .split(',')
+        .map(|t| t.trim().parse_as())
+        .collect::<ParseResult<Vec<u64>>>()?;
+    Ok(Monkey {
+        id: parse_only_number(id)?,
+        items,
+        op: parse_operation(op)?,
+        test: parse_only_number(test)?,
+        throw_true: parse_only_number(throw_true)?,
+        throw_false: parse_only_number(throw_false)?,
+    })
+}
+
+fn do_round(monkeys0: &[Monkey], activity: &mut [usize], relief: Option<u64>) -> Vec<Monkey> {
+    let mut r = monkeys0.to_vec();
+    for i in 0..r.len() {
+        for item_idx in 0..r[i].items.len() {
+            activity[i] += 1;
+            let (throw_to, item) = r[i].inspect(item_idx, relief);
+            assert_eq!(r[throw_to].id, throw_to);
+            r[throw_to].items.push(item);
+        }
+        r[i].items.clear();
+    }
+    r
+}
+
+fn get_monkey_business(activity: &[usize]) -> usize {
+    let mut a = activity.to_vec();
+    a.sort();
+    a.iter().rev().take(2).product::<usize>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Monkey>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let lines = input.lines().collect::<Vec<_>>();
+        lines.split(|l| l.is_empty()).map(parse_monkey).collect()
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut activity1 = vec![0; monkeys.len()];
+        let mut monkeys1 = monkeys.clone();
+        for _ in 0..20 {
+            monkeys1 = do_round(&monkeys1, &mut activity1, None);
+        }
+        get_monkey_business(&activity1)
+    }
+
+    fn part2(monkeys: &Self::Input<'_>) -> impl Into<Answer> {
+        let relief = monkeys.iter().map(|m| m.test).product::<u64>();
+
+        let mut activity2 = vec![0; monkeys.len()];
+        let mut monkeys2 = monkeys.clone();
+        for _ in 0..10000 {
+            monkeys2 = do_round(&monkeys2, &mut activity2, Some(relief));
+        }
+        get_monkey_business(&activity2)
+    }
+}
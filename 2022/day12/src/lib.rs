@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use aoc_common::search::bfs_distances;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+
+pub type Grid = Vec<Vec<u8>>;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Coord {
+    x: usize,
+    y: usize,
+}
+
+fn read_grid(input: &str) -> ParseResult<(Grid, Coord, Coord)> {
+    let mut start = None;
+    let mut end = None;
+    let mut grid = Grid::new();
+
+    for (y, line) in input.lines().enumerate() {
+        let mut v = Vec::new();
+        for (x, c) in line.char_indices() {
+            match c {
+                'S' => {
+                    start = Some(Coord { x, y });
+                    v.push(0);
+                }
+                'E' => {
+                    end = Some(Coord { x, y });
+                    v.push(b'z' - b'a');
+                }
+                'a'..='z' => {
+                    v.push(c as u8 - b'a');
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        &line[x..],
+                        "expected a letter from `a` to `z`, `S` or `E`",
+                    ));
+                }
+            }
+        }
+        if grid
+            .first()
+            .is_some_and(|row: &Vec<u8>| row.len() != v.len())
+        {
+            let width = grid[0].len();
+            return Err(ParseError::new(line, format!("expected {width} squares")));
+        }
+        grid.push(v);
+    }
+    let start = start.ok_or_else(|| ParseError::new(input, "expected a start `S`"))?;
+    let end = end.ok_or_else(|| ParseError::new(input, "expected an end `E`"))?;
+    Ok((grid, start, end))
+}
+
+fn get_neighbours(c: Coord, width: usize, height: usize) -> Vec<Coord> {
+    let mut v = vec![];
+    if c.x > 0 {
+        v.push(Coord { x: c.x - 1, y: c.y });
+    }
+    if c.x + 1 < width {
+        v.push(Coord { x: c.x + 1, y: c.y });
+    }
+    if c.y > 0 {
+        v.push(Coord { x: c.x, y: c.y - 1 });
+    }
+    if c.y + 1 < height {
+        v.push(Coord { x: c.x, y: c.y + 1 });
+    }
+    v
+}
+
+fn map_moves(
+    grid: &Grid,
+    width: usize,
+    height: usize,
+    start: Coord,
+    reverse: bool,
+) -> HashMap<Coord, usize> {
+    bfs_distances([start], |&c| {
+        let e = grid[c.y][c.x];
+        get_neighbours(c, width, height)
+            .into_iter()
+            .filter(move |n| {
+                let ne = grid[n.y][n.x];
+                match reverse {
+                    false => ne <= e + 1,
+                    true => e <= ne || e - 1 == ne,
+                }
+            })
+    })
+}
+
+fn find_lowest(grid: &Grid, width: usize, height: usize) -> Vec<Coord> {
+    let mut v = vec![];
+    for (y, row) in grid.iter().enumerate().take(height) {
+        for (x, &e) in row.iter().enumerate().take(width) {
+            if e == 0 {
+                v.push(Coord { x, y });
+            }
+        }
+    }
+    v
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Grid, Coord, Coord);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        read_grid(input)
+    }
+
+    fn part1((grid, start, end): &Self::Input<'_>) -> impl Into<Answer> {
+        let height = grid.len();
+        let width = grid[0].len();
+        let moves1 = map_moves(grid, width, height, *start, false);
+        *moves1.get(end).unwrap()
+    }
+
+    fn part2((grid, _, end): &Self::Input<'_>) -> impl Into<Answer> {
+        let height = grid.len();
+        let width = grid[0].len();
+        let moves2 = map_moves(grid, width, height, *end, true);
+        let starts2 = find_lowest(grid, width, height);
+        *starts2
+            .iter()
+            .filter(|s| moves2.contains_key(s))
+            .map(|s| moves2.get(s).unwrap())
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_grids() {
+        let e = Day::parse("Sab\nab\nabE\n").unwrap_err();
+        assert_eq!(e.to_string(), "expected 3 squares, found `ab`");
+        let e = Day::parse("aab\nabE\n").unwrap_err();
+        assert!(e.to_string().starts_with("expected a start `S`"));
+        let e = Day::parse("Sab\nabc\n").unwrap_err();
+        assert!(e.to_string().starts_with("expected an end `E`"));
+    }
+}
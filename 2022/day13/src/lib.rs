@@ -0,0 +1,200 @@
+use std::str::Chars;
+
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+enum Token {
+    Integer(u32),
+    ListStart,
+    ListEnd,
+}
+
+fn read_integer(p: &[char], i: &mut usize) -> Option<Token> {
+    let mut s = String::new();
+    while *i < p.len() {
+        let c = p.get(*i).unwrap();
+        if !c.is_ascii_digit() {
+            break;
+        }
+        s.push(*c);
+        *i += 1;
+    }
+    assert!(!s.is_empty());
+    Some(Token::Integer(s.parse().expect("checked by check_packet")))
+}
+
+fn read(p: &[char], i: &mut usize) -> Option<Token> {
+    if *i >= p.len() {
+        return None;
+    }
+    loop {
+        let c = p.get(*i).unwrap();
+        match c {
+            ',' => {
+                *i += 1;
+                continue;
+            }
+            '[' => {
+                *i += 1;
+                break Some(Token::ListStart);
+            }
+            ']' => {
+                *i += 1;
+                break Some(Token::ListEnd);
+            }
+            '0'..='9' => break read_integer(p, i),
+            _ => unreachable!("checked by check_packet"),
+        }
+    }
+}
+
+fn read_tokens(s: Chars) -> Vec<Token> {
+    let chars: Vec<_> = s.collect();
+    let mut i: usize = 0;
+
+    let mut v = vec![];
+    while let Some(t) = read(&chars, &mut i) {
+        v.push(t);
+    }
+    v
+}
+
+fn packets_in_right_order(l: Chars, r: Chars) -> bool {
+    let mut l = read_tokens(l);
+    let mut r = read_tokens(r);
+
+    let mut il: usize = 0;
+    let mut ir: usize = 0;
+    loop {
+        let tl = l.get(il).unwrap();
+        let tr = r.get(ir).unwrap();
+        match tl {
+            Token::Integer(vl) => {
+                match tr {
+                    Token::Integer(vr) => {
+                        if vl < vr {
+                            break true;
+                        }
+                        if vl > vr {
+                            break false;
+                        }
+                        // continue
+                    }
+                    Token::ListStart => {
+                        l.insert(il, Token::ListStart);
+                        l.insert(il + 2, Token::ListEnd);
+                        continue;
+                    }
+                    Token::ListEnd => break false,
+                }
+            }
+            Token::ListStart => {
+                match tr {
+                    Token::Integer(_) => {
+                        r.insert(ir, Token::ListStart);
+                        r.insert(ir + 2, Token::ListEnd);
+                        continue;
+                    }
+                    Token::ListStart => {
+                        // continue
+                    }
+                    Token::ListEnd => break false,
+                }
+            }
+            Token::ListEnd => {
+                match tr {
+                    Token::Integer(_) => break true,
+                    Token::ListStart => break true,
+                    Token::ListEnd => {
+                        // continue
+                    }
+                }
+            }
+        };
+        il += 1;
+        ir += 1;
+    }
+}
+
+/// Checks that `packet` is a list of lists and integers.
+fn check_packet(packet: &str) -> ParseResult<()> {
+    let mut depth = 0;
+    for (i, c) in packet.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth > 0 => depth -= 1,
+            '0'..='9' if depth > 0 => {
+                if !packet[..i].ends_with(|c: char| c.is_ascii_digit()) {
+                    let digits = packet[i..].find(|c: char| !c.is_ascii_digit());
+                    packet[i..i + digits.unwrap_or(packet.len() - i)].parse_as::<u32>()?;
+                }
+            }
+            ',' if depth > 0 => {}
+            _ => return Err(ParseError::new(&packet[i..], "expected a list")),
+        }
+        if depth == 0 && i + 1 < packet.len() {
+            return Err(ParseError::new(
+                &packet[i + 1..],
+                "expected the end of the packet",
+            ));
+        }
+    }
+    if depth != 0 || packet.is_empty() {
+        return Err(ParseError::after(packet, "expected `]`"));
+    }
+    Ok(())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<String>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let lines = input.lines().collect::<Vec<_>>();
+        for pair in lines.split(|l| l.is_empty()) {
+            if pair.len() != 2 {
+                let first = pair.first().copied().unwrap_or_default();
+                return Err(ParseError::new(first, "expected a pair of packets"));
+            }
+            pair.iter().try_for_each(|p| check_packet(p))?;
+        }
+        Ok(lines.into_iter().map(|l| l.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        let packet_lines: Vec<_> = lines.split(|l| l.is_empty()).collect();
+        packet_lines
+            .iter()
+            .map(|p| packets_in_right_order(p[0].chars(), p[1].chars()))
+            .enumerate()
+            .filter_map(|(idx, b)| if b { Some(idx + 1) } else { None })
+            .sum::<usize>()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut lines2: Vec<_> = lines.iter().filter(|l| !l.is_empty()).collect();
+        let r2i1 = String::from("[[2]]");
+        let r2i2 = String::from("[[6]]");
+        lines2.push(&r2i1);
+        lines2.push(&r2i2);
+        lines2.sort_unstable_by(|a, b| {
+            if packets_in_right_order(a.chars(), b.chars()) {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Greater
+            }
+        });
+        lines2
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, s)| {
+                if **s == r2i1 || **s == r2i2 {
+                    Some(idx + 1)
+                } else {
+                    None
+                }
+            })
+            .product::<usize>()
+    }
+}
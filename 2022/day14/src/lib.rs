@@ -1,26 +1,28 @@
 use std::{
     cmp::{max, min},
     collections::HashSet,
-    io,
 };
 
+use aoc_common::render::{self, Frame, Rgb};
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-struct Coord {
+pub struct Coord {
     x: i32,
     y: i32,
 }
 
-type Structure = Vec<Coord>;
+pub type Structure = Vec<Coord>;
 
-fn read_coord(s: &str) -> Coord {
-    let t: Vec<_> = s.split(',').collect();
-    Coord {
-        x: t.get(0).unwrap().parse::<i32>().unwrap(),
-        y: t.get(1).unwrap().parse::<i32>().unwrap(),
-    }
+fn read_coord(s: &str) -> ParseResult<Coord> {
+    let (x, y) = s.split_once_or_err(",")?;
+    Ok(Coord {
+        x: x.parse_as()?,
+        y: y.parse_as()?,
+    })
 }
 
-fn read_structure(s: &String) -> Structure {
+fn read_structure(s: &str) -> ParseResult<Structure> {
     s.split("->").map(|t| read_coord(t.trim())).collect()
 }
 
@@ -115,9 +117,9 @@ fn simulate_sand_step(cave: &HashSet<Coord>, c0: Coord) -> Coord {
 
 fn is_within_boundaries(c0: Coord, b: Boundaries, floor: bool) -> bool {
     if floor {
-        b.0.y <= b.0.y && b.1.y + 2 > c0.y
+        b.1.y + 2 > c0.y
     } else {
-        b.0.x <= c0.x && b.0.y <= b.0.y && b.1.x >= c0.x && b.1.y >= c0.y
+        b.0.x <= c0.x && b.1.x >= c0.x && b.1.y >= c0.y
     }
 }
 
@@ -147,33 +149,67 @@ fn simulate_sand_unit(
     }
 }
 
-fn simulate_sand(cave: &mut HashSet<Coord>, c0: Coord, b: Boundaries, floor: bool) -> usize {
-    let mut c: usize = 0;
-    loop {
-        match simulate_sand_unit(cave, c0, b, floor) {
-            Some(p) => {
-                c += 1;
-                cave.insert(p);
-            }
-            None => break,
+const PALETTE: &[Rgb] = &[[16, 16, 32], [120, 120, 120], [230, 190, 90]];
+
+/// Draws the cave within the boundaries, with the floor if there is one.
+fn draw(rocks: &Cave, cave: &Cave, b: Boundaries, floor: bool) -> Frame {
+    let (x_min, x_max, y_max) = if floor {
+        let y_floor = b.1.y + 2;
+        (
+            min(b.0.x, 500 - y_floor),
+            max(b.1.x, 500 + y_floor),
+            y_floor,
+        )
+    } else {
+        (b.0.x, b.1.x, b.1.y)
+    };
+    let width = (x_max - x_min + 1) as usize;
+    let height = (y_max + 1) as usize;
+    Frame::new(width, height, PALETTE, |x, y| {
+        let c = Coord {
+            x: x_min + x as i32,
+            y: y as i32,
+        };
+        if rocks.contains(&c) || (floor && c.y == y_max) {
+            1
+        } else if cave.contains(&c) {
+            2
+        } else {
+            0
         }
+    })
+}
+
+fn simulate_sand(rocks: &Cave, c0: Coord, b: Boundaries, floor: bool) -> usize {
+    let mut cave = rocks.clone();
+    let mut c: usize = 0;
+    while let Some(p) = simulate_sand_unit(&cave, c0, b, floor) {
+        c += 1;
+        cave.insert(p);
+        render::record(|| draw(rocks, &cave, b, floor));
     }
     c
 }
 
-fn main() {
-    let lines = io::stdin().lines().map(|l| l.unwrap());
-    let structures: Vec<_> = lines.map(|l| read_structure(&l)).collect();
+pub struct Day;
 
-    let cave0 = build_cave(structures.iter());
-    let b = get_boundaries(structures.iter());
-    let c0 = Coord { x: 500, y: 0 };
+impl Solution for Day {
+    type Input<'a> = Vec<Structure>;
+    type Params = ();
 
-    let mut cave1 = cave0.clone();
-    let r1 = simulate_sand(&mut cave1, c0, b, false);
-    println!("{}", r1);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(read_structure).collect()
+    }
+
+    fn part1(structures: &Self::Input<'_>) -> impl Into<Answer> {
+        let rocks = build_cave(structures.iter());
+        let b = get_boundaries(structures.iter());
+        simulate_sand(&rocks, Coord { x: 500, y: 0 }, b, false)
+    }
 
-    let mut cave2 = cave0.clone();
-    let r2 = simulate_sand(&mut cave2, c0, b, true);
-    println!("{}", r2);
+    fn part2(structures: &Self::Input<'_>) -> impl Into<Answer> {
+        let rocks = build_cave(structures.iter());
+        let b = get_boundaries(structures.iter());
+        simulate_sand(&rocks, Coord { x: 500, y: 0 }, b, true)
+    }
 }
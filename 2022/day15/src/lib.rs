@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use aoc_common::{params, scan, Answer, Interval, IntervalSet, ParseResult, Solution};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Coord {
+    x: i32,
+    y: i32,
+}
+
+fn parse_line(l: &str) -> ParseResult<(Coord, Coord)> {
+    let (sx, sy, bx, by) = scan!(
+        l,
+        "Sensor at x={}, y={}: closest beacon is at x={}, y={}" => i32, i32, i32, i32
+    )?;
+    Ok((Coord { x: sx, y: sy }, Coord { x: bx, y: by }))
+}
+
+pub struct Sensor {
+    coord: Coord,
+    distance: i32,
+}
+
+fn sensor_distance(sensor: Coord, beacon: Coord) -> i32 {
+    (sensor.x - beacon.x).abs() + (sensor.y - beacon.y).abs()
+}
+
+fn covered_range(s: &Sensor, y: i32) -> Option<Interval<i32>> {
+    let dy = (s.coord.y - y).abs();
+    let dx = s.distance - dy;
+    if dx > 0 {
+        Some(Interval::inclusive(s.coord.x - dx, s.coord.x + dx))
+    } else {
+        None
+    }
+}
+
+fn get_row_coverage(sensors: &[Sensor], row: i32) -> IntervalSet<i32> {
+    sensors
+        .iter()
+        .filter_map(|s| covered_range(s, row))
+        .collect()
+}
+
+params! {
+    pub struct Params {
+        /// Row in which the first part counts the positions without a beacon
+        pub row: i32 = 2000000,
+        /// Largest coordinate the distress beacon can have in the second part
+        pub max_coord: i32 = 4000000,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<Sensor>, HashSet<Coord>, Params);
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> ParseResult<Self::Input<'a>> {
+        let sensors_and_beacons = input
+            .lines()
+            .map(parse_line)
+            .collect::<ParseResult<Vec<_>>>()?;
+        let sensors: Vec<_> = sensors_and_beacons
+            .iter()
+            .map(|(s, b)| Sensor {
+                coord: *s,
+                distance: sensor_distance(*s, *b),
+            })
+            .collect();
+        let beacons = sensors_and_beacons
+            .iter()
+            .fold(HashSet::<Coord>::new(), |mut a, (_s, b)| {
+                a.insert(*b);
+                a
+            });
+        Ok((sensors, beacons, *params))
+    }
+
+    fn part1((sensors, beacons, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let row1 = params.row;
+        let beacons1 = beacons.iter().filter(|b| b.y == row1).count();
+        let covered_count1 = get_row_coverage(sensors, row1).len();
+        (covered_count1 as usize) - beacons1
+    }
+
+    fn part2((sensors, _, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let b_max = params.max_coord;
+        let search_area = IntervalSet::from(Interval::inclusive(0, b_max));
+        let c2 = (0..=b_max).find_map(|row| {
+            let uncovered = search_area.difference(&get_row_coverage(sensors, row));
+            let gap = uncovered.intervals().first()?;
+            Some(Coord {
+                x: gap.start,
+                y: row,
+            })
+        });
+        c2.unwrap().x as i64 * 4000000 + c2.unwrap().y as i64
+    }
+}
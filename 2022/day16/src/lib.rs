@@ -1,35 +1,31 @@
 use std::{
     cmp::max,
     collections::{HashMap, HashSet},
-    io,
 };
 
+use aoc_common::{scan, Answer, ParseError, ParseResult, Solution};
+
 #[derive(Clone)]
-struct Valve {
+pub struct Valve {
     name: String,
     rate: u32,
     tunnels: Vec<String>,
 }
 
-fn parse_valve<'a>(s: String) -> Valve {
-    let t0: Vec<_> = s.split(';').collect();
-    let t1: Vec<_> = t0[0].split(' ').collect();
-    let name = t1[1];
-    let rate = t1[4]
-        .trim_matches(|c: char| !c.is_digit(10))
-        .parse::<u32>()
-        .unwrap();
-    let tunnels: Vec<_> = t0[1]
-        .trim_matches(|c: char| !c.is_uppercase())
-        .split(',')
-        .map(str::trim)
+fn parse_valve(s: &str) -> ParseResult<Valve> {
+    let (name, rate, tunnels) = scan!(s, "Valve {} has flow rate={}; {}" => &str, u32, &str)?;
+    let tunnels = tunnels
+        .strip_prefix("tunnels lead to valves ")
+        .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+        .ok_or_else(|| ParseError::new(tunnels, "expected `tunnels lead to valves`"))?
+        .split(", ")
         .map(str::to_string)
         .collect();
-    Valve {
+    Ok(Valve {
         name: name.to_string(),
         rate,
         tunnels,
-    }
+    })
 }
 
 fn measure_distances<'a>(
@@ -39,8 +35,7 @@ fn measure_distances<'a>(
     let mut visited = HashMap::new();
     let mut todo = Vec::new();
     todo.push((pos, 0));
-    while !todo.is_empty() {
-        let (p, d) = todo.pop().unwrap();
+    while let Some((p, d)) = todo.pop() {
         if d > 0 {
             visited.insert(p, d);
         }
@@ -72,16 +67,16 @@ fn traverse<'a>(
     valves: &'a HashMap<String, Valve>,
     distances: &HashMap<&str, HashMap<&str, u32>>,
 ) -> Vec<Result<'a>> {
-    if time <= 0 {
-        let r = vec![Result {
-            open_valves: open_valves,
-            flow,
-        }];
-        return r;
+    // Stopping here is a valid path too: in part 2 the other walker may be
+    // the one opening the remaining valves.
+    let mut results = vec![Result {
+        open_valves: open_valves.clone(),
+        flow,
+    }];
+    if time == 0 {
+        return results;
     }
 
-    let mut results = vec![];
-
     for name2 in closed_valves.iter() {
         assert_ne!(v0.name, *name2);
 
@@ -102,12 +97,9 @@ fn traverse<'a>(
 
         let t2 = time - distance;
         let f2 = flow + t2 * v2.rate;
-        let r = traverse(&v2, t2, open_valves2, closed_valves2, f2, valves, distances);
+        let r = traverse(v2, t2, open_valves2, closed_valves2, f2, valves, distances);
         results.extend(r);
     }
-    if results.is_empty() {
-        results.push(Result { open_valves, flow });
-    }
     results
 }
 
@@ -130,18 +122,11 @@ fn find_best(paths: &Vec<Result>) -> u32 {
     best
 }
 
-fn main() {
-    let lines: Vec<_> = io::stdin().lines().map(|l| l.unwrap()).collect();
-    let valves: HashMap<_, _> = lines
-        .into_iter()
-        .map(parse_valve)
-        .map(|v| (v.name.clone(), v))
-        .collect();
-
+fn find_paths(valves: &HashMap<String, Valve>, time: u32) -> Vec<Result<'_>> {
     let distances: HashMap<_, _> = valves
         .keys()
         .map(|r| {
-            let distances = measure_distances(&valves, r);
+            let distances = measure_distances(valves, r);
             (r.as_str(), distances)
         })
         .collect();
@@ -152,29 +137,48 @@ fn main() {
         .filter(|(_name, v)| v.rate > 0)
         .map(|(name, _v)| name.as_str())
         .collect();
-    let paths = traverse(
+    traverse(
         start,
-        30,
+        time,
         HashSet::new(),
-        valves_with_flow.clone(),
+        valves_with_flow,
         0,
-        &valves,
+        valves,
         &distances,
-    );
-    let r1 = paths.iter().max_by_key(|r| r.flow).unwrap().flow;
-    println!("{}", r1);
+    )
+}
 
-    let mut paths2 = traverse(
-        start,
-        26,
-        HashSet::new(),
-        valves_with_flow.clone(),
-        0,
-        &valves,
-        &distances,
-    );
-    paths2.sort_by_key(|r| r.flow);
-    paths2.reverse();
-    let r2 = find_best(&paths2);
-    println!("{}", r2);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = HashMap<String, Valve>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let valves = input
+            .lines()
+            .map(|l| parse_valve(l).map(|v| (v.name.clone(), v)))
+            .collect::<ParseResult<HashMap<_, _>>>()?;
+        if !valves.contains_key("AA") {
+            return Err(ParseError::after(input, "expected a valve `AA`"));
+        }
+        for v in valves.values() {
+            if let Some(t) = v.tunnels.iter().find(|t| !valves.contains_key(*t)) {
+                return Err(ParseError::new(t, "unknown valve"));
+            }
+        }
+        Ok(valves)
+    }
+
+    fn part1(valves: &Self::Input<'_>) -> impl Into<Answer> {
+        let paths = find_paths(valves, 30);
+        paths.iter().max_by_key(|r| r.flow).unwrap().flow
+    }
+
+    fn part2(valves: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut paths2 = find_paths(valves, 26);
+        paths2.sort_by_key(|r| r.flow);
+        paths2.reverse();
+        find_best(&paths2)
+    }
 }
@@ -0,0 +1,347 @@
+use std::cmp::max;
+use std::collections::HashSet;
+
+use aoc_common::render::{self, Frame, Rgb};
+use aoc_common::{cycle::find_cycle_by_key, Answer, ParseError, ParseResult, Solution, Vec2};
+
+/// Position in the chamber, with `y` growing upwards from the floor.
+type Coord = Vec2<i32>;
+
+type Shape = Vec<Coord>;
+
+fn shape0() -> Shape {
+    vec![
+        Coord::new(0, 0),
+        Coord::new(1, 0),
+        Coord::new(2, 0),
+        Coord::new(3, 0),
+    ]
+}
+
+fn shape1() -> Shape {
+    vec![
+        Coord::new(1, 0),
+        Coord::new(0, 1),
+        Coord::new(1, 1),
+        Coord::new(2, 1),
+        Coord::new(1, 2),
+    ]
+}
+
+fn shape2() -> Shape {
+    vec![
+        Coord::new(0, 0),
+        Coord::new(1, 0),
+        Coord::new(2, 0),
+        Coord::new(2, 1),
+        Coord::new(2, 2),
+    ]
+}
+
+fn shape3() -> Shape {
+    vec![
+        Coord::new(0, 0),
+        Coord::new(0, 1),
+        Coord::new(0, 2),
+        Coord::new(0, 3),
+    ]
+}
+
+fn shape4() -> Shape {
+    vec![
+        Coord::new(0, 0),
+        Coord::new(1, 0),
+        Coord::new(0, 1),
+        Coord::new(1, 1),
+    ]
+}
+
+struct Rock {
+    pos: Coord,
+    shape: Shape,
+}
+
+impl Rock {
+    fn new(pos: Coord, shape: usize) -> Self {
+        Self {
+            pos,
+            shape: match shape {
+                0 => shape0(),
+                1 => shape1(),
+                2 => shape2(),
+                3 => shape3(),
+                4 => shape4(),
+                _ => panic!("Invalid shape type"),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+/// Shape of the next rock, position in the jet pattern and the cells the rock
+/// can reach, relative to where it appears.
+type Situation = (usize, usize, Vec<Coord>);
+
+const PALETTE: &[Rgb] = &[[16, 16, 32], [200, 120, 60]];
+
+/// Number of rows drawn, from just above the top of the tower down.
+const VIEW_HEIGHT: usize = 48;
+
+/// Depth below a new rock down to which the cells it can reach tell the
+/// situations apart for the cycle detection, at first. Without a limit, a
+/// column the jets keep every rock away from gets ever deeper and the
+/// situations never repeat.
+const DEPTH: i32 = 64;
+
+struct Chamber<'a> {
+    width: i32,
+    height: usize,
+    rock_count: usize,
+    grid: Vec<Vec<bool>>,
+    jet_pattern: &'a Vec<Jet>,
+    next_jet_idx: usize,
+    /// How far down the situations look, see `DEPTH`.
+    depth: i32,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(width: i32, jet_pattern: &'a Vec<Jet>, depth: i32) -> Self {
+        Self {
+            width,
+            height: 0,
+            rock_count: 0,
+            grid: vec![],
+            jet_pattern,
+            next_jet_idx: 0,
+            depth,
+        }
+    }
+
+    fn enlarge_grid(&mut self, height: usize) {
+        for _ in self.grid.len()..=height {
+            self.grid.push(vec![false; self.width as usize]);
+        }
+    }
+
+    fn spawn_rock(&mut self) -> (Rock, Situation) {
+        let rock_shape = self.rock_count % 5;
+        let pos = Coord::new(2, self.height as i32 + 3);
+        let rock = Rock::new(pos, rock_shape);
+        self.rock_count += 1;
+        self.enlarge_grid(pos.y as usize + 3);
+
+        let situation = (rock_shape, self.next_jet_idx, self.reachable(pos.y));
+        (rock, situation)
+    }
+
+    fn collides(&self, pos: Coord, shape: &Shape) -> bool {
+        shape.iter().map(|offset| *offset + pos).any(|c| {
+            if c.x < 0 || c.x >= self.width || c.y < 0 {
+                return true;
+            }
+            *self
+                .grid
+                .get(c.y as usize)
+                .unwrap()
+                .get(c.x as usize)
+                .unwrap()
+        })
+    }
+
+    fn next_jet_direction(&mut self) -> Coord {
+        let p = self.jet_pattern.get(self.next_jet_idx).unwrap();
+        self.next_jet_idx = (self.next_jet_idx + 1) % self.jet_pattern.len();
+        match *p {
+            Jet::Left => Coord::LEFT,
+            Jet::Right => Coord::RIGHT,
+        }
+    }
+
+    // true if moved
+    fn move_rock(&self, rock: &mut Rock, dir: Coord) -> bool {
+        let p2 = rock.pos + dir;
+        if !self.collides(p2, &rock.shape) {
+            rock.pos = p2;
+            return true;
+        }
+        false
+    }
+
+    fn push_rock_by_jet(&mut self, rock: &mut Rock) -> bool {
+        let dir = self.next_jet_direction();
+        self.move_rock(rock, dir)
+    }
+
+    fn apply_gravity(&mut self, rock: &mut Rock) -> bool {
+        let dir = Coord::new(0, -1);
+        self.move_rock(rock, dir)
+    }
+
+    fn lock_in(&mut self, rock: Rock) {
+        for c in rock.shape.iter().map(|offset| *offset + rock.pos) {
+            let p = self
+                .grid
+                .get_mut(c.y as usize)
+                .unwrap()
+                .get_mut(c.x as usize)
+                .unwrap();
+            assert!(!(*p));
+            *p = true;
+            self.height = max(self.height, c.y as usize + 1);
+        }
+    }
+
+    /// Drops a rock, returning the situation it appeared in and how far below
+    /// where it appeared it came to rest, counting the cell it rests on.
+    fn simulate_rock(&mut self) -> (Situation, i32) {
+        let (mut rock, situation) = self.spawn_rock();
+        let top = rock.pos.y;
+        loop {
+            self.push_rock_by_jet(&mut rock);
+            if !self.apply_gravity(&mut rock) {
+                break;
+            }
+        }
+        let fall = top - (rock.pos.y - 1);
+        self.lock_in(rock);
+        render::record(|| self.draw());
+        (situation, fall)
+    }
+
+    /// Draws the top of the tower.
+    fn draw(&self) -> Frame {
+        let top = max(self.height + 3, VIEW_HEIGHT);
+        Frame::new(self.width as usize, VIEW_HEIGHT, PALETTE, |x, y| {
+            self.grid.get(top - 1 - y).is_some_and(|row| row[x]) as u8
+        })
+    }
+
+    /// The empty cells reachable from row `y` without going more than
+    /// `depth` below it, relative to `y`. A falling rock only ever moves
+    /// through those.
+    fn reachable(&self, y: i32) -> Vec<Coord> {
+        let mut todo = (0..self.width)
+            .map(|x| Coord::new(x, y))
+            .collect::<Vec<_>>();
+        let mut seen = todo.iter().copied().collect::<HashSet<_>>();
+        while let Some(p) = todo.pop() {
+            for n in [p + Coord::LEFT, p + Coord::RIGHT, p + Coord::new(0, -1)] {
+                if n.x >= 0
+                    && n.x < self.width
+                    && n.y >= 0
+                    && n.y >= y - self.depth
+                    && !self.grid[n.y as usize][n.x as usize]
+                    && seen.insert(n)
+                {
+                    todo.push(n);
+                }
+            }
+        }
+        let mut cells = seen
+            .into_iter()
+            .map(|p| p - Coord::new(0, y))
+            .collect::<Vec<_>>();
+        cells.sort_by_key(|p| (p.y, p.x));
+        cells
+    }
+}
+
+/// Height of the tower after `rocks` rocks, skipping ahead once the falling
+/// rocks start repeating themselves.
+///
+/// Two situations alike down to some depth only lead to the same rocks
+/// forever if none of the rocks in between falls deeper, so the situations
+/// look deeper until that holds.
+fn tower_height(jet_pattern: &Vec<Jet>, rocks: usize) -> usize {
+    let mut depth = DEPTH;
+    loop {
+        let mut c = Chamber::new(7, jet_pattern, depth);
+        let mut next = || {
+            let height = c.height;
+            let (situation, fall) = c.simulate_rock();
+            (situation, fall, height)
+        };
+        let initial = next();
+        let (cycle, states) =
+            find_cycle_by_key(initial, |_| next(), |(situation, _, _)| situation.clone());
+        let cycle_states = &states[cycle.start..cycle.start + cycle.length];
+        if cycle_states.iter().all(|&(_, fall, _)| fall <= depth) {
+            return cycle.extrapolate(|i| states[i].2, rocks);
+        }
+        depth *= 2;
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Jet>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let pattern = input.trim();
+        pattern
+            .char_indices()
+            .map(|(i, c)| match c {
+                '<' => Ok(Jet::Left),
+                '>' => Ok(Jet::Right),
+                _ => Err(ParseError::new(&pattern[i..], "expected `<` or `>`")),
+            })
+            .collect()
+    }
+
+    fn part1(jet_pattern: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut c = Chamber::new(7, jet_pattern, DEPTH);
+        for _ in 0..2022 {
+            c.simulate_rock();
+        }
+        c.height
+    }
+
+    fn part2(jet_pattern: &Self::Input<'_>) -> impl Into<Answer> {
+        tower_height(jet_pattern, 1000000000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn tower_height() {
+        let jet_pattern = Day::parse(include_str!("../examples/1.txt")).unwrap();
+        let mut c = Chamber::new(7, &jet_pattern, DEPTH);
+        for rocks in 0..500 {
+            assert_eq!(
+                super::tower_height(&jet_pattern, rocks),
+                c.height,
+                "{rocks} rocks"
+            );
+            c.simulate_rock();
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn tower_height_matches_simulation(
+            jets in prop::collection::vec(prop::bool::ANY, 1..40),
+            rocks in 0..3000usize,
+        ) {
+            let jet_pattern = jets
+                .iter()
+                .map(|&left| if left { Jet::Left } else { Jet::Right })
+                .collect::<Vec<_>>();
+            let mut c = Chamber::new(7, &jet_pattern, DEPTH);
+            for _ in 0..rocks {
+                c.simulate_rock();
+            }
+            prop_assert_eq!(super::tower_height(&jet_pattern, rocks), c.height);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use aoc_common::{Answer, ParseExt, ParseResult, Solution, Vec3};
+use itertools::iproduct;
+use std::collections::HashSet;
+
+pub type Coord = Vec3<i32>;
+
+fn parse_coord(s: &str) -> ParseResult<Coord> {
+    let (x, rest) = s.split_once_or_err(",")?;
+    let (y, z) = rest.split_once_or_err(",")?;
+    Ok(Coord::new(x.parse_as()?, y.parse_as()?, z.parse_as()?))
+}
+
+type CoordSet = HashSet<Coord>;
+
+fn get_adjacent(c: &Coord) -> impl Iterator<Item = Coord> + '_ {
+    Coord::CARDINALS.into_iter().map(move |d| *c + d)
+}
+
+fn count_adjacent(set: &CoordSet, c: &Coord) -> usize {
+    get_adjacent(c).filter(|c| set.contains(c)).count()
+}
+
+fn is_within_boundaries(c: &Coord, (b_min, b_max): (Coord, Coord)) -> bool {
+    c.x >= b_min.x
+        && c.x <= b_max.x
+        && c.y >= b_min.y
+        && c.y <= b_max.y
+        && c.z >= b_min.z
+        && c.z <= b_max.z
+}
+
+fn flood_fill(set: &mut CoordSet, start: Coord, boundaries: (Coord, Coord)) {
+    let mut todo = Vec::<Coord>::new();
+    todo.push(start);
+
+    while let Some(c) = todo.pop() {
+        if !set.contains(&c) {
+            set.insert(c);
+            let free_adjacent: Vec<_> = get_adjacent(&c)
+                .filter(|c| is_within_boundaries(c, boundaries))
+                .filter(|c| !set.contains(c))
+                .collect();
+            todo.extend(free_adjacent);
+        }
+    }
+}
+
+fn surface_area(cubes: &[Coord]) -> usize {
+    let mut s = CoordSet::new();
+    let mut r = 0;
+    for c in cubes {
+        let a = count_adjacent(&s, c);
+        s.insert(*c);
+        r = r + 6 - 2 * a;
+    }
+    r
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Coord>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(parse_coord).collect()
+    }
+
+    fn part1(cubes: &Self::Input<'_>) -> impl Into<Answer> {
+        surface_area(cubes)
+    }
+
+    fn part2(cubes: &Self::Input<'_>) -> impl Into<Answer> {
+        let r1 = surface_area(cubes);
+        let mut s = cubes.iter().copied().collect::<CoordSet>();
+
+        let b_min = s
+            .clone()
+            .into_iter()
+            .reduce(|a, b| Coord::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)))
+            .unwrap();
+        let b_max = s
+            .clone()
+            .into_iter()
+            .reduce(|a, b| Coord::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)))
+            .unwrap();
+        let b = (b_min, b_max);
+
+        for (x, y, z) in iproduct!([b_min.x, b_max.x], b_min.y..=b_max.y, b_min.z..=b_max.z) {
+            flood_fill(&mut s, Coord::new(x, y, z), b)
+        }
+        for (x, y, z) in iproduct!(b_min.x..=b_max.x, [b_min.y, b_max.y], b_min.z..=b_max.z) {
+            flood_fill(&mut s, Coord::new(x, y, z), b)
+        }
+        for (x, y, z) in iproduct!(b_min.x..=b_max.x, b_min.y..=b_max.y, [b_min.z, b_max.z]) {
+            flood_fill(&mut s, Coord::new(x, y, z), b)
+        }
+
+        let inner: Vec<_> = iproduct!(b_min.x..=b_max.x, b_min.y..=b_max.y, b_min.z..=b_max.z)
+            .filter_map(|(x, y, z)| {
+                let c = Coord::new(x, y, z);
+                if !s.contains(&c) {
+                    Some(c)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let mut r2 = r1;
+        for c in inner {
+            let a = count_adjacent(&s, &c);
+            s.insert(c);
+            r2 = r2 + 6 - 2 * a;
+        }
+        r2
+    }
+}
@@ -1,7 +1,10 @@
-use std::{collections::HashMap, io, str::FromStr};
+use std::collections::HashMap;
+
+use aoc_common::parallel::prelude::*;
+use aoc_common::{scan, Answer, ParseResult, Solution};
 
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     id: u32,
     ore_robot_ore_costs: u32,
     clay_robot_ore_costs: u32,
@@ -11,40 +14,29 @@ struct Blueprint {
     geode_robot_obsidian_costs: u32,
 }
 
-fn parse_number<T>(s: &str) -> Result<T, <T as FromStr>::Err>
-where
-    T: FromStr,
-{
-    s.trim_matches(|c: char| !c.is_digit(10)).parse::<T>()
-}
-
-fn parse_blueprint(s: &str) -> Blueprint {
-    let t: Vec<_> = s.split(':').flat_map(|s| s.split('.')).collect();
-
-    let id = parse_number::<u32>(t.get(0).unwrap()).unwrap();
-    let ore_robot_ore_costs = parse_number::<u32>(t.get(1).unwrap()).unwrap();
-    let clay_robot_ore_costs = parse_number::<u32>(t.get(2).unwrap()).unwrap();
-
-    let t2: Vec<_> = t.get(3).unwrap().split("and").collect();
-    let obsidian_robot_ore_costs = parse_number::<u32>(t2.get(0).unwrap()).unwrap();
-    let obsidian_robot_clay_costs = parse_number::<u32>(t2.get(1).unwrap()).unwrap();
-
-    let t3: Vec<_> = t.get(4).unwrap().split("and").collect();
-    let geode_robot_ore_costs = parse_number::<u32>(t3.get(0).unwrap()).unwrap();
-    let geode_robot_obsidian_costs = parse_number::<u32>(t3.get(1).unwrap()).unwrap();
-
-    Blueprint {
+fn parse_blueprint(s: &str) -> ParseResult<Blueprint> {
+    let (id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = scan!(
+        s,
+        "Blueprint {}: \
+         Each ore robot costs {} ore. \
+         Each clay robot costs {} ore. \
+         Each obsidian robot costs {} ore and {} clay. \
+         Each geode robot costs {} ore and {} obsidian."
+            => u32, u32, u32, u32, u32, u32, u32
+    )?;
+    Ok(Blueprint {
         id,
-        ore_robot_ore_costs,
-        clay_robot_ore_costs,
-        obsidian_robot_ore_costs,
-        obsidian_robot_clay_costs,
-        geode_robot_ore_costs,
-        geode_robot_obsidian_costs,
-    }
+        ore_robot_ore_costs: ore,
+        clay_robot_ore_costs: clay,
+        obsidian_robot_ore_costs: obsidian_ore,
+        obsidian_robot_clay_costs: obsidian_clay,
+        geode_robot_ore_costs: geode_ore,
+        geode_robot_obsidian_costs: geode_obsidian,
+    })
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::enum_variant_names)]
 enum BuildTask {
     OreRobot,
     ClayRobot,
@@ -95,7 +87,7 @@ impl System {
     }
 
     fn tick(&mut self) -> bool {
-        if self.time <= 0 {
+        if self.time == 0 {
             return false;
         }
 
@@ -204,7 +196,7 @@ fn simulate(start: System, blueprint: &Blueprint) -> u32 {
     let mut best = HashMap::<u32, u32>::new();
     let mut max_geodes = 0;
     while let Some(mut s) = todo.pop() {
-        if s.task == None {
+        if s.task.is_none() {
             for new_task in [
                 BuildTask::GeodeRobot,
                 BuildTask::ObsidianRobot,
@@ -255,28 +247,36 @@ fn simulate(start: System, blueprint: &Blueprint) -> u32 {
     max_geodes
 }
 
-fn main() {
-    let lines = io::stdin().lines().map(|l| l.unwrap());
-    let blueprints: Vec<_> = lines.map(|l| parse_blueprint(l.as_str())).collect();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Blueprint>;
+    type Params = ();
 
-    let r1: u32 = blueprints
-        .iter()
-        .map(|b| {
-            let s = System::new(24);
-            let geodes = simulate(s, &b);
-            b.id * geodes
-        })
-        .sum();
-    println!("{:?}", r1);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(parse_blueprint).collect()
+    }
 
-    let r2: u32 = blueprints
-        .iter()
-        .take(3)
-        .map(|b| {
-            let s = System::new(32);
-            let time = simulate(s, &b);
-            time
-        })
-        .product();
-    println!("{:?}", r2);
+    fn part1(blueprints: &Self::Input<'_>) -> impl Into<Answer> {
+        blueprints
+            .maybe_par_iter()
+            .map(|b| {
+                let s = System::new(24);
+                let geodes = simulate(s, b);
+                b.id * geodes
+            })
+            .sum::<u32>()
+    }
+
+    fn part2(blueprints: &Self::Input<'_>) -> impl Into<Answer> {
+        blueprints
+            .maybe_par_iter()
+            .take(3)
+            .map(|b| {
+                let s = System::new(32);
+
+                simulate(s, b)
+            })
+            .product::<u32>()
+    }
 }
@@ -0,0 +1,85 @@
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+fn wrap(v: i64, count: i64) -> i64 {
+    let v0 = v % count;
+    let v2 = if v0 < 0 { v0 + count } else { v0 };
+    assert_eq!(v2, v.rem_euclid(count));
+    v2
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Item {
+    priority: usize,
+    value: i64,
+}
+
+fn rotate(priority: usize, numbers: &mut Vec<Item>) {
+    // priority, idx, value
+    let pos = numbers
+        .iter()
+        .position(|item| priority == item.priority)
+        .unwrap();
+    let item = numbers.remove(pos);
+    let count = numbers.len() as i64;
+    let new_pos = wrap(pos as i64 + item.value, count);
+    numbers.insert(new_pos as usize, item);
+}
+
+fn grove_coordinates(numbers: &[Item]) -> i64 {
+    let count = numbers.len();
+    let zero_pos = numbers.iter().position(|i| i.value == 0).unwrap();
+    [1000, 2000, 3000]
+        .map(|idx| (zero_pos + idx) % count)
+        .map(|p| numbers.get(p).unwrap().value)
+        .iter()
+        .sum::<i64>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Item>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let numbers = input
+            .lines()
+            .enumerate()
+            .map(|(index, l)| {
+                Ok(Item {
+                    priority: index,
+                    value: l.parse_as()?,
+                })
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        if !numbers.iter().any(|i| i.value == 0) {
+            return Err(ParseError::after(input, "expected a 0 in the file"));
+        }
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut numbers = numbers.clone();
+        for i in 0..numbers.len() {
+            rotate(i, &mut numbers);
+        }
+        grove_coordinates(&numbers)
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> impl Into<Answer> {
+        let decription_key = 811589153;
+        let mut numbers2 = numbers
+            .iter()
+            .map(|i| Item {
+                priority: i.priority,
+                value: i.value * decription_key,
+            })
+            .collect::<Vec<_>>();
+        for _ in 0..10 {
+            for i in 0..numbers2.len() {
+                rotate(i, &mut numbers2);
+            }
+        }
+        grove_coordinates(&numbers2)
+    }
+}
@@ -1,10 +1,9 @@
-use std::{
-    collections::{HashMap, HashSet},
-    io,
-};
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
-enum Operation<'a> {
+pub enum Operation<'a> {
     Plus(&'a str, &'a str),
     Minus(&'a str, &'a str),
     Multiply(&'a str, &'a str),
@@ -12,21 +11,18 @@ enum Operation<'a> {
     Value(i64),
 }
 
-fn parse_monkey<'a>(s: &'a String) -> (&'a str, Operation<'a>) {
-    let t0: Vec<_> = s.split(':').collect();
-    let name = t0[0];
-    let t: Vec<_> = t0[1].trim().split(' ').collect();
-    if t.len() == 1 {
-        return (name, Operation::Value(t[0].parse::<i64>().unwrap()));
-    }
-    let op = match t[1] {
-        "+" => Operation::Plus(t[0], t[2]),
-        "-" => Operation::Minus(t[0], t[2]),
-        "*" => Operation::Multiply(t[0], t[2]),
-        "/" => Operation::Divide(t[0], t[2]),
-        _ => panic!("Invalid monkey job {}", t[1]),
+fn parse_monkey(s: &str) -> ParseResult<(&str, Operation<'_>)> {
+    let (name, job) = s.split_once_or_err(": ")?;
+    let t: Vec<_> = job.split(' ').collect();
+    let op = match t[..] {
+        [value] => Operation::Value(value.parse_as()?),
+        [a, "+", b] => Operation::Plus(a, b),
+        [a, "-", b] => Operation::Minus(a, b),
+        [a, "*", b] => Operation::Multiply(a, b),
+        [a, "/", b] => Operation::Divide(a, b),
+        _ => return Err(ParseError::new(job, "expected a number or `<a> <op> <b>`")),
     };
-    (name, op)
+    Ok((name, op))
 }
 
 fn evaluate<'a>(
@@ -68,15 +64,13 @@ fn map_humn_branch<'a>(name: &'a str, ops: &'a HashMap<&str, Operation>) -> Vec<
         }
         let op = ops.get(name).unwrap();
         match get_op_params(op) {
-            Some((a, b)) => {
-                if map_humn_branch_rec(a, ops, humn) || map_humn_branch_rec(b, ops, humn) {
-                    humn.push(name);
-                    true
-                } else {
-                    false
-                }
+            Some((a, b))
+                if map_humn_branch_rec(a, ops, humn) || map_humn_branch_rec(b, ops, humn) =>
+            {
+                humn.push(name);
+                true
             }
-            None => false,
+            _ => false,
         }
     }
     let mut humn_branch = Vec::<&str>::new();
@@ -153,24 +147,49 @@ fn find_humn_value(
     }
 }
 
-fn main() {
-    let lines: Vec<_> = io::stdin().lines().map(|l| l.unwrap()).collect();
-    let operations: HashMap<&str, Operation> = lines.iter().map(|l| parse_monkey(&l)).collect();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = HashMap<&'a str, Operation<'a>>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let operations = input
+            .lines()
+            .map(parse_monkey)
+            .collect::<ParseResult<HashMap<_, _>>>()?;
+        for (a, b) in operations.values().filter_map(get_op_params) {
+            if let Some(m) = [a, b].into_iter().find(|m| !operations.contains_key(m)) {
+                return Err(ParseError::new(m, "unknown monkey"));
+            }
+        }
+        match operations.get("root") {
+            Some(Operation::Value(_)) | None => Err(ParseError::after(
+                input,
+                "expected a `root` monkey with an operation",
+            )),
+            Some(_) => Ok(operations),
+        }
+    }
 
-    let mut results = HashMap::<&str, i64>::new();
+    fn part1(operations: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut results = HashMap::<&str, i64>::new();
+        evaluate("root", operations, &mut results)
+    }
 
-    let r1 = evaluate("root", &operations, &mut results);
-    println!("{}", r1);
+    fn part2(operations: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut results = HashMap::<&str, i64>::new();
+        evaluate("root", operations, &mut results);
 
-    let root = operations.get("root").unwrap();
-    let (root_a, root_b) = get_op_params(root).unwrap();
+        let root = operations.get("root").unwrap();
+        let (root_a, root_b) = get_op_params(root).unwrap();
 
-    let humn_branch = map_humn_branch(root_a, &operations)
-        .into_iter()
-        .collect::<HashSet<&str>>();
+        let humn_branch = map_humn_branch(root_a, operations)
+            .into_iter()
+            .collect::<HashSet<&str>>();
 
-    let (known, unknown) = identify_unknown((root_a, root_b), &&humn_branch);
-    let expected = *results.get(known).unwrap();
-    let r2 = find_humn_value(expected, unknown, &operations, &results, &humn_branch);
-    println!("{}", r2);
+        let (known, unknown) = identify_unknown((root_a, root_b), &humn_branch);
+        let expected = *results.get(known).unwrap();
+        find_humn_value(expected, unknown, operations, &results, &humn_branch)
+    }
 }
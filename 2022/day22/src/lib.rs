@@ -2,98 +2,29 @@ use std::{
     cell::RefCell,
     cmp::max,
     collections::{HashMap, VecDeque},
-    fmt::Display,
-    io,
-    ops::{Add, Sub},
 };
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-struct Coord {
-    x: i32,
-    y: i32,
-}
-
-impl Add for Coord {
-    type Output = Coord;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Coord {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
-impl Sub for Coord {
-    type Output = Coord;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Coord {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
-}
-
-impl Display for Coord {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{},{}", self.x, self.y)
-    }
-}
-
-impl Coord {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-
-    fn from_facing(facing: i32) -> Coord {
-        match facing {
-            0 => DIR_RIGHT,
-            1 => DIR_DOWN,
-            2 => DIR_LEFT,
-            3 => DIR_UP,
-            _ => panic!(),
-        }
-    }
-
-    fn add_dir(&self, dir: Direction) -> Coord {
-        Coord {
-            x: self.x + dir.x,
-            y: self.y - dir.y,
-        }
-    }
-
-    fn turn_right(&self) -> Coord {
-        Coord::new(self.y, -self.x)
-    }
-
-    fn turn_left(&self) -> Coord {
-        Coord::new(-self.y, self.x)
-    }
-
-    fn turn_around(&self) -> Coord {
-        self.turn_left().turn_left()
-    }
-}
-
-// impl ops::Add<Coord> for Coord {
-//     type Output = Coord;
+use aoc_common::{params, Answer, ParseError, ParseExt, ParseResult, Solution, Vec2};
 
-//     fn add(self, other: Coord) -> Coord {
-//         Coord {
-//             x: self.x + other.x,
-//             y: self.y + other.y,
-//         }
-//     }
-// }
+type Coord = Vec2<i32>;
 
 type Direction = Coord;
 type Face = Coord;
 
-const DIR_RIGHT: Direction = Coord { x: 1, y: 0 };
-const DIR_DOWN: Direction = Coord { x: 0, y: -1 };
-const DIR_LEFT: Direction = Coord { x: -1, y: 0 };
-const DIR_UP: Direction = Coord { x: 0, y: 1 };
+const DIR_RIGHT: Direction = Coord::RIGHT;
+const DIR_DOWN: Direction = Coord::DOWN;
+const DIR_LEFT: Direction = Coord::LEFT;
+const DIR_UP: Direction = Coord::UP;
+
+fn from_facing(facing: i32) -> Direction {
+    match facing {
+        0 => DIR_RIGHT,
+        1 => DIR_DOWN,
+        2 => DIR_LEFT,
+        3 => DIR_UP,
+        _ => panic!(),
+    }
+}
 
 fn get_facing(dir: Direction) -> i32 {
     match dir {
@@ -133,14 +64,16 @@ enum Tile {
     Wall,
 }
 
-struct Board {
+type FaceEdges = HashMap<i32, (Face, i32)>;
+
+pub struct Board {
     width: usize,
     height: usize,
     tiles: Vec<Option<Tile>>,
     face_size: usize,
     face_max_x: i32,
     face_max_y: i32,
-    faces: HashMap<Face, RefCell<HashMap<i32, (Face, i32)>>>,
+    faces: HashMap<Face, RefCell<FaceEdges>>,
 }
 
 impl Board {
@@ -163,10 +96,7 @@ impl Board {
         let x = self
             .tiles
             .iter()
-            .position(|o| match o {
-                Some(Tile::Empty) => true,
-                _ => false,
-            })
+            .position(|o| matches!(o, Some(Tile::Empty)))
             .unwrap();
         Coord::new(x as i32, y)
     }
@@ -181,12 +111,11 @@ impl Board {
     fn find_next_position_cube(&self, pos: Coord, dir: Direction) -> Option<(Coord, Direction)> {
         fn advance_cube(this: &Board, pos: Coord, dir: Direction) -> (Coord, Direction) {
             let fc = this.get_tile_face(pos);
-            let pos2 = this.wrap_pos(pos.add_dir(dir));
+            let pos2 = pos + dir;
             let fc2 = this.get_tile_face(pos2);
-            if fc == fc2 {
-                return (pos2, dir);
-            }
-            if this.faces.contains_key(&fc2) {
+            // A face across the edge of the board is not adjacent on the cube.
+            let on_board = this.wrap_pos(pos2) == pos2;
+            if on_board && (fc == fc2 || this.faces.contains_key(&fc2)) {
                 return (pos2, dir);
             }
             let e = this.face_size as i32 - 1;
@@ -195,7 +124,7 @@ impl Board {
             let o = pos - this.get_face_origin(fc);
 
             let (face2, facing2) = face.get(&facing).unwrap();
-            let dir2 = Coord::from_facing(*facing2);
+            let dir2 = from_facing(*facing2);
             let o2 = match dir {
                 DIR_RIGHT => match dir2 {
                     DIR_RIGHT => Coord::new(e, e - o.y),
@@ -227,7 +156,7 @@ impl Board {
                 },
                 _ => panic!(),
             };
-            (this.get_face_origin(*face2) + o2, dir2.turn_around())
+            (this.get_face_origin(*face2) + o2, -dir2)
         }
         let (next_pos, next_dir) = advance_cube(self, pos, dir);
         match self.tile(next_pos.x as usize, next_pos.y as usize) {
@@ -239,7 +168,7 @@ impl Board {
 
     fn find_next_position(&self, pos: Coord, dir: Direction) -> Option<Coord> {
         fn advance(this: &Board, pos: Coord, dir: Direction) -> Coord {
-            this.wrap_pos(pos.add_dir(dir))
+            this.wrap_pos(pos + dir)
         }
         let mut next_pos = advance(self, pos, dir);
         loop {
@@ -255,24 +184,18 @@ impl Board {
     }
 
     fn get_tile_face(&self, pos: Coord) -> Face {
-        Face {
-            x: pos.x / (self.face_size as i32),
-            y: pos.y / (self.face_size as i32),
-        }
+        pos / self.face_size as i32
     }
 
     fn get_face_origin(&self, face: Face) -> Coord {
-        Coord {
-            x: face.x * self.face_size as i32,
-            y: face.y * self.face_size as i32,
-        }
+        face * self.face_size as i32
     }
 
     fn map_faces(&mut self) {
         for (f, neighbours) in self.faces.iter() {
             for facing in 0..4 {
-                let d = Coord::from_facing(facing);
-                let f2 = f.add_dir(d);
+                let d = from_facing(facing);
+                let f2 = *f + d;
                 if self.faces.contains_key(&f2) {
                     neighbours
                         .borrow_mut()
@@ -317,7 +240,7 @@ impl Board {
 }
 
 #[derive(Debug)]
-enum Path {
+pub enum Path {
     TurnRight,
     TurnLeft,
     Walk(u32),
@@ -368,8 +291,8 @@ impl Actor {
     }
 }
 
-fn parse_board(lines: &Vec<String>, face_size: usize) -> Board {
-    let width = lines.iter().map(|l| l.len()).max().unwrap();
+fn parse_board(lines: &[&str], face_size: usize) -> ParseResult<Board> {
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
     let height = lines.len();
     let mut board = Board {
         width,
@@ -382,11 +305,12 @@ fn parse_board(lines: &Vec<String>, face_size: usize) -> Board {
     };
 
     for (y, l) in lines.iter().enumerate() {
-        for (x, c) in l.chars().enumerate() {
+        for (x, c) in l.char_indices() {
             let t = match c {
                 '.' => Some(Tile::Empty),
                 '#' => Some(Tile::Wall),
-                _ => None,
+                ' ' => None,
+                _ => return Err(ParseError::new(&l[x..], "expected `.`, `#` or a space")),
             };
             *board.tile_mut(x, y) = t;
             if t.is_some() {
@@ -400,70 +324,84 @@ fn parse_board(lines: &Vec<String>, face_size: usize) -> Board {
             }
         }
     }
-    board
+    Ok(board)
 }
 
-fn parse_path(line: &String) -> Vec<Path> {
+fn parse_path(line: &str) -> ParseResult<Vec<Path>> {
     let mut r = Vec::<Path>::new();
-    let mut s = String::new();
-    for c in line.chars() {
-        match c {
-            'R' => {
-                if !s.is_empty() {
-                    r.push(Path::Walk(s.parse::<u32>().unwrap()));
-                    s.clear();
-                }
-                r.push(Path::TurnRight);
-            }
-            'L' => {
-                if !s.is_empty() {
-                    r.push(Path::Walk(s.parse::<u32>().unwrap()));
-                    s.clear();
-                }
-                r.push(Path::TurnLeft);
-            }
-            '0'..='9' => s.push(c),
-            _ => panic!("Unexpected path"),
+    let mut start = 0;
+    for (i, c) in line.char_indices() {
+        let turn = match c {
+            'R' => Path::TurnRight,
+            'L' => Path::TurnLeft,
+            '0'..='9' => continue,
+            _ => return Err(ParseError::new(&line[i..], "expected `R`, `L` or a number")),
         };
+        if start < i {
+            r.push(Path::Walk(line[start..i].parse_as()?));
+        }
+        r.push(turn);
+        start = i + 1;
     }
-    if !s.is_empty() {
-        r.push(Path::Walk(s.parse::<u32>().unwrap()));
+    if start < line.len() {
+        r.push(Path::Walk(line[start..].parse_as()?));
     }
-    r
+    Ok(r)
 }
 
-fn main() {
-    let mut lines: Vec<_> = io::stdin().lines().map(|l| l.unwrap()).collect();
-    let path_str = lines.pop().unwrap();
-    lines.pop();
-
-    let mut board = parse_board(&lines, 50);
-    board.map_faces();
-    let path = parse_path(&path_str);
+fn password(board: &Board, path: &[Path], cube: bool) -> i32 {
     let mut actor = Actor {
         pos: board.find_initial_position(),
-        dir: Direction::new(1, 0),
-        cube: false,
-    };
-    let mut actor2 = Actor {
-        pos: board.find_initial_position(),
-        dir: Direction::new(1, 0),
-        cube: true,
+        dir: DIR_RIGHT,
+        cube,
     };
     for s in path {
-        actor.act(&s, &board);
-        actor2.act(&s, &board);
+        actor.act(s, board);
     }
 
-    let row1 = actor.pos.y + 1;
-    let column1 = actor.pos.x + 1;
-    let facing1 = get_facing(actor.dir);
-    let r1 = 1000 * row1 + 4 * column1 + facing1;
-    println!("{}", r1);
-
-    let row2 = actor2.pos.y + 1;
-    let column2 = actor2.pos.x + 1;
-    let facing2 = get_facing(actor2.dir);
-    let r2 = 1000 * row2 + 4 * column2 + facing2;
-    println!("{}", r2);
+    let row = actor.pos.y + 1;
+    let column = actor.pos.x + 1;
+    let facing = get_facing(actor.dir);
+    1000 * row + 4 * column + facing
+}
+
+params! {
+    pub struct Params {
+        /// Length of the edges of the cube faces
+        pub face_size: usize = 50,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Board, Vec<Path>);
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> ParseResult<Self::Input<'a>> {
+        let (board, path) = input
+            .trim_end()
+            .rsplit_once("\n\n")
+            .ok_or_else(|| ParseError::after(input, "expected the path after the board"))?;
+
+        let lines: Vec<_> = board.lines().collect();
+        let mut board = parse_board(&lines, params.face_size)?;
+        if board.tiles.iter().all(Option::is_none) {
+            return Err(ParseError::new(input, "expected a board"));
+        }
+        board.map_faces();
+        Ok((board, parse_path(path)?))
+    }
+
+    fn part1((board, path): &Self::Input<'_>) -> impl Into<Answer> {
+        password(board, path, false)
+    }
+
+    fn part2((board, path): &Self::Input<'_>) -> impl Into<Answer> {
+        password(board, path, true)
+    }
 }
@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::render::{self, Frame, Rgb};
+use aoc_common::{Answer, ParseError, ParseResult, Solution, Vec2};
+
+pub type Coord = Vec2<i32>;
+
+fn parse_elves(input: &str) -> ParseResult<HashSet<Coord>> {
+    let mut elves = HashSet::<Coord>::new();
+    for (y, l) in input.lines().enumerate() {
+        for (x, c) in l.char_indices() {
+            match c {
+                '#' => {
+                    elves.insert(Coord::new(x as i32, y as i32));
+                }
+                '.' => {}
+                _ => return Err(ParseError::new(&l[x..], "expected `#` or `.`")),
+            }
+        }
+    }
+    Ok(elves)
+}
+
+fn are_other_elves_around(elves: &HashSet<Coord>, pos: Coord) -> bool {
+    Coord::ADJACENT.iter().any(|&d| elves.contains(&(pos + d)))
+}
+
+fn check_direction(elves: &HashSet<Coord>, pos: Coord, dir: Coord) -> bool {
+    [dir, dir + dir.turn_left(), dir + dir.turn_right()]
+        .iter()
+        .all(|&d| !elves.contains(&(pos + d)))
+}
+
+fn propose_moves(
+    elves: &HashSet<Coord>,
+    directions: &[Coord],
+) -> (HashMap<Coord, HashSet<Coord>>, usize) {
+    let mut moves = HashMap::<Coord, HashSet<Coord>>::new();
+    let mut elves_without_move = 0;
+    for e in elves {
+        let mut moved = false;
+        if are_other_elves_around(elves, *e) {
+            for &d in directions {
+                if check_direction(elves, *e, d) {
+                    let p2 = *e + d;
+                    moves.entry(p2).or_default();
+                    moves.get_mut(&p2).unwrap().insert(*e);
+                    moved = true;
+                    break;
+                }
+            }
+        } else {
+            elves_without_move += 1;
+        }
+        if !moved {
+            let mut o = HashSet::<Coord>::new();
+            o.insert(*e);
+            moves.insert(*e, o);
+        }
+    }
+    (moves, elves_without_move)
+}
+
+fn apply_moves(proposed_moves: &HashMap<Coord, HashSet<Coord>>) -> HashSet<Coord> {
+    let mut elves = HashSet::<Coord>::new();
+    for (d, origins) in proposed_moves {
+        if origins.len() > 1 {
+            for o in origins {
+                elves.insert(*o);
+            }
+            continue;
+        }
+        elves.insert(*d);
+    }
+    elves
+}
+
+fn find_smallest_rect(elves: &HashSet<Coord>) -> (Coord, Coord) {
+    let tl = elves
+        .iter()
+        .copied()
+        .reduce(|a, b| Coord::new(a.x.min(b.x), a.y.min(b.y)))
+        .unwrap();
+    let br = elves
+        .iter()
+        .copied()
+        .reduce(|a, b| Coord::new(a.x.max(b.x), a.y.max(b.y)))
+        .unwrap();
+    (tl, br)
+}
+
+const PALETTE: &[Rgb] = &[[16, 16, 32], [80, 200, 120]];
+
+/// Draws the smallest rectangle holding the elves.
+fn draw(elves: &HashSet<Coord>) -> Frame {
+    let (tl, br) = find_smallest_rect(elves);
+    let width = (br.x - tl.x + 1) as usize;
+    let height = (br.y - tl.y + 1) as usize;
+    Frame::new(width, height, PALETTE, |x, y| {
+        elves.contains(&(tl + Coord::new(x as i32, y as i32))) as u8
+    })
+}
+
+fn do_round(elves: &HashSet<Coord>, directions: &mut [Coord]) -> (HashSet<Coord>, bool) {
+    let (moves, elves_without_move) = propose_moves(elves, directions);
+    directions.rotate_left(1);
+    let elves2 = apply_moves(&moves);
+    render::record(|| draw(&elves2));
+    (elves2, elves_without_move == elves.len())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = HashSet<Coord>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        parse_elves(input)
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut elves = elves.clone();
+        let mut directions = vec![Coord::UP, Coord::DOWN, Coord::LEFT, Coord::RIGHT];
+        for _ in 0..10 {
+            elves = do_round(&elves, &mut directions).0;
+        }
+        let (tl, br) = find_smallest_rect(&elves);
+        let w = br.x - tl.x + 1;
+        let h = br.y - tl.y + 1;
+        w * h - elves.len() as i32
+    }
+
+    fn part2(elves: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut elves = elves.clone();
+        let mut directions = vec![Coord::UP, Coord::DOWN, Coord::LEFT, Coord::RIGHT];
+        let mut r2 = 1;
+        loop {
+            let (next, finished) = do_round(&elves, &mut directions);
+            if finished {
+                break r2;
+            }
+            elves = next;
+            r2 += 1;
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::render::{self, Frame, Rgb};
+use aoc_common::search::bfs;
+use aoc_common::{Answer, ParseError, ParseResult, Solution, Vec2};
+
+type Coord = Vec2<i32>;
+
+#[derive(Clone)]
+struct Blizzard {
+    pos: Coord,
+    dir: Coord,
+}
+
+impl Blizzard {
+    fn new(pos: Coord, dir: Coord) -> Self {
+        Self { pos, dir }
+    }
+}
+
+const PALETTE: &[Rgb] = &[
+    [16, 16, 32],
+    [120, 120, 120],
+    [90, 140, 220],
+    [140, 180, 240],
+    [190, 215, 250],
+    [240, 245, 255],
+];
+
+#[derive(Clone)]
+pub struct Valley {
+    width: i32,
+    height: i32,
+    start: Coord,
+    goal: Coord,
+    blizzards: Vec<Blizzard>,
+}
+
+impl Valley {
+    fn tick(&mut self) -> HashSet<Coord> {
+        let mut blizz_positions = HashSet::<Coord>::new();
+        for b in self.blizzards.iter_mut() {
+            b.pos += b.dir;
+            if b.pos.x == self.width - 1 {
+                b.pos.x = 1;
+            } else if b.pos.x == 0 {
+                b.pos.x = self.width - 2;
+            }
+            if b.pos.y == self.height - 1 {
+                b.pos.y = 1;
+            } else if b.pos.y == 0 {
+                b.pos.y = self.height - 2;
+            }
+            blizz_positions.insert(b.pos);
+        }
+        render::record(|| self.draw());
+        blizz_positions
+    }
+
+    /// Draws the valley, shading blizzards by how many share a tile.
+    fn draw(&self) -> Frame {
+        let mut counts = HashMap::<Coord, u8>::new();
+        for b in &self.blizzards {
+            *counts.entry(b.pos).or_default() += 1;
+        }
+        Frame::new(
+            self.width as usize,
+            self.height as usize,
+            PALETTE,
+            |x, y| {
+                let p = Coord::new(x as i32, y as i32);
+                let wall =
+                    x == 0 || y == 0 || x as i32 == self.width - 1 || y as i32 == self.height - 1;
+                if wall && p != self.start && p != self.goal {
+                    1
+                } else {
+                    counts.get(&p).map_or(0, |&n| 1 + n.min(4))
+                }
+            },
+        )
+    }
+}
+
+fn parse_valley(input: &str) -> ParseResult<Valley> {
+    let lines: Vec<_> = input.lines().collect();
+    let (Some(first_line), Some(last_line)) = (lines.first(), lines.last()) else {
+        return Err(ParseError::new(input, "expected a valley"));
+    };
+
+    let width = first_line.len() as i32;
+    let height = lines.len() as i32;
+
+    let gap = |l: &str| {
+        l.find('.')
+            .ok_or_else(|| ParseError::new(l, "expected a gap in the wall"))
+    };
+    let start = Coord::new(gap(first_line)? as i32, 0);
+    let goal = Coord::new(gap(last_line)? as i32, height - 1);
+    let mut blizzards = vec![];
+
+    for (y, l) in lines.iter().enumerate() {
+        for (x, c) in l.char_indices() {
+            let dir = match c {
+                '>' => Coord::RIGHT,
+                '<' => Coord::LEFT,
+                '^' => Coord::UP,
+                'v' => Coord::DOWN,
+                '#' | '.' => continue,
+                _ => return Err(ParseError::new(&l[x..], "expected `#`, `.` or a blizzard")),
+            };
+            blizzards.push(Blizzard::new(Coord::new(x as i32, y as i32), dir));
+        }
+    }
+    Ok(Valley {
+        width,
+        height,
+        start,
+        goal,
+        blizzards,
+    })
+}
+
+fn get_available_positions(pos: Coord, v: &Valley, blizz_positions: &HashSet<Coord>) -> Vec<Coord> {
+    let mut r = vec![];
+    for d in [Coord::LEFT, Coord::RIGHT, Coord::DOWN, Coord::UP] {
+        let c = pos + d;
+        if (c.x <= 0 || c.y <= 0 || c.x >= v.width - 1 || c.y >= v.height - 1)
+            && c != v.start
+            && c != v.goal
+        {
+            continue;
+        }
+        if blizz_positions.contains(&c) {
+            continue;
+        }
+        r.push(c);
+    }
+    if !blizz_positions.contains(&pos) {
+        r.push(pos);
+    }
+    r
+}
+
+fn make_key2(blizz_positions: &HashSet<Coord>) -> String {
+    let mut v: Vec<_> = blizz_positions.iter().collect();
+    v.sort();
+    format!("{:?}", v)
+}
+
+fn find_all_states(valley: &mut Valley) -> Vec<HashSet<Coord>> {
+    let initial_blizz: HashSet<_> = valley.blizzards.iter().map(|b| b.pos).collect();
+    let key = make_key2(&initial_blizz);
+
+    let mut seen = HashMap::<String, usize>::new();
+    seen.insert(key, 0);
+    let mut blizz_states = vec![initial_blizz];
+
+    let mut minutes = 1;
+
+    loop {
+        let blizz_positions = valley.tick();
+        let key = make_key2(&blizz_positions);
+        if seen.insert(key, minutes).is_some() {
+            break;
+        }
+        blizz_states.push(blizz_positions);
+        minutes += 1;
+    }
+    blizz_states
+}
+
+/// Minute at which `goal` is reached when leaving `start` at `starting_minute`.
+fn find_shortest_path(
+    starting_minute: usize,
+    start: Coord,
+    goal: Coord,
+    valley: &Valley,
+    blizz_states: &[HashSet<Coord>],
+) -> usize {
+    let period = blizz_states.len();
+    let next = |&(pos, state_idx): &(Coord, usize)| {
+        let next_state_idx = (state_idx + 1) % period;
+        let blizz_positions = &blizz_states[next_state_idx];
+        get_available_positions(pos, valley, blizz_positions)
+            .into_iter()
+            .map(move |np| (np, next_state_idx))
+    };
+    let (_, minutes) = bfs([(start, starting_minute % period)], next, |&(pos, _)| {
+        pos == goal
+    })
+    .expect("There does not seem to be a path");
+    starting_minute + minutes
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Valley;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        parse_valley(input)
+    }
+
+    fn part1(valley: &Self::Input<'_>) -> impl Into<Answer> {
+        let blizz_states = find_all_states(&mut valley.clone());
+        find_shortest_path(0, valley.start, valley.goal, valley, &blizz_states)
+    }
+
+    fn part2(valley: &Self::Input<'_>) -> impl Into<Answer> {
+        let blizz_states = find_all_states(&mut valley.clone());
+        let r1 = find_shortest_path(0, valley.start, valley.goal, valley, &blizz_states);
+        let r2a = find_shortest_path(r1, valley.goal, valley.start, valley, &blizz_states);
+        find_shortest_path(r2a, valley.start, valley.goal, valley, &blizz_states)
+    }
+}
@@ -1,25 +1,31 @@
-use core::panic;
-use std::io;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
 
-fn convert_from_snafu_digit(c: char) -> i64 {
+fn convert_from_snafu_digit(c: char) -> Option<i64> {
     match c {
-        '2' => 2,
-        '1' => 1,
-        '0' => 0,
-        '-' => -1,
-        '=' => -2,
-        _ => panic!(),
+        '2' => Some(2),
+        '1' => Some(1),
+        '0' => Some(0),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None,
     }
 }
 
-fn convert_from_snafu(snafu: &String) -> i64 {
-    let dec = snafu
-        .chars()
+fn convert_from_snafu(snafu: &str) -> ParseResult<i64> {
+    snafu
+        .char_indices()
         .rev()
         .enumerate()
-        .map(|(i, c)| 5_i64.pow(i as u32) * convert_from_snafu_digit(c))
-        .sum::<i64>();
-    dec
+        .map(|(i, (j, c))| {
+            let digit = convert_from_snafu_digit(c).ok_or_else(|| {
+                ParseError::new(
+                    &snafu[j..j + c.len_utf8()],
+                    "expected `2`, `1`, `0`, `-` or `=`",
+                )
+            })?;
+            Ok(5_i64.pow(i as u32) * digit)
+        })
+        .sum()
 }
 
 fn convert_to_snafu_number(dec: i64) -> (i64, i64) {
@@ -79,10 +85,19 @@ fn convert_to_snafu(mut dec: i64) -> String {
     snafu
 }
 
-fn main() {
-    let lines: Vec<_> = io::stdin().lines().map(|l| l.unwrap()).collect();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<i64>;
+    type Params = ();
+
+    const HAS_PART2: bool = false;
 
-    let dec = lines.iter().map(|l| convert_from_snafu(l)).sum::<i64>();
-    let r1 = convert_to_snafu(dec);
-    println!("{}", r1);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(convert_from_snafu).collect()
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> impl Into<Answer> {
+        convert_to_snafu(numbers.iter().sum::<i64>())
+    }
 }
@@ -0,0 +1,77 @@
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+#[derive(Default)]
+pub struct Set {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+fn parse(line: &str) -> ParseResult<(u32, Vec<Set>)> {
+    let (id, sets) = line.strip_prefix_or_err("Game ")?.split_once_or_err(": ")?;
+    let sets = sets
+        .split("; ")
+        .map(|cubes| {
+            cubes.split(", ").try_fold(Set::default(), |mut set, cube| {
+                let (n, color) = cube.split_once_or_err(" ")?;
+                let n = n.parse_as()?;
+                match color {
+                    "red" => set.red = n,
+                    "green" => set.green = n,
+                    "blue" => set.blue = n,
+                    _ => return Err(ParseError::new(color, "expected a color")),
+                };
+                Ok(set)
+            })
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+    Ok((id.parse_as()?, sets))
+}
+
+const LIMIT: Set = Set {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(u32, Set)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(parse)
+            .map(|game| {
+                let (id, sets) = game?;
+                let m = sets.iter().fold(Set::default(), |mut a, set| {
+                    a.red = a.red.max(set.red);
+                    a.green = a.green.max(set.green);
+                    a.blue = a.blue.max(set.blue);
+                    a
+                });
+                Ok((id, m))
+            })
+            .collect()
+    }
+
+    fn part1(x: &Self::Input<'_>) -> impl Into<Answer> {
+        x.iter()
+            .filter_map(|(id, m)| {
+                if m.red <= LIMIT.red && m.green <= LIMIT.green && m.blue <= LIMIT.blue {
+                    Some(id)
+                } else {
+                    None
+                }
+            })
+            .sum::<u32>()
+    }
+
+    fn part2(x: &Self::Input<'_>) -> impl Into<Answer> {
+        x.iter()
+            .map(|(_id, m)| m.red * m.green * m.blue)
+            .sum::<u32>()
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
+
+pub struct Card {
+    pub id: u32,
+    pub numbers: Vec<u32>,
+    pub winning: Vec<u32>,
+}
+
+fn parse_numbers(s: &str) -> ParseResult<Vec<u32>> {
+    let mut numbers = s
+        .split_whitespace()
+        .map(|n| n.parse_as())
+        .collect::<ParseResult<Vec<_>>>()?;
+    numbers.sort();
+    Ok(numbers)
+}
+
+fn parse(line: &str) -> ParseResult<Card> {
+    let (id, rest) = line.strip_prefix_or_err("Card")?.split_once_or_err(":")?;
+    let (numbers, winning) = rest.split_once_or_err("|")?;
+    Ok(Card {
+        id: id.trim_start().parse_as()?,
+        numbers: parse_numbers(numbers)?,
+        winning: parse_numbers(winning)?,
+    })
+}
+
+fn count_matching(numbers: &[u32], winning: &[u32]) -> u32 {
+    let count = numbers
+        .iter()
+        .filter_map(|n| winning.binary_search(n).ok())
+        .count() as u32;
+    count
+}
+
+fn count_winning(numbers: &[u32], winning: &[u32]) -> u32 {
+    let count = count_matching(numbers, winning);
+    match count {
+        0 => 0u32,
+        1 => 1u32,
+        _ => 2u32.pow(count - 1),
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Card>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(parse).collect()
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> impl Into<Answer> {
+        cards
+            .iter()
+            .map(|c| count_winning(&c.numbers, &c.winning))
+            .sum::<u32>()
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut m = HashMap::<u32, u32>::new();
+        cards.iter().for_each(|c| {
+            m.insert(c.id, 1);
+        });
+        cards.iter().for_each(|c| {
+            let w0 = *m.get(&c.id).unwrap();
+            let w = count_matching(&c.numbers, &c.winning);
+            (c.id + 1..=c.id + w).for_each(|i| {
+                *m.get_mut(&i).unwrap() += w0;
+            });
+        });
+        m.values().sum::<u32>()
+    }
+}
@@ -0,0 +1,227 @@
+use aoc_common::{
+    Answer, InputExt, Interval, IntervalSet, ParseError, ParseExt, ParseResult, PiecewiseLinearMap,
+    Solution,
+};
+use itertools::Itertools;
+
+type ConversionMap = PiecewiseLinearMap<u64>;
+
+#[derive(Clone)]
+struct Maps {
+    pub seed_to_soil: ConversionMap,
+    pub soil_to_fertilizer: ConversionMap,
+    pub fertilizer_to_water: ConversionMap,
+    pub water_to_light: ConversionMap,
+    pub light_to_temperature: ConversionMap,
+    pub temperature_to_humidity: ConversionMap,
+    pub humidity_to_location: ConversionMap,
+}
+
+fn parse_conversion_maps<'a>(
+    sections: &mut impl Iterator<Item = &'a str>,
+    header: &str,
+) -> ParseResult<ConversionMap> {
+    let section = sections
+        .next()
+        .ok_or_else(|| ParseError::new("", format!("expected `{header}`")))?;
+    let (name, lines) = section.split_once('\n').unwrap_or((section, ""));
+    if name != header {
+        return Err(ParseError::new(name, format!("expected `{header}`")));
+    }
+    let mut map = ConversionMap::new();
+    for line in lines.lines() {
+        let (dest_start, rest) = line.split_once_or_err(" ")?;
+        let (src_start, length) = rest.split_once_or_err(" ")?;
+        let src_start: u64 = src_start.parse_as()?;
+        let source = Interval::new(src_start, src_start + length.parse_as::<u64>()?);
+        if !map.insert(source, dest_start.parse_as()?) {
+            return Err(ParseError::new(line, "expected ranges not to overlap"));
+        }
+    }
+    Ok(map)
+}
+
+fn parse<'a>(sections: &mut impl Iterator<Item = &'a str>) -> ParseResult<Maps> {
+    Ok(Maps {
+        seed_to_soil: parse_conversion_maps(sections, "seed-to-soil map:")?,
+        soil_to_fertilizer: parse_conversion_maps(sections, "soil-to-fertilizer map:")?,
+        fertilizer_to_water: parse_conversion_maps(sections, "fertilizer-to-water map:")?,
+        water_to_light: parse_conversion_maps(sections, "water-to-light map:")?,
+        light_to_temperature: parse_conversion_maps(sections, "light-to-temperature map:")?,
+        temperature_to_humidity: parse_conversion_maps(sections, "temperature-to-humidity map:")?,
+        humidity_to_location: parse_conversion_maps(sections, "humidity-to-location map:")?,
+    })
+}
+
+fn merge_maps(maps: &Maps) -> ConversionMap {
+    maps.seed_to_soil
+        .then(&maps.soil_to_fertilizer)
+        .then(&maps.fertilizer_to_water)
+        .then(&maps.water_to_light)
+        .then(&maps.light_to_temperature)
+        .then(&maps.temperature_to_humidity)
+        .then(&maps.humidity_to_location)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<u64>, ConversionMap);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let mut sections = input.paragraphs();
+        let seeds_line = sections
+            .next()
+            .unwrap_or_default()
+            .strip_prefix_or_err("seeds:")?;
+        let seeds = seeds_line
+            .split_whitespace()
+            .map(|t| t.parse_as())
+            .collect::<ParseResult<Vec<u64>>>()?;
+        // Part 2 reads the seeds as pairs of a start and a length.
+        if seeds.is_empty() || seeds.len() % 2 != 0 {
+            return Err(ParseError::after(
+                seeds_line,
+                "expected pairs of a seed and a range length",
+            ));
+        }
+        let maps = parse(&mut sections)?;
+        Ok((seeds, merge_maps(&maps)))
+    }
+
+    fn part1((seeds, merged_maps): &Self::Input<'_>) -> impl Into<Answer> {
+        seeds.iter().map(|&s| merged_maps.apply(s)).min().unwrap()
+    }
+
+    fn part2((seeds, merged_maps): &Self::Input<'_>) -> impl Into<Answer> {
+        let seeds2 = seeds
+            .iter()
+            .tuples()
+            .map(|(&start, &length)| Interval::new(start, start + length))
+            .collect::<IntervalSet<_>>();
+
+        // The intervals of a set are in increasing order, so the lowest
+        // location starts the first one.
+        merged_maps
+            .image(&seeds2)
+            .intervals()
+            .first()
+            .map(|i| i.start)
+            .expect("some seed range should not be empty")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const HEADERS: [&str; 7] = [
+        "seed-to-soil map:",
+        "soil-to-fertilizer map:",
+        "fertilizer-to-water map:",
+        "water-to-light map:",
+        "light-to-temperature map:",
+        "temperature-to-humidity map:",
+        "humidity-to-location map:",
+    ];
+
+    /// Ranges `(dest_start, src_start, length)` of a map, not overlapping.
+    fn ranges() -> impl Strategy<Value = Vec<(u64, u64, u64)>> {
+        prop::collection::vec((0..10u64, 1..10u64, 0..150u64), 0..5).prop_map(|pieces| {
+            let mut end = 0;
+            pieces
+                .into_iter()
+                .map(|(gap, length, dest)| {
+                    let src = end + gap;
+                    end = src + length;
+                    (dest, src, length)
+                })
+                .collect()
+        })
+    }
+
+    fn input(seeds: &[(u64, u64)], maps: &[Vec<(u64, u64, u64)>]) -> String {
+        let seeds = seeds
+            .iter()
+            .map(|(s, l)| format!(" {s} {l}"))
+            .collect::<String>();
+        let mut input = format!("seeds:{seeds}\n");
+        for (header, ranges) in HEADERS.iter().zip(maps) {
+            input.push_str(&format!("\n{header}\n"));
+            for (dest, src, length) in ranges {
+                input.push_str(&format!("{dest} {src} {length}\n"));
+            }
+        }
+        input
+    }
+
+    /// Runs a value through every map, one range at a time.
+    fn naive_location(maps: &[Vec<(u64, u64, u64)>], seed: u64) -> u64 {
+        maps.iter().fold(seed, |v, ranges| {
+            ranges
+                .iter()
+                .find(|&&(_, src, length)| (src..src + length).contains(&v))
+                .map_or(v, |&(dest, src, _)| dest + v - src)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn merged_maps_match_naive(
+            seeds in prop::collection::vec((0..100u64, 1..10u64), 1..4),
+            maps in prop::collection::vec(ranges(), 7),
+        ) {
+            let input = Day::parse(&input(&seeds, &maps)).unwrap();
+            let part1 = seeds
+                .iter()
+                .flat_map(|&(s, l)| [s, l])
+                .map(|s| naive_location(&maps, s))
+                .min()
+                .unwrap();
+            let part2 = seeds
+                .iter()
+                .flat_map(|&(s, l)| s..s + l)
+                .map(|s| naive_location(&maps, s))
+                .min()
+                .unwrap();
+            prop_assert_eq!(Day::part1(&input).into(), Answer::from(part1));
+            prop_assert_eq!(Day::part2(&input).into(), Answer::from(part2));
+        }
+    }
+
+    #[test]
+    fn odd_seed_count() {
+        let maps = vec![vec![]; 7];
+        let odd = input(&[(79, 14)], &maps).replacen(" 14", "", 1);
+        let e = Day::parse(&odd).err().unwrap();
+        assert_eq!(e.message, "expected pairs of a seed and a range length");
+        assert!(Day::parse(&input(&[], &maps)).is_err());
+        assert!(Day::parse(&input(&[(79, 14)], &maps)).is_ok());
+    }
+
+    #[test]
+    fn merge_maps() {
+        let to_map = |m: &[(u64, u64, u64)]| {
+            m.iter()
+                .map(|&(dest_start, src_start, length)| {
+                    (Interval::new(src_start, src_start + length), dest_start)
+                })
+                .collect::<ConversionMap>()
+        };
+        let c1 = to_map(&[(50, 98, 2), (52, 50, 48)]);
+        let c2 = to_map(&[(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+
+        let c = c1.then(&c2);
+
+        (0..=100).for_each(|i| {
+            let expected = c1.apply(i);
+            let expected = c2.apply(expected);
+
+            let result = c.apply(i);
+
+            assert_eq!(expected, result);
+        });
+    }
+}
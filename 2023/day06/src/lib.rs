@@ -0,0 +1,58 @@
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+fn parse(s: &str, label: &str) -> ParseResult<Vec<u64>> {
+    s.strip_prefix_or_err(label)?
+        .split_whitespace()
+        .map(|x| x.parse_as())
+        .collect()
+}
+
+fn count_ways_to_win(time: u64, distance: u64) -> u64 {
+    let r = (0..=time)
+        .map(|charging_time| {
+            let speed = charging_time;
+            let remaining_time = time - charging_time;
+            speed * remaining_time
+        })
+        .filter(|&x| x > distance);
+    r.count() as u64
+}
+
+fn fix_number(v: &[u64]) -> u64 {
+    v.iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join("")
+        .parse::<u64>()
+        .unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<u64>, Vec<u64>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let (time, distance) = input.trim_end().split_once_or_err("\n")?;
+        let time = parse(time, "Time:")?;
+        let distance = parse(distance, "Distance:")?;
+        if time.len() != distance.len() {
+            return Err(ParseError::new(input, "expected a distance for every time"));
+        }
+        Ok((time, distance))
+    }
+
+    fn part1((time, distance): &Self::Input<'_>) -> impl Into<Answer> {
+        time.iter()
+            .zip(distance.iter())
+            .map(|(&time, &distance)| count_ways_to_win(time, distance))
+            .product::<u64>()
+    }
+
+    fn part2((time, distance): &Self::Input<'_>) -> impl Into<Answer> {
+        let time = fix_number(time);
+        let distance = fix_number(distance);
+        count_ways_to_win(time, distance)
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use aoc_common::number::{crt, smallest_at_least};
+use aoc_common::{Answer, InputExt, ParseError, ParseExt, ParseResult, Solution};
+
+fn parse(s: &str) -> ParseResult<(&str, (&str, &str))> {
+    let (node, next) = s.split_once_or_err(" = ")?;
+    let (left, right) = next
+        .strip_prefix_or_err("(")?
+        .strip_suffix_or_err(")")?
+        .split_once_or_err(", ")?;
+    Ok((node, (left, right)))
+}
+
+struct State {
+    instruction_idx: usize,
+    node: String,
+    steps: usize,
+}
+
+fn process(s: &State, instructions: &str, nodes: &HashMap<&str, (&str, &str)>) -> State {
+    let instruction = instructions.as_bytes()[s.instruction_idx] as char;
+    let (left, right) = nodes.get(s.node.as_str()).unwrap();
+    let instruction_idx = if s.instruction_idx + 1 >= instructions.len() {
+        0
+    } else {
+        s.instruction_idx + 1
+    };
+    let node = match instruction {
+        'L' => *left,
+        'R' => *right,
+        _ => panic!("Invalid instruction"),
+    };
+    State {
+        instruction_idx,
+        node: node.to_string(),
+        steps: s.steps + 1,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (&'a str, HashMap<&'a str, (&'a str, &'a str)>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let [instructions, nodes] = input.split_blocks()?;
+        if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+            return Err(ParseError::new(&instructions[i..], "expected `L` or `R`"));
+        }
+        let nodes = nodes
+            .lines()
+            .map(parse)
+            .collect::<ParseResult<HashMap<_, _>>>()?;
+        for (left, right) in nodes.values() {
+            if let Some(n) = [left, right].into_iter().find(|n| !nodes.contains_key(*n)) {
+                return Err(ParseError::new(n, "unknown node"));
+            }
+        }
+        Ok((instructions, nodes))
+    }
+
+    fn part1((instructions, nodes): &Self::Input<'_>) -> impl Into<Answer> {
+        let s0 = State {
+            instruction_idx: 0,
+            node: "AAA".to_string(),
+            steps: 0,
+        };
+        let mut s = s0;
+        while s.node != "ZZZ" {
+            s = process(&s, instructions, nodes);
+        }
+        s.steps
+    }
+
+    /// Each ghost is assumed to pass a single `Z` node on each loop around
+    /// its path, which need not start at its first step.
+    fn part2((instructions, nodes): &Self::Input<'_>) -> impl Into<Answer> {
+        let walk_to_z = |s: &State| {
+            let mut s = process(s, instructions, nodes);
+            while !s.node.ends_with('Z') {
+                s = process(&s, instructions, nodes);
+            }
+            s
+        };
+        let ghosts = nodes
+            .keys()
+            .filter(|n| n.ends_with('A'))
+            .map(|n| {
+                let first = walk_to_z(&State {
+                    instruction_idx: 0,
+                    node: n.to_string(),
+                    steps: 0,
+                });
+                let second = walk_to_z(&first);
+                (first.steps as i64, (second.steps - first.steps) as i64)
+            })
+            .collect::<Vec<_>>();
+
+        let (steps, period) = crt(ghosts.iter().copied()).expect("the ghosts never meet");
+        let first = ghosts.iter().map(|&(steps, _)| steps).max().unwrap();
+        smallest_at_least(steps, period, first)
+    }
+}
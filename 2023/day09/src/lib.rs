@@ -1,11 +1,8 @@
-use std::io;
-
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
 use itertools::Itertools;
 
-fn parse(line: String) -> Vec<i32> {
-    line.split_whitespace()
-        .map(|s| s.parse().unwrap())
-        .collect()
+fn parse(line: &str) -> ParseResult<Vec<i32>> {
+    line.split_whitespace().map(|s| s.parse_as()).collect()
 }
 
 fn get_differences(h: &[i32]) -> Vec<i32> {
@@ -46,15 +43,21 @@ fn extrapolate(h: Vec<i32>) -> (i32, i32) {
     (r1, r2)
 }
 
-fn main() {
-    let histories = io::stdin()
-        .lines()
-        .map(|l| parse(l.unwrap()))
-        .map(extrapolate)
-        .collect_vec();
-    let r1 = histories.iter().map(|(r1, _r2)| r1).sum::<i32>();
-    println!("{}", r1);
-
-    let r2 = histories.into_iter().map(|(_r1, r2)| r2).sum::<i32>();
-    println!("{}", r2);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(i32, i32)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(|l| parse(l).map(extrapolate)).collect()
+    }
+
+    fn part1(histories: &Self::Input<'_>) -> impl Into<Answer> {
+        histories.iter().map(|(r1, _r2)| r1).sum::<i32>()
+    }
+
+    fn part2(histories: &Self::Input<'_>) -> impl Into<Answer> {
+        histories.iter().map(|(_r1, r2)| r2).sum::<i32>()
+    }
 }
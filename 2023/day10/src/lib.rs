@@ -1,7 +1,9 @@
-use std::{collections::VecDeque, io};
+use std::collections::VecDeque;
+
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct Coord {
+pub struct Coord {
     pub x: usize,
     pub y: usize,
 }
@@ -13,7 +15,7 @@ impl Coord {
 }
 
 #[derive(Debug)]
-struct Grid {
+pub struct Grid {
     pub width: usize,
     pub height: usize,
     pub tiles: Vec<char>,
@@ -299,23 +301,44 @@ impl Grid {
     }
 }
 
-fn main() {
-    let lines = io::stdin().lines().map(|l| l.unwrap()).collect::<Vec<_>>();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Grid, Coord);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let lines = input.lines().collect::<Vec<_>>();
 
-    let width = lines[0].len();
-    let height = lines.len();
-    let tiles = lines
-        .into_iter()
-        .flat_map(|l| l.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |l| l.len());
+        let height = lines.len();
+        for l in &lines {
+            if let Some(x) = l.find(|c| !"|-LJ7F.S".contains(c)) {
+                return Err(ParseError::new(&l[x..], "expected a pipe, `.` or `S`"));
+            }
+            if l.len() != width {
+                return Err(ParseError::new(l, format!("expected {width} tiles")));
+            }
+        }
+        if input.matches('S').count() != 1 {
+            return Err(ParseError::new(input, "expected a single `S`"));
+        }
+        let tiles = lines
+            .into_iter()
+            .flat_map(|l| l.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
 
-    let mut grid = Grid::new(width, height, tiles);
-    let start = grid.find_start();
-    grid.fill_distances(&start);
+        let mut grid = Grid::new(width, height, tiles);
+        let start = grid.find_start();
+        grid.fill_distances(&start);
+        Ok((grid, start))
+    }
 
-    let r1 = grid.find_largest_distance();
-    println!("{:?}", r1);
+    fn part1((grid, _): &Self::Input<'_>) -> impl Into<Answer> {
+        grid.find_largest_distance()
+    }
 
-    let r2 = grid.find_enclosed_tile_count(&start);
-    println!("{:?}", r2);
+    fn part2((grid, start): &Self::Input<'_>) -> impl Into<Answer> {
+        grid.find_enclosed_tile_count(start)
+    }
 }
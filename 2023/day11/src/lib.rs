@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+use itertools::{iproduct, Itertools};
+
+fn calculate_galaxies(
+    galaxies: &[(usize, usize)],
+    is_row_expandable: &HashSet<usize>,
+    is_col_expandable: &HashSet<usize>,
+    expand_distance: u64,
+) -> u64 {
+    galaxies
+        .iter()
+        .combinations(2)
+        .map(|c| {
+            assert_eq!(c.len(), 2);
+            let x1 = c[0].0.min(c[1].0);
+            let x2 = c[0].0.max(c[1].0);
+            let y1 = c[0].1.min(c[1].1);
+            let y2 = c[0].1.max(c[1].1);
+
+            let expandable_x = (x1..=x2)
+                .filter(|&x| is_col_expandable.contains(&x))
+                .count() as u64;
+            let expandable_y = (y1..=y2)
+                .filter(|&y| is_row_expandable.contains(&y))
+                .count() as u64;
+
+            let x = (x2 - x1) as u64 + expandable_x * (expand_distance - 1);
+            let y = (y2 - y1) as u64 + expandable_y * (expand_distance - 1);
+            x + y
+        })
+        .sum::<u64>()
+}
+
+pub struct Galaxies {
+    galaxies: Vec<(usize, usize)>,
+    is_row_expandable: HashSet<usize>,
+    is_col_expandable: HashSet<usize>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Galaxies;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let width = input.lines().next().map_or(0, str::len);
+        for l in input.lines() {
+            if let Some(x) = l.find(|c| c != '.' && c != '#') {
+                return Err(ParseError::new(&l[x..], "expected `.` or `#`"));
+            }
+            if l.len() != width {
+                return Err(ParseError::new(l, format!("expected {width} characters")));
+            }
+        }
+        let grid = input
+            .lines()
+            .map(|l| l.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let height = grid.len();
+
+        let is_row_expandable = (0..height)
+            .filter(|&row| (0..width).all(|col| grid[row][col] == '.'))
+            .collect::<HashSet<_>>();
+        let is_col_expandable = (0..width)
+            .filter(|&col| (0..height).all(|row| grid[row][col] == '.'))
+            .collect::<HashSet<_>>();
+
+        let galaxies = iproduct!(0..width, 0..height)
+            .filter(|(col, row)| grid[*row][*col] == '#')
+            .collect::<Vec<_>>();
+
+        Ok(Galaxies {
+            galaxies,
+            is_row_expandable,
+            is_col_expandable,
+        })
+    }
+
+    fn part1(g: &Self::Input<'_>) -> impl Into<Answer> {
+        calculate_galaxies(&g.galaxies, &g.is_row_expandable, &g.is_col_expandable, 2)
+    }
+
+    fn part2(g: &Self::Input<'_>) -> impl Into<Answer> {
+        calculate_galaxies(
+            &g.galaxies,
+            &g.is_row_expandable,
+            &g.is_col_expandable,
+            1000000,
+        )
+    }
+}
@@ -1,4 +1,6 @@
-use std::{collections::HashMap, io};
+use aoc_common::memo::Memo;
+use aoc_common::parallel::prelude::*;
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
 
 fn is_long_enough(remaining: usize, contigous_list: &[u32]) -> bool {
     assert!(!contigous_list.is_empty());
@@ -25,54 +27,36 @@ fn check_spring(s: &str, size: usize) -> Option<(String, &str)> {
     ))
 }
 
-fn create_combinations(s: &str, contigous_list0: &[u32]) -> u64 {
-    let mut seen = HashMap::<(usize, usize), u64>::new();
-    fn create_combinations_rec(
-        history: String,
-        s: &str,
-        contigous_list: &[u32],
-        seen: &mut HashMap<(usize, usize), u64>,
-    ) -> u64 {
-        // skip all the dots
-        let p = s.chars().position(|c| c != '.').unwrap_or(s.len());
-        let s = &s[p..];
-        let history = [history, ".".repeat(p)].concat();
-
-        if let Some(c) = seen.get(&(s.len(), contigous_list.len())) {
-            return *c;
-        }
-
+/// Counts the arrangements of `contigous_list` in `s`, which only depend on
+/// the lengths of what is left of both.
+fn create_combinations_rec(
+    s: &str,
+    contigous_list: &[u32],
+    memo: &mut Memo<(usize, usize), u64>,
+) -> u64 {
+    // skip all the dots
+    let s = s.trim_start_matches('.');
+    memo.get_or_insert_with(&(s.len(), contigous_list.len()), |memo| {
         if contigous_list.is_empty() {
-            let c = if s.chars().all(|c| c == '.' || c == '?') {
-                1
-            } else {
-                0
-            };
-            seen.insert((s.len(), contigous_list.len()), c);
-            return c;
+            return s.chars().all(|c| c == '.' || c == '?') as u64;
         }
 
         let first_to_place = contigous_list[0] as usize;
         let mut c = 0;
-        if let Some((h, s2)) = check_spring(s, first_to_place) {
-            let l2 = &contigous_list[1..];
-            let c2 = create_combinations_rec(format!("{}{}", history, h), s2, l2, seen);
-            seen.insert((s2.len(), l2.len()), c2);
-            c += c2;
+        if let Some((_, s2)) = check_spring(s, first_to_place) {
+            c += create_combinations_rec(s2, &contigous_list[1..], memo);
         }
-        if s.starts_with('?') {
-            let s2 = s.strip_prefix('?').unwrap();
-            let c2 = if is_long_enough(s2.len(), contigous_list) {
-                create_combinations_rec(format!("{}.", history), s2, contigous_list, seen)
-            } else {
-                0
-            };
-            seen.insert((s2.len(), contigous_list.len()), c2);
-            c += c2;
+        if let Some(s2) = s.strip_prefix('?') {
+            if is_long_enough(s2.len(), contigous_list) {
+                c += create_combinations_rec(s2, contigous_list, memo);
+            }
         }
         c
-    }
-    create_combinations_rec("".to_string(), s, contigous_list0, &mut seen)
+    })
+}
+
+fn create_combinations(s: &str, contigous_list: &[u32]) -> u64 {
+    create_combinations_rec(s, contigous_list, &mut Memo::new())
 }
 
 fn count_arrangements(s: &str) -> u64 {
@@ -94,20 +78,41 @@ fn unfold(s: &str) -> String {
     format!("{t1} {t2}")
 }
 
-fn main() {
-    let lines = io::stdin()
-        .lines()
-        .map(|l| l.unwrap())
-        .collect::<Vec<String>>();
+/// Checks that `line` is a record of springs followed by the group sizes.
+fn check_line(line: &str) -> ParseResult<()> {
+    let (record, groups) = line.split_once_or_err(" ")?;
+    if let Some(i) = record.find(|c| !".#?".contains(c)) {
+        return Err(ParseError::new(&record[i..], "expected `.`, `#` or `?`"));
+    }
+    for g in groups.split(',') {
+        g.parse_as::<u32>()?;
+    }
+    Ok(())
+}
+
+pub struct Day;
 
-    let r1 = lines.iter().map(|l| count_arrangements(l)).sum::<u64>();
-    println!("{}", r1);
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(|l| check_line(l).map(|_| l)).collect()
+    }
 
-    let r2 = lines
-        .into_iter()
-        .map(|l| count_arrangements(unfold(&l).as_str()))
-        .sum::<u64>();
-    println!("{}", r2);
+    fn part1(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        lines
+            .maybe_par_iter()
+            .map(|l| count_arrangements(l))
+            .sum::<u64>()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        lines
+            .maybe_par_iter()
+            .map(|l| count_arrangements(unfold(l).as_str()))
+            .sum::<u64>()
+    }
 }
 
 #[cfg(test)]
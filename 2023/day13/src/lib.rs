@@ -1,24 +1,31 @@
-use std::io;
-
+use aoc_common::{Answer, InputExt, ParseError, ParseResult, Solution};
 use itertools::Itertools;
 
 #[derive(Debug, Clone)]
-struct Grid {
+pub struct Grid {
     pub width: usize,
     pub height: usize,
     pub grid: Vec<char>,
 }
 
 impl Grid {
-    fn from(lines: Vec<String>) -> Self {
-        let width = lines[0].len();
+    fn parse(lines: Vec<&str>) -> ParseResult<Self> {
+        let width = lines.first().map_or(0, |l| l.len());
         let height = lines.len();
+        for l in &lines {
+            if let Some(x) = l.find(|c| c != '#' && c != '.') {
+                return Err(ParseError::new(&l[x..], "expected `#` or `.`"));
+            }
+            if l.len() != width {
+                return Err(ParseError::new(l, format!("expected {width} characters")));
+            }
+        }
         let grid = lines.iter().flat_map(|l| l.chars()).collect();
-        Self {
+        Ok(Self {
             width,
             height,
             grid,
-        }
+        })
     }
 
     fn get_row_bitmap(&self, row: usize) -> u64 {
@@ -133,55 +140,53 @@ impl Pattern {
     }
 }
 
-fn main() {
-    let grids = io::stdin()
-        .lines()
-        .map(|l| l.unwrap())
-        .fold(vec![vec![]], |mut a, b| {
-            if !b.is_empty() {
-                a.last_mut().unwrap().push(b);
-            } else {
-                a.push(vec![])
-            }
-            a
-        })
-        .into_iter()
-        .map(Grid::from)
-        .collect::<Vec<_>>();
+pub struct Day;
 
-    let r1 = grids
-        .iter()
-        .map(Pattern::from)
-        .map(|p| match p.find_reflection() {
-            (Some(h), None) => h as u64 * 100,
-            (None, Some(v)) => v as u64,
-            _ => panic!("invalid pattern"),
-        })
-        .sum::<u64>();
-    println!("{:?}", r1);
+impl Solution for Day {
+    type Input<'a> = Vec<Grid>;
+    type Params = ();
 
-    let r2 = grids
-        .iter()
-        .map(|g| {
-            let r0 = Pattern::from(g).find_reflection();
-
-            let patterns = g.fix_smudge().map(|g| Pattern::from(&g));
-            let reflections = patterns
-                .filter_map(|p| {
-                    let r = p.find_fixed_reflection(r0);
-                    // println!("{:?}", r);
-                    match r {
-                        (Some(h), None) => Some(h as u64 * 100),
-                        (None, Some(v)) => Some(v as u64),
-                        (None, None) => None,
-                        _ => panic!("invalid pattern"),
-                    }
-                })
-                .unique()
-                .collect::<Vec<_>>();
-            assert!(reflections.len() <= 1, "too many ? {:?}", reflections);
-            reflections.into_iter().next().unwrap()
-        })
-        .sum::<u64>();
-    println!("{:?}", r2);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .paragraphs()
+            .map(|p| Grid::parse(p.lines().collect()))
+            .collect()
+    }
+
+    fn part1(grids: &Self::Input<'_>) -> impl Into<Answer> {
+        grids
+            .iter()
+            .map(Pattern::from)
+            .map(|p| match p.find_reflection() {
+                (Some(h), None) => h as u64 * 100,
+                (None, Some(v)) => v as u64,
+                _ => panic!("invalid pattern"),
+            })
+            .sum::<u64>()
+    }
+
+    fn part2(grids: &Self::Input<'_>) -> impl Into<Answer> {
+        grids
+            .iter()
+            .map(|g| {
+                let r0 = Pattern::from(g).find_reflection();
+
+                let patterns = g.fix_smudge().map(|g| Pattern::from(&g));
+                let reflections = patterns
+                    .filter_map(|p| {
+                        let r = p.find_fixed_reflection(r0);
+                        match r {
+                            (Some(h), None) => Some(h as u64 * 100),
+                            (None, Some(v)) => Some(v as u64),
+                            (None, None) => None,
+                            _ => panic!("invalid pattern"),
+                        }
+                    })
+                    .unique()
+                    .collect::<Vec<_>>();
+                assert!(reflections.len() <= 1, "too many ? {:?}", reflections);
+                reflections.into_iter().next().unwrap()
+            })
+            .sum::<u64>()
+    }
 }
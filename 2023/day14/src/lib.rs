@@ -1,7 +1,9 @@
-use std::{collections::HashMap, fmt::Display, io};
+use std::fmt::Display;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Tile {
+use aoc_common::{cycle::find_cycle, Answer, ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
     Empty,
     RoundRock,
     CubeRock,
@@ -18,17 +20,17 @@ impl Display for Tile {
     }
 }
 
-fn parse_tile(c: char) -> Tile {
-    match c {
-        '.' => Tile::Empty,
-        'O' => Tile::RoundRock,
-        '#' => Tile::CubeRock,
-        _ => panic!("Invalid tile: {}", c),
+fn parse_tile(s: &str) -> ParseResult<Tile> {
+    match s {
+        "." => Ok(Tile::Empty),
+        "O" => Ok(Tile::RoundRock),
+        "#" => Ok(Tile::CubeRock),
+        _ => Err(ParseError::new(s, "expected `.`, `O` or `#`")),
     }
 }
 
-#[derive(Clone)]
-struct Platform {
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
     width: usize,
     height: usize,
     grid: Vec<Vec<Tile>>,
@@ -159,49 +161,42 @@ impl Platform {
             .map(|(y, _)| (self.height - y) as u32)
             .sum::<u32>()
     }
+}
+
+pub struct Day;
 
-    fn get_key(&self) -> String {
-        self.grid.iter().flatten().map(|t| t.to_string()).collect()
+impl Solution for Day {
+    type Input<'a> = Platform;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let width = input.lines().next().map_or(0, str::len);
+        let grid = input
+            .lines()
+            .map(|l| {
+                if l.len() != width {
+                    return Err(ParseError::new(l, format!("expected {width} tiles")));
+                }
+                l.char_indices()
+                    .map(|(x, c)| parse_tile(&l[x..x + c.len_utf8()]))
+                    .collect::<ParseResult<Vec<Tile>>>()
+            })
+            .collect::<ParseResult<Vec<Vec<Tile>>>>()?;
+        Ok(Platform::from(grid))
     }
-}
 
-fn main() {
-    let grid = io::stdin()
-        .lines()
-        .map(|l| l.unwrap().chars().map(parse_tile).collect::<Vec<Tile>>())
-        .collect::<Vec<Vec<Tile>>>();
-    let mut p = Platform::from(grid);
-    p.tilt_north();
-    let r1 = p.calculate_load();
-    println!("{}", r1);
-
-    p.tilt_west();
-    p.tilt_south();
-    p.tilt_east();
-
-    let mut seen = HashMap::new();
-    seen.insert(p.get_key(), 0);
-
-    let total = 1_000_000_000;
-    for i in 2..=total {
-        p.spin_cycle();
-        let key = p.get_key();
-        if let Some(i0) = seen.get(&key) {
-            let d = i - i0;
-            for _ in 0..d {
-                p.spin_cycle();
-            }
-            assert_eq!(p.get_key(), key);
+    fn part1(p: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut p = p.clone();
+        p.tilt_north();
+        p.calculate_load()
+    }
 
-            let t2 = total - i0;
-            let d2 = t2 % d;
-            for _ in 0..d2 {
-                p.spin_cycle();
-            }
-            break;
-        }
-        seen.insert(key, i);
+    fn part2(p: &Self::Input<'_>) -> impl Into<Answer> {
+        let (cycle, states) = find_cycle(p.clone(), |p| {
+            let mut p = p.clone();
+            p.spin_cycle();
+            p
+        });
+        states[cycle.equivalent_step(1_000_000_000)].calculate_load()
     }
-    let r2 = p.calculate_load();
-    println!("{}", r2);
 }
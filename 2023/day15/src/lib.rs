@@ -1,4 +1,6 @@
-use std::{collections::HashMap, fmt, io};
+use std::{collections::HashMap, fmt};
+
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
 
 fn hash(s: &str) -> u8 {
     s.chars()
@@ -6,28 +8,23 @@ fn hash(s: &str) -> u8 {
         .fold(0u32, |a, c| ((a + c as u32) * 17) % 256) as u8
 }
 
-enum Step<'a> {
+#[derive(Clone, Copy)]
+pub enum Step<'a> {
     Remove(&'a str),
     Add(&'a str, u32),
 }
 
-fn parse_step(s: &str) -> Step {
-    let is_remove = s.contains('-');
-    let is_add = s.contains('=');
-    assert_ne!(is_remove, is_add);
-
-    if is_remove {
-        let t = s.split('-').collect::<Vec<_>>();
-        let label = t[0];
-        return Step::Remove(label);
+fn parse_step(s: &str) -> ParseResult<Step<'_>> {
+    if let Some(label) = s.strip_suffix('-') {
+        return Ok(Step::Remove(label));
     }
-    if is_add {
-        let t = s.split('=').collect::<Vec<_>>();
-        let label = t[0];
-        let focal_length = t[1].parse().unwrap();
-        return Step::Add(label, focal_length);
+    match s.split_once('=') {
+        Some((label, focal_length)) => Ok(Step::Add(label, focal_length.parse_as()?)),
+        None => Err(ParseError::new(
+            s,
+            "expected `<label>-` or `<label>=<focal length>`",
+        )),
     }
-    panic!("Invalid step: {}", s);
 }
 
 #[derive(Clone, Copy)]
@@ -110,21 +107,27 @@ impl<'a> Boxes<'a> {
     }
 }
 
-fn main() {
-    let line = io::stdin().lines().map(|l| l.unwrap()).next().unwrap();
-    let steps = line.split(',').collect::<Vec<_>>();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(&'a str, Step<'a>)>;
+    type Params = ();
 
-    let r1 = steps.iter().map(|s| hash(s) as u32).sum::<u32>();
-    println!("{}", r1);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let line = input.lines().next().unwrap_or_default();
+        line.split(',').map(|s| Ok((s, parse_step(s)?))).collect()
+    }
+
+    fn part1(steps: &Self::Input<'_>) -> impl Into<Answer> {
+        steps.iter().map(|(s, _)| hash(s) as u32).sum::<u32>()
+    }
 
-    let mut boxes = Boxes::new();
-    steps
-        .into_iter()
-        .map(parse_step)
-        .for_each(|s| boxes.execute_step(s));
+    fn part2(steps: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut boxes = Boxes::new();
+        steps.iter().for_each(|(_, s)| boxes.execute_step(*s));
 
-    let r2 = boxes.get_focusing_power();
-    println!("{}", r2);
+        boxes.get_focusing_power()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,115 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{Answer, Coords, Direction, Grid, InputExt, ParseResult, Solution};
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+struct Laser {
+    p: Coords,
+    dir: Direction,
+}
+
+impl Laser {
+    fn advance(&self, grid: &Grid<char>) -> Vec<Laser> {
+        let Some(p) = grid.step(self.p, self.dir) else {
+            // No movement
+            return vec![];
+        };
+        let d = match grid[p] {
+            '.' => vec![self.dir],
+            '/' => match self.dir {
+                Direction::Up => vec![Direction::Right],
+                Direction::Down => vec![Direction::Left],
+                Direction::Left => vec![Direction::Down],
+                Direction::Right => vec![Direction::Up],
+            },
+            '\\' => match self.dir {
+                Direction::Up => vec![Direction::Left],
+                Direction::Down => vec![Direction::Right],
+                Direction::Left => vec![Direction::Up],
+                Direction::Right => vec![Direction::Down],
+            },
+            '|' => match self.dir {
+                Direction::Left => vec![Direction::Up, Direction::Down],
+                Direction::Right => vec![Direction::Up, Direction::Down],
+                _ => vec![self.dir],
+            },
+            '-' => match self.dir {
+                Direction::Up => vec![Direction::Left, Direction::Right],
+                Direction::Down => vec![Direction::Left, Direction::Right],
+                _ => vec![self.dir],
+            },
+            _ => panic!("Unexpected tile"),
+        };
+        d.into_iter()
+            .map(|dir| Laser { p, dir })
+            .collect::<Vec<_>>()
+    }
+}
+
+fn energise(grid: &Grid<char>, start: Laser) -> usize {
+    let mut visited = HashSet::new();
+    let mut energised = Grid::new(grid.width, grid.height, false);
+    let mut todo = VecDeque::from([start]);
+    while let Some(laser) = todo.pop_front() {
+        if visited.contains(&laser) {
+            continue;
+        }
+        visited.insert(laser);
+        energised[laser.p] = true;
+
+        todo.extend(laser.advance(grid));
+    }
+    energised.tiles.iter().filter(|&&e| e).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.grid_with(|c| "./\\|-".contains(c).then_some(c))
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        energise(
+            grid,
+            Laser {
+                p: Coords::new(0, 0),
+                dir: Direction::Right,
+            },
+        )
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        (0..grid.width)
+            .flat_map(|x| {
+                vec![
+                    Laser {
+                        p: Coords::new(x, 0),
+                        dir: Direction::Down,
+                    },
+                    Laser {
+                        p: Coords::new(x, grid.height - 1),
+                        dir: Direction::Up,
+                    },
+                ]
+            })
+            .chain((0..grid.height).flat_map(|y| {
+                vec![
+                    Laser {
+                        p: Coords::new(0, y),
+                        dir: Direction::Right,
+                    },
+                    Laser {
+                        p: Coords::new(grid.width - 1, y),
+                        dir: Direction::Left,
+                    },
+                ]
+            }))
+            .map(|laser| energise(grid, laser))
+            .max()
+            .unwrap()
+    }
+}
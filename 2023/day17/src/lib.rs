@@ -0,0 +1,89 @@
+use aoc_common::search::dijkstra;
+use aoc_common::{Answer, Coords, Direction, Grid, InputExt, ParseResult, Solution};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Crucible {
+    p: Coords,
+    dir: Direction,
+}
+
+impl Crucible {
+    pub fn new(dir: Direction) -> Crucible {
+        Crucible {
+            p: Coords::new(0, 0),
+            dir,
+        }
+    }
+
+    /// The crucibles after going straight for `min_straight` to `max_straight`
+    /// blocks and turning, with the heat lost on the way.
+    pub fn step(
+        &self,
+        grid: &Grid<u32>,
+        min_straight: i32,
+        max_straight: i32,
+    ) -> Vec<(Crucible, u32)> {
+        let mut r = vec![];
+        let mut p = self.p;
+        let mut heat_loss = 0;
+        for i in 1..=max_straight {
+            let Some(p2) = grid.step(p, self.dir) else {
+                break;
+            };
+            p = p2;
+            heat_loss += grid[p];
+            if i >= min_straight {
+                for dir in [self.dir.left(), self.dir.right()] {
+                    r.push((Crucible { p, dir }, heat_loss));
+                }
+            }
+        }
+        r
+    }
+}
+
+fn advance(start: Vec<Crucible>, grid: &Grid<u32>, min_straight: i32, max_straight: i32) -> u32 {
+    let end = Coords::new(grid.width - 1, grid.height - 1);
+    let (_, heat_loss) = dijkstra(
+        start,
+        |c| c.step(grid, min_straight, max_straight),
+        |c| c.p == end,
+    )
+    .unwrap();
+    heat_loss
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid<u32>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.grid_with(|c| c.to_digit(10))
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        advance(
+            vec![
+                Crucible::new(Direction::Right),
+                Crucible::new(Direction::Down),
+            ],
+            grid,
+            0,
+            3,
+        )
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        advance(
+            vec![
+                Crucible::new(Direction::Right),
+                Crucible::new(Direction::Down),
+            ],
+            grid,
+            4,
+            10,
+        )
+    }
+}
@@ -0,0 +1,187 @@
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Operation {
+    direction: Direction,
+    length: i64,
+}
+
+fn parse(line: &str) -> ParseResult<(Operation, Operation)> {
+    let (d1, rest) = line.split_once_or_err(" ")?;
+    let (l, color) = rest.split_once_or_err(" ")?;
+    let d1 = match d1 {
+        "R" => Direction::Right,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "U" => Direction::Up,
+        _ => return Err(ParseError::new(d1, "expected `R`, `D`, `L` or `U`")),
+    };
+    let o1 = Operation {
+        direction: d1,
+        length: l.parse_as()?,
+    };
+
+    let color = color.strip_prefix_or_err("(#")?.strip_suffix_or_err(")")?;
+    if color.len() != 6 || !color.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseError::new(color, "expected 6 hexadecimal digits"));
+    }
+    let l = i64::from_str_radix(&color[..5], 16).unwrap();
+    let d2 = match &color[5..] {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        d => return Err(ParseError::new(d, "expected a direction from 0 to 3")),
+    };
+    let o2 = Operation {
+        direction: d2,
+        length: l,
+    };
+    Ok((o1, o2))
+}
+
+fn shoelace(operations: &[Operation]) -> u64 {
+    let area = operations
+        .iter()
+        .scan((0, 0), |p, &o| {
+            match o.direction {
+                Direction::Right => p.0 += o.length,
+                Direction::Down => p.1 -= o.length,
+                Direction::Left => p.0 -= o.length,
+                Direction::Up => p.1 += o.length,
+            };
+            Some(*p)
+        })
+        .tuple_windows::<(_, _)>()
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - y1 * x2)
+        .sum::<i64>()
+        .unsigned_abs()
+        / 2;
+    let perimeter = operations.iter().map(|o| o.length as u64).sum::<u64>();
+    area + perimeter / 2 + 1
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(Operation, Operation)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(parse).collect()
+    }
+
+    fn part1(operations: &Self::Input<'_>) -> impl Into<Answer> {
+        let o1 = operations.iter().map(|o| o.0).collect::<Vec<_>>();
+        shoelace(&o1)
+    }
+
+    fn part2(operations: &Self::Input<'_>) -> impl Into<Answer> {
+        let o2 = operations.iter().map(|o| o.1).collect::<Vec<_>>();
+        shoelace(&o2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashSet, VecDeque};
+
+    use super::*;
+    use proptest::prelude::*;
+
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Up,
+    ];
+
+    /// A loop that does not cross itself: columns of the given widths, each
+    /// spanning from a bottom below 0 to a top above it, drawn clockwise and
+    /// then rotated `turns` quarter turns.
+    fn lagoon() -> impl Strategy<Value = Vec<Operation>> {
+        (
+            prop::collection::vec((1..5i64, -5..0i64, 1..6i64), 1..6),
+            0..4usize,
+        )
+            .prop_map(|(columns, turns)| {
+                let mut operations = vec![];
+                let mut dig = |direction: usize, length: i64| {
+                    if length != 0 {
+                        operations.push(Operation {
+                            direction: DIRECTIONS[(direction + turns) % 4],
+                            length: length.abs(),
+                        });
+                    }
+                };
+                let (right, down, left, up) = (0, 1, 2, 3);
+                let vertical = |delta: i64| if delta > 0 { up } else { down };
+                let (_, lo, hi) = columns[0];
+                dig(up, hi - lo);
+                for (i, &(width, _, hi)) in columns.iter().enumerate() {
+                    dig(right, width);
+                    if let Some(&(_, _, next)) = columns.get(i + 1) {
+                        dig(vertical(next - hi), next - hi);
+                    }
+                }
+                let &(_, lo, hi) = columns.last().unwrap();
+                dig(down, hi - lo);
+                for (i, &(width, lo, _)) in columns.iter().enumerate().rev() {
+                    dig(left, width);
+                    if i > 0 {
+                        let prev = columns[i - 1].1;
+                        dig(vertical(prev - lo), prev - lo);
+                    }
+                }
+                operations
+            })
+    }
+
+    /// Digs the trench cell by cell and counts what the outside does not reach.
+    fn naive_area(operations: &[Operation]) -> u64 {
+        let mut p = (0i64, 0i64);
+        let mut trench = HashSet::from([p]);
+        for o in operations {
+            for _ in 0..o.length {
+                match o.direction {
+                    Direction::Right => p.0 += 1,
+                    Direction::Down => p.1 -= 1,
+                    Direction::Left => p.0 -= 1,
+                    Direction::Up => p.1 += 1,
+                }
+                trench.insert(p);
+            }
+        }
+        let min_x = trench.iter().map(|p| p.0).min().unwrap() - 1;
+        let max_x = trench.iter().map(|p| p.0).max().unwrap() + 1;
+        let min_y = trench.iter().map(|p| p.1).min().unwrap() - 1;
+        let max_y = trench.iter().map(|p| p.1).max().unwrap() + 1;
+        let mut outside = HashSet::from([(min_x, min_y)]);
+        let mut todo = VecDeque::from([(min_x, min_y)]);
+        while let Some((x, y)) = todo.pop_front() {
+            for n in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                let inside_box = (min_x..=max_x).contains(&n.0) && (min_y..=max_y).contains(&n.1);
+                if inside_box && !trench.contains(&n) && outside.insert(n) {
+                    todo.push_back(n);
+                }
+            }
+        }
+        ((max_x - min_x + 1) * (max_y - min_y + 1)) as u64 - outside.len() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn shoelace_matches_flood_fill(operations in lagoon()) {
+            prop_assert_eq!(shoelace(&operations), naive_area(&operations));
+        }
+    }
+}
@@ -0,0 +1,450 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{Answer, Interval, ParseError, ParseExt, ParseResult, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartType {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug)]
+pub enum Condition {
+    LessThan(PartType, u32),
+    GreaterThan(PartType, u32),
+    Nope,
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    condition: Condition,
+    destination: String,
+}
+
+fn parse_part_type(s: &str) -> ParseResult<PartType> {
+    match s {
+        "x" => Ok(PartType::X),
+        "m" => Ok(PartType::M),
+        "a" => Ok(PartType::A),
+        "s" => Ok(PartType::S),
+        _ => Err(ParseError::new(s, "expected `x`, `m`, `a` or `s`")),
+    }
+}
+
+fn parse_rule(s: &str) -> ParseResult<Rule> {
+    let Some((condition, destination)) = s.split_once(':') else {
+        return Ok(Rule {
+            condition: Condition::Nope,
+            destination: s.to_string(),
+        });
+    };
+    if condition.len() < 2 || !condition.is_char_boundary(1) || !condition.is_char_boundary(2) {
+        return Err(ParseError::new(condition, "expected a condition"));
+    }
+    let part = parse_part_type(&condition[..1])?;
+    let value = condition[2..].parse_as()?;
+    let condition = match &condition[1..2] {
+        "<" => Condition::LessThan(part, value),
+        ">" => Condition::GreaterThan(part, value),
+        op => return Err(ParseError::new(op, "expected `<` or `>`")),
+    };
+    Ok(Rule {
+        condition,
+        destination: destination.to_string(),
+    })
+}
+
+fn parse_workflows<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> ParseResult<HashMap<String, Vec<Rule>>> {
+    let mut r = HashMap::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        let (name, rules) = line.strip_suffix_or_err("}")?.split_once_or_err("{")?;
+        let rules = rules
+            .split(',')
+            .map(parse_rule)
+            .collect::<ParseResult<_>>()?;
+        r.insert(name.to_string(), rules);
+    }
+    Ok(r)
+}
+
+#[derive(Debug)]
+pub struct Part {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32,
+}
+
+fn parse_parts<'a>(lines: &mut impl Iterator<Item = &'a str>) -> ParseResult<Vec<Part>> {
+    let mut r = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+
+        let ratings = line.strip_prefix_or_err("{")?.strip_suffix_or_err("}")?;
+        let p = ratings.split(',').map(|s| s.trim()).try_fold(
+            Part {
+                x: 0,
+                m: 0,
+                a: 0,
+                s: 0,
+            },
+            |mut p, s| {
+                let (key, value) = s.split_once_or_err("=")?;
+                let value = value.parse_as()?;
+                match parse_part_type(key)? {
+                    PartType::X => p.x = value,
+                    PartType::M => p.m = value,
+                    PartType::A => p.a = value,
+                    PartType::S => p.s = value,
+                };
+                Ok(p)
+            },
+        )?;
+        r.push(p);
+    }
+    Ok(r)
+}
+
+fn get_part_value(part: &Part, part_type: PartType) -> u32 {
+    match part_type {
+        PartType::X => part.x,
+        PartType::M => part.m,
+        PartType::A => part.a,
+        PartType::S => part.s,
+    }
+}
+
+fn test_condition(condition: &Condition, part: &Part) -> bool {
+    match condition {
+        Condition::LessThan(t, v) => get_part_value(part, *t) < *v,
+        Condition::GreaterThan(t, v) => get_part_value(part, *t) > *v,
+        Condition::Nope => true,
+    }
+}
+
+fn evaluate_a_workflow<'a>(workflow: &'a [Rule], part: &Part) -> &'a str {
+    workflow
+        .iter()
+        .find(|r| test_condition(&r.condition, part))
+        .expect("No rule matches")
+        .destination
+        .as_str()
+}
+
+fn evaluate_workflows(workflows: &HashMap<String, Vec<Rule>>, part: &Part) -> bool {
+    // print!("{:?}: ", part);
+    let mut location = "in";
+    while location != "A" && location != "R" {
+        // print!("{} -> ", location);
+        let workflow = workflows.get(location).unwrap();
+        location = evaluate_a_workflow(workflow, part);
+    }
+    // println!("{}", location);
+    location == "A"
+}
+
+#[derive(Debug, Clone)]
+struct PartCombinations {
+    parts: HashMap<PartType, Interval<u32>>,
+}
+
+impl PartCombinations {
+    pub fn new() -> Self {
+        Self {
+            parts: HashMap::from([
+                (PartType::X, Interval::inclusive(1, 4000)),
+                (PartType::M, Interval::inclusive(1, 4000)),
+                (PartType::A, Interval::inclusive(1, 4000)),
+                (PartType::S, Interval::inclusive(1, 4000)),
+            ]),
+        }
+    }
+
+    pub fn get(&self, part_type: PartType) -> Interval<u32> {
+        *self.parts.get(&part_type).expect("Unknown part type")
+    }
+
+    fn clone_with(&self, part_type: PartType, value: Interval<u32>) -> Option<Self> {
+        if value.is_empty() {
+            return None;
+        }
+        let mut parts = self.parts.clone();
+        parts.entry(part_type).and_modify(|e| *e = value);
+        Some(Self { parts })
+    }
+
+    pub fn split_by_less_than(
+        &self,
+        part_type: PartType,
+        value: u32,
+    ) -> (Option<PartCombinations>, Option<PartCombinations>) {
+        let (left, right) = self.get(part_type).split_at(value);
+        (
+            self.clone_with(part_type, left),
+            self.clone_with(part_type, right),
+        )
+    }
+
+    pub fn split_by_greater_than(
+        &self,
+        part_type: PartType,
+        value: u32,
+    ) -> (Option<PartCombinations>, Option<PartCombinations>) {
+        let (rejected, accepted) = self.split_by_less_than(part_type, value + 1);
+        (accepted, rejected)
+    }
+
+    pub fn split_by_condition(
+        &self,
+        condition: &Condition,
+    ) -> (Option<PartCombinations>, Option<PartCombinations>) {
+        match condition {
+            Condition::LessThan(t, v) => self.split_by_less_than(*t, *v),
+            Condition::GreaterThan(t, v) => self.split_by_greater_than(*t, *v),
+            Condition::Nope => (Some(self.clone()), None),
+        }
+    }
+
+    pub fn count_combinations(&self) -> u64 {
+        self.parts.values().map(|i| i.len() as u64).product()
+    }
+}
+
+fn split_part_combinations(workflows: &HashMap<String, Vec<Rule>>) -> Vec<PartCombinations> {
+    let mut accepted = Vec::new();
+
+    let mut todo = VecDeque::from([("in", PartCombinations::new())]);
+    while let Some((location, combinations)) = todo.pop_front() {
+        if location == "A" {
+            accepted.push(combinations);
+            continue;
+        }
+        if location == "R" {
+            continue;
+        }
+
+        let workflow = workflows.get(location).unwrap();
+        let mut combinations = combinations;
+        for rule in workflow {
+            let (accepted, rejected) = combinations.split_by_condition(&rule.condition);
+            if let Some(accepted) = accepted {
+                todo.push_back((&rule.destination, accepted));
+            }
+            if let Some(rejected) = rejected {
+                combinations = rejected;
+            }
+        }
+    }
+    accepted
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (HashMap<String, Vec<Rule>>, Vec<Part>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let mut lines = input.lines();
+
+        let workflows = parse_workflows(&mut lines)?;
+        let parts = parse_parts(&mut lines)?;
+        if !workflows.contains_key("in") {
+            return Err(ParseError::after(input, "expected a workflow named `in`"));
+        }
+        let destinations = workflows.values().flatten().map(|r| &r.destination);
+        for d in destinations {
+            if d != "A" && d != "R" && !workflows.contains_key(d) {
+                return Err(ParseError::new(d, "unknown workflow"));
+            }
+        }
+        Ok((workflows, parts))
+    }
+
+    fn part1((workflows, parts): &Self::Input<'_>) -> impl Into<Answer> {
+        parts
+            .iter()
+            .filter_map(|p| evaluate_workflows(workflows, p).then_some(p.x + p.m + p.a + p.s))
+            .sum::<u32>()
+    }
+
+    fn part2((workflows, _): &Self::Input<'_>) -> impl Into<Answer> {
+        split_part_combinations(workflows)
+            .into_iter()
+            .map(|c| c.count_combinations())
+            .sum::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_by_less_than() {
+        let c = PartCombinations::new();
+
+        let (a, r) = c.split_by_less_than(PartType::X, 500);
+        let a = a.unwrap();
+        let r = r.unwrap();
+
+        assert_eq!(a.get(PartType::X), Interval::inclusive(1, 499));
+        assert_eq!(a.get(PartType::M), Interval::inclusive(1, 4000));
+        assert_eq!(a.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(a.get(PartType::S), Interval::inclusive(1, 4000));
+
+        assert_eq!(r.get(PartType::X), Interval::inclusive(500, 4000));
+        assert_eq!(r.get(PartType::M), Interval::inclusive(1, 4000));
+        assert_eq!(r.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(r.get(PartType::S), Interval::inclusive(1, 4000));
+
+        let (a, r) = a.split_by_less_than(PartType::M, 1234);
+        let a = a.unwrap();
+        let r = r.unwrap();
+
+        assert_eq!(a.get(PartType::X), Interval::inclusive(1, 499));
+        assert_eq!(a.get(PartType::M), Interval::inclusive(1, 1233));
+        assert_eq!(a.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(a.get(PartType::S), Interval::inclusive(1, 4000));
+
+        assert_eq!(r.get(PartType::X), Interval::inclusive(1, 499));
+        assert_eq!(r.get(PartType::M), Interval::inclusive(1234, 4000));
+        assert_eq!(r.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(r.get(PartType::S), Interval::inclusive(1, 4000));
+
+        let (a2, r2) = a.split_by_less_than(PartType::X, 2000);
+        let a2 = a2.unwrap();
+
+        assert_eq!(a2.get(PartType::X), Interval::inclusive(1, 499));
+        assert_eq!(a2.get(PartType::M), Interval::inclusive(1, 1233));
+        assert_eq!(a2.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(a2.get(PartType::S), Interval::inclusive(1, 4000));
+
+        assert!(r2.is_none());
+
+        let (a2, r2) = r.split_by_less_than(PartType::M, 1000);
+        let r2 = r2.unwrap();
+
+        assert!(a2.is_none());
+
+        assert_eq!(r2.get(PartType::X), Interval::inclusive(1, 499));
+        assert_eq!(r2.get(PartType::M), Interval::inclusive(1234, 4000));
+        assert_eq!(r2.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(r2.get(PartType::S), Interval::inclusive(1, 4000));
+    }
+
+    #[test]
+    fn test_split_by_greater_than() {
+        let c = PartCombinations::new();
+
+        let (a, r) = c.split_by_greater_than(PartType::X, 500);
+        let a = a.unwrap();
+        let r = r.unwrap();
+
+        assert_eq!(a.get(PartType::X), Interval::inclusive(501, 4000));
+        assert_eq!(a.get(PartType::M), Interval::inclusive(1, 4000));
+        assert_eq!(a.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(a.get(PartType::S), Interval::inclusive(1, 4000));
+
+        assert_eq!(r.get(PartType::X), Interval::inclusive(1, 500));
+        assert_eq!(r.get(PartType::M), Interval::inclusive(1, 4000));
+        assert_eq!(r.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(r.get(PartType::S), Interval::inclusive(1, 4000));
+
+        let (a, r) = a.split_by_greater_than(PartType::M, 1234);
+        let a = a.unwrap();
+        let r = r.unwrap();
+
+        assert_eq!(a.get(PartType::X), Interval::inclusive(501, 4000));
+        assert_eq!(a.get(PartType::M), Interval::inclusive(1235, 4000));
+        assert_eq!(a.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(a.get(PartType::S), Interval::inclusive(1, 4000));
+
+        assert_eq!(r.get(PartType::X), Interval::inclusive(501, 4000));
+        assert_eq!(r.get(PartType::M), Interval::inclusive(1, 1234));
+        assert_eq!(r.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(r.get(PartType::S), Interval::inclusive(1, 4000));
+
+        let (a2, r2) = a.split_by_greater_than(PartType::X, 200);
+        let a2 = a2.unwrap();
+
+        assert_eq!(a2.get(PartType::X), Interval::inclusive(501, 4000));
+        assert_eq!(a2.get(PartType::M), Interval::inclusive(1235, 4000));
+        assert_eq!(a2.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(a2.get(PartType::S), Interval::inclusive(1, 4000));
+
+        assert!(r2.is_none());
+
+        let (a2, r2) = r.split_by_greater_than(PartType::M, 2000);
+        let r2 = r2.unwrap();
+
+        assert!(a2.is_none());
+
+        assert_eq!(r2.get(PartType::X), Interval::inclusive(501, 4000));
+        assert_eq!(r2.get(PartType::M), Interval::inclusive(1, 1234));
+        assert_eq!(r2.get(PartType::A), Interval::inclusive(1, 4000));
+        assert_eq!(r2.get(PartType::S), Interval::inclusive(1, 4000));
+    }
+
+    #[test]
+    fn test_count_combinations() {
+        let c = PartCombinations {
+            parts: HashMap::from([
+                (PartType::X, Interval::inclusive(1, 1)),
+                (PartType::M, Interval::inclusive(1, 1)),
+                (PartType::A, Interval::inclusive(1, 1)),
+                (PartType::S, Interval::inclusive(1, 1)),
+            ]),
+        };
+        assert_eq!(c.count_combinations(), 1);
+
+        let c = PartCombinations {
+            parts: HashMap::from([
+                (PartType::X, Interval::inclusive(1, 2)),
+                (PartType::M, Interval::inclusive(1, 1)),
+                (PartType::A, Interval::inclusive(1, 1)),
+                (PartType::S, Interval::inclusive(1, 1)),
+            ]),
+        };
+        assert_eq!(c.count_combinations(), 2);
+
+        let c = PartCombinations {
+            parts: HashMap::from([
+                (PartType::X, Interval::inclusive(2, 2)),
+                (PartType::M, Interval::inclusive(1, 1)),
+                (PartType::A, Interval::inclusive(1, 1)),
+                (PartType::S, Interval::inclusive(1, 1)),
+            ]),
+        };
+        assert_eq!(c.count_combinations(), 1);
+
+        let c = PartCombinations {
+            parts: HashMap::from([
+                (PartType::X, Interval::inclusive(1, 100)),
+                (PartType::M, Interval::inclusive(1, 1)),
+                (PartType::A, Interval::inclusive(1, 1)),
+                (PartType::S, Interval::inclusive(1, 1)),
+            ]),
+        };
+        assert_eq!(c.count_combinations(), 100);
+
+        let c = PartCombinations {
+            parts: HashMap::from([
+                (PartType::X, Interval::inclusive(1, 2)),
+                (PartType::M, Interval::inclusive(1, 2)),
+                (PartType::A, Interval::inclusive(1, 2)),
+                (PartType::S, Interval::inclusive(1, 2)),
+            ]),
+        };
+        assert_eq!(c.count_combinations(), 16);
+    }
+}
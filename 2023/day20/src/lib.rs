@@ -1,8 +1,8 @@
 use core::fmt;
-use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    io,
-};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_common::number::{crt, smallest_at_least};
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 enum Pulse {
@@ -202,24 +202,47 @@ impl Module for BroadcastModule {
     }
 }
 
-fn parse(line: &str) -> Box<dyn Module> {
-    let t = line.split(" -> ").collect::<Vec<&str>>();
+/// A line of the input, from which a fresh module is built for each run.
+pub struct ModuleSpec<'a> {
+    kind: &'a str,
+    name: &'a str,
+    destinations: Vec<String>,
+}
+
+impl ModuleSpec<'_> {
+    fn build(&self) -> Box<dyn Module> {
+        let destinations = self.destinations.clone();
+        match self.kind {
+            "%" => Box::new(FlipFlopModule::new(self.name, destinations)),
+            "&" => Box::new(ConjunctionModule::new(self.name, destinations)),
+            _ => Box::new(BroadcastModule::new(destinations)),
+        }
+    }
+}
+
+fn parse(line: &str) -> ParseResult<ModuleSpec<'_>> {
+    let (module, destinations) = line.split_once_or_err(" -> ")?;
 
-    let destinations = t[1]
+    let destinations = destinations
         .split(',')
         .map(|s| s.trim().to_string())
         .collect::<Vec<_>>();
 
-    let m: Box<dyn Module> = if t[0] == "broadcaster" {
-        Box::new(BroadcastModule::new(destinations))
+    let (kind, name) = if module == "broadcaster" {
+        ("", module)
+    } else if module.starts_with(['%', '&']) {
+        module.split_at(1)
     } else {
-        match &t[0][0..1] {
-            "%" => Box::new(FlipFlopModule::new(&t[0][1..], destinations)),
-            "&" => Box::new(ConjunctionModule::new(&t[0][1..], destinations)),
-            _ => panic!("Unknown module type"),
-        }
+        return Err(ParseError::new(
+            module,
+            "expected `broadcaster`, `%<name>` or `&<name>`",
+        ));
     };
-    m
+    Ok(ModuleSpec {
+        kind,
+        name,
+        destinations,
+    })
 }
 
 fn wire_inputs(modules: &mut HashMap<String, Box<dyn Module>>) {
@@ -239,21 +262,29 @@ fn wire_inputs(modules: &mut HashMap<String, Box<dyn Module>>) {
 struct Machine {
     presses: u64,
     modules: HashMap<String, Box<dyn Module>>,
-    needed: HashMap<String, Option<u64>>,
+    /// Presses on which the inputs of the module feeding the destination
+    /// first sent a high pulse, up to two each.
+    needed: HashMap<String, Vec<u64>>,
 }
 
 impl Machine {
     fn new(modules: HashMap<String, Box<dyn Module>>, destination: &str) -> Self {
-        let last_inputs = modules.get(destination).unwrap().inputs();
-        assert_eq!(last_inputs.len(), 1);
-        let last_input = last_inputs.iter().next().unwrap();
-        let needed = modules
-            .get(last_input)
-            .unwrap()
-            .inputs()
-            .iter()
-            .map(|i| (i.clone(), None))
-            .collect();
+        // The examples have no `rx` module, only part 1 can run on them.
+        let needed = match modules.get(destination) {
+            Some(m) => {
+                let last_inputs = m.inputs();
+                assert_eq!(last_inputs.len(), 1);
+                let last_input = last_inputs.iter().next().unwrap();
+                modules
+                    .get(last_input)
+                    .unwrap()
+                    .inputs()
+                    .iter()
+                    .map(|i| (i.clone(), vec![]))
+                    .collect()
+            }
+            None => HashMap::new(),
+        };
         Self {
             presses: 0,
             modules,
@@ -262,7 +293,7 @@ impl Machine {
     }
 
     fn got_all_needed(&self) -> bool {
-        self.needed.values().all(|v| v.is_some())
+        self.needed.values().all(|v| v.len() == 2)
     }
 
     fn mark_needed(&mut self, name: &str, pulse: Pulse) {
@@ -270,8 +301,8 @@ impl Machine {
             return;
         }
         if let Some(n) = self.needed.get_mut(name) {
-            if n.is_none() {
-                *n = Some(self.presses);
+            if n.len() < 2 && n.last() != Some(&self.presses) {
+                n.push(self.presses);
             }
         }
     }
@@ -296,26 +327,54 @@ impl Machine {
     }
 }
 
-fn main() {
-    let mut modules = io::stdin()
-        .lines()
-        .map(|l| {
-            let m = parse(l.unwrap().as_str());
+fn build_machine(specs: &[ModuleSpec]) -> Machine {
+    let mut modules = specs
+        .iter()
+        .map(|spec| {
+            let m = spec.build();
             (m.name().to_string(), m)
         })
         .collect::<HashMap<_, _>>();
     wire_inputs(&mut modules);
+    Machine::new(modules, "rx")
+}
+
+pub struct Day;
 
-    let mut machine = Machine::new(modules, "rx");
-    let (l, h) = (0..1000)
-        .map(|_| machine.press_button())
-        .fold((0, 0), |(a, b), (c, d)| (a + c, b + d));
-    let r1 = l * h;
-    println!("{}", r1);
+impl Solution for Day {
+    type Input<'a> = Vec<ModuleSpec<'a>>;
+    type Params = ();
 
-    while !machine.got_all_needed() {
-        machine.press_button();
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let specs = input.lines().map(parse).collect::<ParseResult<Vec<_>>>()?;
+        if !specs.iter().any(|s| s.name == "broadcaster") {
+            return Err(ParseError::after(input, "expected a `broadcaster` module"));
+        }
+        Ok(specs)
+    }
+
+    fn part1(specs: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut machine = build_machine(specs);
+        let (l, h) = (0..1000)
+            .map(|_| machine.press_button())
+            .fold((0, 0), |(a, b), (c, d)| (a + c, b + d));
+        l * h
+    }
+
+    fn part2(specs: &Self::Input<'_>) -> impl Into<Answer> {
+        let mut machine = build_machine(specs);
+        while !machine.got_all_needed() {
+            machine.press_button();
+        }
+        // Each input sends a high pulse every so many presses, from its first
+        // one on.
+        let cycles = machine
+            .needed
+            .values()
+            .map(|v| (v[0] as i64, (v[1] - v[0]) as i64))
+            .collect::<Vec<_>>();
+        let (presses, period) = crt(cycles.iter().copied()).expect("rx never gets a low pulse");
+        let first = cycles.iter().map(|&(first, _)| first).max().unwrap();
+        smallest_at_least(presses, period, first)
     }
-    let r2 = machine.needed.values().map(|v| v.unwrap()).product::<u64>();
-    println!("{}", r2);
 }
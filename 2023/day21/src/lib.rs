@@ -0,0 +1,230 @@
+use std::collections::VecDeque;
+
+use aoc_common::{params, Answer, Coords, Grid, InputExt, ParseError, ParseResult, Solution};
+
+fn trace(grid: &Grid<char>, start: Coords, start_steps: u64) -> Grid<Option<u64>> {
+    let mut hit_grid = Grid::new(grid.width, grid.height, None);
+    let mut todo = VecDeque::from([(start, start_steps)]);
+    while let Some((p, steps)) = todo.pop_front() {
+        let s = &mut hit_grid[p];
+        if s.is_some() {
+            continue;
+        }
+        *s = Some(steps);
+        grid.neighbours4(p).for_each(|n| {
+            if grid[n] != '#' {
+                todo.push_back((n, steps + 1));
+            }
+        });
+    }
+    hit_grid
+}
+
+fn count_steps(hit_grid: &Grid<Option<u64>>, steps: u64) -> u64 {
+    if steps == 0 {
+        return 0;
+    }
+    let z = steps % 2;
+    hit_grid
+        .tiles
+        .iter()
+        .filter_map(|&s| {
+            if let Some(s) = s {
+                (s == steps || (s < steps && s % 2 == z))
+                    .then_some(s)
+                    .or(None)
+            } else {
+                None
+            }
+        })
+        .count() as u64
+}
+
+//    odo
+//   oidio
+//  oi.d.io
+// oi..d..io
+// ddddsdddd
+// oi..d..io
+//  oi.d.io
+//   oidio
+//    odo
+
+fn solve_corner(grid: &Grid<char>, start: Coords, steps: u64) -> u64 {
+    let steps_initial = grid.width as u64 + 1;
+    let steps_corner = steps - steps_initial;
+
+    let grids = steps_corner / grid.width as u64;
+    let steps_outer_remaining = steps_corner % grid.width as u64;
+    let steps_inner_remaining = steps_corner % grid.width as u64 + grid.width as u64;
+    assert!(steps_outer_remaining > 0);
+
+    let grids_inner = grids;
+    let grids_outer = grids + 1;
+
+    let grids_corner_odd = (1..grids).filter(|i| i % 2 == 1).sum::<u64>();
+    let grids_corner_even = (1..grids).filter(|i| i % 2 == 0).sum::<u64>();
+
+    let hit_grid = trace(grid, start, 0);
+
+    let steps_odd = count_steps(&hit_grid, steps_corner);
+    let steps_even = count_steps(&hit_grid, steps_corner + 1);
+    let steps_inner = count_steps(&hit_grid, steps_inner_remaining);
+    let steps_outer = count_steps(&hit_grid, steps_outer_remaining);
+    assert_eq!(steps_inner_remaining % 2, 1);
+    assert_eq!(steps_outer_remaining % 2, 0);
+
+    let before_last = count_steps(&hit_grid, steps_inner_remaining + grid.width as u64);
+    assert_eq!(before_last, steps_even);
+
+    steps_odd * grids_corner_odd
+        + steps_even * grids_corner_even
+        + steps_inner * grids_inner
+        + steps_outer * grids_outer
+}
+
+fn solve_direct(grid: &Grid<char>, start: Coords, steps: u64) -> u64 {
+    let steps_initial = grid.width as u64 / 2 + 1;
+    let steps_direct = steps - steps_initial;
+
+    let grids = steps_direct / grid.width as u64;
+    let steps_remaining = steps_direct % grid.width as u64;
+    assert!(steps_remaining > 0);
+
+    let grids_odd = grids / 2 + grids % 2;
+    let grids_even = grids / 2;
+
+    let hit_grid = trace(grid, start, 0);
+
+    let steps_odd = count_steps(&hit_grid, steps_direct);
+    let steps_even = count_steps(&hit_grid, steps_direct + 1);
+    let steps_last = count_steps(&hit_grid, steps_remaining);
+
+    let before_last = count_steps(&hit_grid, steps_remaining + grid.width as u64);
+    assert_eq!(before_last, steps_odd);
+
+    steps_odd * grids_odd + steps_even * grids_even + steps_last
+}
+
+fn solve2(grid: &Grid<char>, hit_grid: &Grid<Option<u64>>, steps: u64) -> u64 {
+    assert_eq!(grid.width, grid.height);
+    let z = grid.width / 2;
+
+    let base = count_steps(hit_grid, steps);
+
+    let right = solve_direct(grid, Coords::new(0, z), steps);
+    let left = solve_direct(grid, Coords::new(grid.width - 1, z), steps);
+    let up = solve_direct(grid, Coords::new(z, 0), steps);
+    let down = solve_direct(grid, Coords::new(z, grid.height - 1), steps);
+
+    let up_right = solve_corner(grid, Coords::new(0, grid.height - 1), steps);
+    let up_left = solve_corner(grid, Coords::new(grid.width - 1, grid.height - 1), steps);
+    let down_right = solve_corner(grid, Coords::new(0, 0), steps);
+    let down_left = solve_corner(grid, Coords::new(grid.width - 1, 0), steps);
+
+    base + right + left + up + down + up_right + up_left + down_right + down_left
+}
+
+const STEPS2: u64 = 26501365;
+
+params! {
+    pub struct Params {
+        /// Number of steps the elf takes in part 1
+        pub steps: usize = 64,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Grid<char>, Grid<Option<u64>>, Params);
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> ParseResult<Self::Input<'a>> {
+        let grid = input.grid_with(|c| ".#S".contains(c).then_some(c))?;
+        let start = grid
+            .find('S')
+            .ok_or_else(|| ParseError::new(input, "expected a start `S`"))?;
+        let hit_grid = trace(&grid, start, 0);
+        Ok((grid, hit_grid, *params))
+    }
+
+    fn part1((_, hit_grid, params): &Self::Input<'_>) -> impl Into<Answer> {
+        count_steps(hit_grid, params.steps as u64)
+    }
+
+    fn part2((grid, hit_grid, _): &Self::Input<'_>) -> impl Into<Answer> {
+        solve2(grid, hit_grid, STEPS2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A garden with what the solution relies on in the puzzle inputs: an odd
+    /// square with the start in the middle, a clear border, middle row and
+    /// middle column, and no detours, which rocks never touching each other,
+    /// even diagonally, ensure.
+    fn garden() -> impl Strategy<Value = Grid<char>> {
+        prop::sample::select(vec![7i64, 11, 15])
+            .prop_flat_map(|n| prop::collection::vec(prop::bool::weighted(0.3), (n * n) as usize))
+            .prop_map(|rocks| {
+                let n = (rocks.len() as f64).sqrt() as i64;
+                let mut grid = Grid::new(n, n, '.');
+                grid[Coords::new(n / 2, n / 2)] = 'S';
+                let clear = [0, n / 2, n - 1];
+                for p in grid.coords().collect::<Vec<_>>() {
+                    let touching = grid.neighbours8(p).any(|n| grid[n] == '#');
+                    if rocks[(p.y * n + p.x) as usize]
+                        && !clear.contains(&p.x)
+                        && !clear.contains(&p.y)
+                        && !touching
+                    {
+                        grid[p] = '#';
+                    }
+                }
+                grid
+            })
+    }
+
+    /// Walks the infinitely repeated garden one plot at a time.
+    fn naive_reachable(grid: &Grid<char>, steps: u64) -> u64 {
+        let garden = grid.as_torus();
+        let start = grid.find('S').unwrap();
+        let mut distance = HashMap::from([(start, 0)]);
+        let mut todo = VecDeque::from([start]);
+        while let Some(p) = todo.pop_front() {
+            let d = distance[&p];
+            if d == steps {
+                continue;
+            }
+            for n in garden.neighbours4(p) {
+                if garden[n] != '#' && !distance.contains_key(&n) {
+                    distance.insert(n, d + 1);
+                    todo.push_back(n);
+                }
+            }
+        }
+        distance.values().filter(|&&d| d % 2 == steps % 2).count() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn solve2_matches_naive(grid in garden(), half_repeats in 2..4u64) {
+            // Like the puzzle's 65 + 131 * 202300 steps, an even number of
+            // garden copies past the first edge, and enough of them for the
+            // copies next to the start to be filled.
+            let steps = grid.width as u64 / 2 + grid.width as u64 * half_repeats * 2;
+            let (grid, hit_grid, _) = Day::parse(&grid.to_string()).unwrap();
+            prop_assert_eq!(solve2(&grid, &hit_grid, steps), naive_reachable(&grid, steps));
+        }
+    }
+}
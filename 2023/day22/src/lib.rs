@@ -1,44 +1,10 @@
 use core::fmt;
-use std::{
-    cmp::Ordering,
-    collections::{HashMap, HashSet, VecDeque},
-    io,
-};
+use std::collections::{HashMap, HashSet, VecDeque};
 
+use aoc_common::{Answer, ParseExt, ParseResult, Solution, Vec3};
 use itertools::{iproduct, Itertools};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Coord {
-    x: usize,
-    y: usize,
-    z: usize,
-}
-
-impl Ord for Coord {
-    fn cmp(&self, other: &Self) -> Ordering {
-        match self.z.cmp(&other.z) {
-            Ordering::Equal => {}
-            ord => return ord,
-        }
-        match self.y.cmp(&other.y) {
-            Ordering::Equal => {}
-            ord => return ord,
-        }
-        self.x.cmp(&other.x)
-    }
-}
-
-impl PartialOrd for Coord {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl fmt::Display for Coord {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
-    }
-}
+type Coord = Vec3<i64>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Block {
@@ -51,59 +17,37 @@ impl Block {
     fn new(id: usize, start: Coord, end: Coord) -> Block {
         Block {
             id,
-            start: Coord {
-                x: start.x.min(end.x),
-                y: start.y.min(end.y),
-                z: start.z.min(end.z) - 1,
-            },
-            end: Coord {
-                x: start.x.max(end.x),
-                y: start.y.max(end.y),
-                z: start.z.max(end.z) - 1,
-            },
+            start: Coord::new(
+                start.x.min(end.x),
+                start.y.min(end.y),
+                start.z.min(end.z) - 1,
+            ),
+            end: Coord::new(
+                start.x.max(end.x),
+                start.y.max(end.y),
+                start.z.max(end.z) - 1,
+            ),
         }
     }
 }
 
-impl Ord for Block {
-    fn cmp(&self, other: &Self) -> Ordering {
-        match self.start.cmp(&other.start) {
-            Ordering::Equal => {}
-            ord => return ord,
-        }
-        self.end.cmp(&other.end)
-    }
-}
-
-impl PartialOrd for Block {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 impl fmt::Display for Block {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}: {}->{}", self.id, self.start, self.end)
     }
 }
 
-fn parse(id: usize, line: &str) -> Block {
-    fn parse_coord(t: &str) -> Coord {
-        let t = t
-            .split(',')
-            .map(|x| x.parse::<usize>().unwrap())
-            .collect::<Vec<usize>>();
-        Coord {
-            x: t[0],
-            y: t[1],
-            z: t[2],
-        }
+fn parse(id: usize, line: &str) -> ParseResult<Block> {
+    fn parse_coord(t: &str) -> ParseResult<Coord> {
+        let (x, rest) = t.split_once_or_err(",")?;
+        let (y, z) = rest.split_once_or_err(",")?;
+        Ok(Coord::new(x.parse_as()?, y.parse_as()?, z.parse_as()?))
     }
-    let t = line.split('~').collect::<Vec<&str>>();
-    Block::new(id, parse_coord(t[0]), parse_coord(t[1]))
+    let (start, end) = line.split_once_or_err("~")?;
+    Ok(Block::new(id, parse_coord(start)?, parse_coord(end)?))
 }
 
-struct World {
+pub struct World {
     tiles: Vec<[usize; 10 * 10]>,
     blocks: HashMap<usize, Block>,
 }
@@ -116,16 +60,18 @@ impl World {
         }
     }
 
-    fn set(&mut self, x: usize, y: usize, z: usize, id: usize) {
-        assert!(x < 10 && y < 10);
+    fn set(&mut self, x: i64, y: i64, z: i64, id: usize) {
+        assert!((0..10).contains(&x) && (0..10).contains(&y));
+        let (x, y, z) = (x as usize, y as usize, z as usize);
         if z + 1 > self.tiles.len() {
             self.tiles.resize(z + 1, [0; 10 * 10]);
         }
         self.tiles[z][y * 10 + x] = id;
     }
 
-    fn get(&self, x: usize, y: usize, z: usize) -> usize {
-        assert!(x < 10 && y < 10);
+    fn get(&self, x: i64, y: i64, z: i64) -> usize {
+        assert!((0..10).contains(&x) && (0..10).contains(&y));
+        let (x, y, z) = (x as usize, y as usize, z as usize);
         assert!(z < self.tiles.len());
         self.tiles[z][y * 10 + x]
     }
@@ -163,7 +109,7 @@ impl World {
 
     fn drop_blocks(&mut self) {
         let mut blocks = self.blocks.clone().into_values().collect_vec();
-        blocks.sort();
+        blocks.sort_by_key(|b| b.start.z);
         blocks.iter().map(|b| b.id).for_each(|id| loop {
             if !self.is_free_below(id) {
                 break;
@@ -260,20 +206,30 @@ impl World {
     }
 }
 
-fn main() {
-    let blocks = io::stdin()
-        .lines()
-        .enumerate()
-        .map(|(i, l)| parse(i + 1, l.unwrap().as_str()))
-        .collect::<Vec<Block>>();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = World;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let blocks = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse(i + 1, l))
+            .collect::<ParseResult<Vec<Block>>>()?;
 
-    let mut world = World::new();
-    blocks.iter().for_each(|b| world.add_block(*b));
-    world.drop_blocks();
+        let mut world = World::new();
+        blocks.iter().for_each(|b| world.add_block(*b));
+        world.drop_blocks();
+        Ok(world)
+    }
 
-    let r1 = world.analyse();
-    println!("{}", r1);
+    fn part1(world: &Self::Input<'_>) -> impl Into<Answer> {
+        world.analyse()
+    }
 
-    let r2 = world.analyse2();
-    println!("{}", r2);
+    fn part2(world: &Self::Input<'_>) -> impl Into<Answer> {
+        world.analyse2()
+    }
 }
@@ -0,0 +1,173 @@
+use core::fmt;
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, Coords, Grid, InputExt, ParseResult, Solution};
+use itertools::Itertools;
+
+type Node = Coords;
+
+#[derive(Debug)]
+struct Edge {
+    to: Node,
+    steps: usize,
+}
+
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  -> {:?} ({})", self.to, self.steps)
+    }
+}
+
+#[derive(Copy, Clone, Eq, Hash, PartialEq, Debug)]
+enum EdgeDirection {
+    Both,
+    Forward,
+    Backward,
+    Blocked,
+}
+
+#[derive(Debug)]
+struct Graph {
+    nodes: HashMap<Node, Vec<Edge>>,
+    end_y: i64,
+}
+
+impl fmt::Display for Graph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.nodes.iter().try_for_each(|(n, e)| {
+            writeln!(f, "{:?}", n)?;
+            e.iter().try_for_each(|e| writeln!(f, "{}", e))?;
+            Ok(())
+        })
+    }
+}
+
+impl Graph {
+    pub fn trace(grid: &Grid<char>, start: Node, ignore_slopes: bool) -> Graph {
+        let mut nodes = HashMap::<Node, Vec<Edge>>::new();
+        let mut hit_grid = Grid::new(grid.width, grid.height, false);
+
+        let mut todo = Vec::new();
+        todo.push((start, (start, 0usize, Coords::DOWN, EdgeDirection::Both)));
+
+        while let Some((p, (start, steps, dir, edge_dir))) = todo.pop() {
+            if hit_grid[p] {
+                continue;
+            }
+
+            if p.y == grid.height - 1 {
+                assert_eq!(grid[p], '.');
+                nodes.entry(start).or_default().push(Edge { to: p, steps });
+                continue;
+            }
+
+            let n = grid
+                .neighbours4(p)
+                .filter(|&n| grid[n] != '#')
+                .collect_vec();
+            let t = grid[p];
+            assert_ne!(t, '#');
+            assert!(
+                t == '.' || n.len() == 2,
+                "Slope expected to have two neighbours! ({} {:?})",
+                t,
+                n
+            );
+            let is_slope = t != '.' && !ignore_slopes;
+            let is_slope_same_dir = match t {
+                '>' => dir == Coords::RIGHT,
+                '<' => dir == Coords::LEFT,
+                '^' => dir == Coords::UP,
+                'v' => dir == Coords::DOWN,
+                '.' => true,
+                _ => panic!(),
+            };
+            let new_edge_dir = match (is_slope, is_slope_same_dir, edge_dir) {
+                (false, _, _) => edge_dir,
+                (true, true, EdgeDirection::Both) => EdgeDirection::Forward,
+                (true, true, EdgeDirection::Backward) => EdgeDirection::Blocked,
+                (true, true, _) => edge_dir,
+                (true, false, EdgeDirection::Both) => EdgeDirection::Backward,
+                (true, false, EdgeDirection::Forward) => EdgeDirection::Blocked,
+                (true, false, _) => edge_dir,
+            };
+
+            let is_crossing = n.len() > 2;
+            hit_grid[p] = !is_crossing;
+
+            if is_crossing {
+                if new_edge_dir == EdgeDirection::Both || new_edge_dir == EdgeDirection::Forward {
+                    nodes.entry(start).or_default().push(Edge { to: p, steps });
+                }
+                if new_edge_dir == EdgeDirection::Both || new_edge_dir == EdgeDirection::Backward {
+                    nodes.entry(p).or_default().push(Edge { to: start, steps });
+                }
+            }
+            let (start, steps, new_edge_dir) = if is_crossing {
+                (p, 0, EdgeDirection::Both)
+            } else {
+                (start, steps, new_edge_dir)
+            };
+
+            n.into_iter().filter(|&n| !hit_grid[n]).for_each(|n| {
+                let d = n - p;
+                if dir + d == Coords::new(0, 0) {
+                    return;
+                }
+                todo.push((n, (start, steps + 1, d, new_edge_dir)));
+            });
+        }
+        Graph {
+            nodes,
+            end_y: grid.height - 1,
+        }
+    }
+
+    pub fn find_paths(&self, start: Node) -> Vec<usize> {
+        let mut result = Vec::new();
+
+        let mut todo = Vec::new();
+        todo.push((start, 0usize, HashSet::new()));
+
+        while let Some((n, steps, seen)) = todo.pop() {
+            if n.y == self.end_y {
+                result.push(steps);
+                continue;
+            }
+
+            let edges = self.nodes.get(&n).unwrap();
+            edges.iter().for_each(|e| {
+                if seen.contains(&e.to) {
+                    return;
+                }
+                let mut seen = seen.clone();
+                seen.insert(e.to);
+                todo.push((e.to, steps + e.steps, seen));
+            });
+        }
+        result
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.grid_with(|c| "#.<>^v".contains(c).then_some(c))
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        let start = Node::new(1, 0);
+        let graph = Graph::trace(grid, start, false);
+        graph.find_paths(start).into_iter().max().unwrap()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> impl Into<Answer> {
+        let start = Node::new(1, 0);
+        let graph = Graph::trace(grid, start, true);
+        graph.find_paths(start).into_iter().max().unwrap()
+    }
+}
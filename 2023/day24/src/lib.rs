@@ -0,0 +1,272 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{params, Answer, ParseExt, ParseResult, Rational, Solution, Vec3};
+use itertools::{iproduct, Itertools};
+
+type Q = Rational<i128>;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Hailstone {
+    pos: Vec3<i64>,
+    vel: Vec3<i64>,
+}
+
+fn component(v: Vec3<i64>, idx: usize) -> i128 {
+    match idx {
+        0 => v.x as i128,
+        1 => v.y as i128,
+        2 => v.z as i128,
+        _ => panic!("Invalid index"),
+    }
+}
+
+fn parse_vec3(s: &str) -> ParseResult<Vec3<i64>> {
+    let (x, rest) = s.split_once_or_err(",")?;
+    let (y, z) = rest.split_once_or_err(",")?;
+    Ok(Vec3::new(
+        x.trim().parse_as()?,
+        y.trim().parse_as()?,
+        z.trim().parse_as()?,
+    ))
+}
+
+fn parse(line: &str) -> ParseResult<Hailstone> {
+    let (pos, vel) = line.split_once_or_err("@")?;
+    Ok(Hailstone {
+        pos: parse_vec3(pos)?,
+        vel: parse_vec3(vel)?,
+    })
+}
+
+/// Where the paths of `a` and `b` cross in the XY plane, if they cross ahead
+/// of both hailstones.
+fn crossing_2d(a: &Hailstone, b: &Hailstone) -> Option<(Q, Q)> {
+    let cross = |u: (i128, i128), v: (i128, i128)| u.0 * v.1 - u.1 * v.0;
+    let va = (a.vel.x as i128, a.vel.y as i128);
+    let vb = (b.vel.x as i128, b.vel.y as i128);
+    let d = cross(va, vb);
+    if d == 0 {
+        return None;
+    }
+    let dp = ((b.pos.x - a.pos.x) as i128, (b.pos.y - a.pos.y) as i128);
+    let ta = Q::new(cross(dp, vb), d);
+    let tb = Q::new(cross(dp, va), d);
+    if ta < Q::from(0) || tb < Q::from(0) {
+        return None;
+    }
+    let at = |p: i64, v: i64| Q::from(p as i128) + Q::from(v as i128) * ta;
+    Some((at(a.pos.x, a.vel.x), at(a.pos.y, a.vel.y)))
+}
+
+fn find_most_used_velocities(velocities: &[i128]) -> Vec<(&i128, i32)> {
+    velocities
+        .iter()
+        .fold(HashMap::new(), |mut acc, v| {
+            *acc.entry(v).or_insert(0) += 1;
+            acc
+        })
+        .into_iter()
+        .filter(|(_, c)| *c > 1)
+        .collect_vec()
+}
+
+fn find_suitable_rock_velocities(
+    hailstones: &[Hailstone],
+    idx: usize,
+    m_vel: i128,
+) -> HashSet<i128> {
+    let hailstones_with_m_vel = hailstones
+        .iter()
+        .filter(|h| component(h.vel, idx) == m_vel)
+        .collect_vec();
+    let velocities = (-1000..=1000)
+        .filter(|&rock_vel| {
+            hailstones_with_m_vel.iter().combinations(2).all(|c| {
+                let a = c[0];
+                let b = c[1];
+
+                let v = (rock_vel - component(a.vel, idx)).abs();
+                let d = (component(a.pos, idx) - component(b.pos, idx)).abs();
+                // A rock keeping pace with both only meets them if they are
+                // level with each other along this axis.
+                if v == 0 {
+                    return d == 0;
+                }
+                d % v == 0
+            })
+        })
+        .collect::<HashSet<_>>();
+    velocities
+}
+
+/// The velocities along axis `idx` the rock may have. The hailstones sharing
+/// a velocity along it rule out most, though not always all but one.
+fn find_rock_velocities(hailstones: &[Hailstone], idx: usize) -> HashSet<i128> {
+    let vel = hailstones
+        .iter()
+        .map(|h| component(h.vel, idx))
+        .collect_vec();
+    let m_vel = find_most_used_velocities(&vel);
+
+    m_vel
+        .iter()
+        .map(|v| find_suitable_rock_velocities(hailstones, idx, *v.0))
+        .reduce(|a, b| a.intersection(&b).cloned().collect())
+        .expect("some hailstones should share a velocity along each axis")
+}
+
+/// Where a rock thrown with velocity `vel` has to start from to hit the first
+/// hailstone and another one whose path, as seen from the rock, is not
+/// parallel to it, if there is one.
+fn rock_position(hailstones: &[Hailstone], vel: [Q; 3]) -> Option<[Q; 3]> {
+    let dot = |a: [Q; 3], b: [Q; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let cross = |a: [Q; 3], b: [Q; 3]| {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    };
+    // Each hailstone's path as seen from the rock, which both start on.
+    let seen_from_rock = |h: &Hailstone| {
+        let pos = [0, 1, 2].map(|i| Q::from(component(h.pos, i)));
+        let w = [0, 1, 2].map(|i| Q::from(component(h.vel, i)) - vel[i]);
+        (pos, w)
+    };
+    let (p1, w1) = seen_from_rock(&hailstones[0]);
+    hailstones[1..].iter().find_map(|h| {
+        let (p2, w2) = seen_from_rock(h);
+        let n = cross(w1, w2);
+        let d = dot(n, n);
+        if d == Q::from(0) {
+            return None;
+        }
+        let dp = [0, 1, 2].map(|i| p2[i] - p1[i]);
+        let t = dot(cross(dp, w2), n) / d;
+        Some([0, 1, 2].map(|i| p1[i] + w1[i] * t))
+    })
+}
+
+/// Whether a rock thrown from `pos` with velocity `vel` hits every hailstone.
+fn hits_all(hailstones: &[Hailstone], pos: [Q; 3], vel: [Q; 3]) -> bool {
+    hailstones.iter().all(|h| {
+        let hp = [0, 1, 2].map(|i| Q::from(component(h.pos, i)));
+        let hv = [0, 1, 2].map(|i| Q::from(component(h.vel, i)));
+        let Some(i) = (0..3).find(|&i| hv[i] != vel[i]) else {
+            return hp == pos;
+        };
+        let t = (hp[i] - pos[i]) / (vel[i] - hv[i]);
+        t >= Q::from(0) && (0..3).all(|i| hp[i] + hv[i] * t == pos[i] + vel[i] * t)
+    })
+}
+
+params! {
+    pub struct Params {
+        /// Bounds of the test area, on both the X and Y axes
+        pub test_area_min: i64 = 200000000000000,
+        pub test_area_max: i64 = 400000000000000,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<Hailstone>, Params);
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> ParseResult<Self::Input<'a>> {
+        let hailstones = input.lines().map(parse).collect::<ParseResult<_>>()?;
+        Ok((hailstones, *params))
+    }
+
+    fn part1((hailstones, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let area = Q::from(params.test_area_min as i128)..=Q::from(params.test_area_max as i128);
+        hailstones
+            .iter()
+            .tuple_combinations()
+            .filter_map(|(a, b)| crossing_2d(a, b))
+            .filter(|(x, y)| area.contains(x) && area.contains(y))
+            .count()
+    }
+
+    fn part2((hailstones, _): &Self::Input<'_>) -> impl Into<Answer> {
+        let [xs, ys, zs] = [0, 1, 2].map(|idx| find_rock_velocities(hailstones, idx));
+        let [x_pos, y_pos, z_pos] = iproduct!(&xs, &ys, &zs)
+            .find_map(|(&x, &y, &z)| {
+                let vel = [x, y, z].map(Q::from);
+                let pos = rock_position(hailstones, vel)?;
+                hits_all(hailstones, pos, vel).then_some(pos)
+            })
+            .expect("a rock should hit every hailstone");
+
+        (x_pos + y_pos + z_pos)
+            .to_integer()
+            .expect("the rock should start at integer coordinates")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Hailstones a rock thrown from `rock` hits, at distinct times. Like in
+    /// the puzzle inputs, many hailstones share a velocity along some axis,
+    /// and none has the rock's.
+    fn hailstones() -> impl Strategy<Value = (Vec3<i64>, Vec<Hailstone>)> {
+        let vec3 = |r: std::ops::Range<i64>| {
+            (r.clone(), r.clone(), r).prop_map(|(x, y, z)| Vec3::new(x, y, z))
+        };
+        let nonzero = (1..10i64, any::<bool>()).prop_map(|(d, neg)| if neg { -d } else { d });
+        let offset =
+            (nonzero.clone(), nonzero.clone(), nonzero).prop_map(|(x, y, z)| Vec3::new(x, y, z));
+        (
+            vec3(-1_000_000_000..1_000_000_000),
+            vec3(-8..8),
+            prop::collection::btree_set(1..1_000_000i64, 30..60),
+        )
+            .prop_flat_map(move |(rock, rock_vel, times)| {
+                let offsets = prop::collection::vec(offset.clone(), times.len());
+                (Just(rock), Just(rock_vel), Just(times), offsets)
+            })
+            .prop_map(|(rock, rock_vel, times, offsets)| {
+                let hailstones = times
+                    .into_iter()
+                    .zip(offsets)
+                    .map(|(t, offset)| Hailstone {
+                        pos: rock - offset * t,
+                        vel: rock_vel + offset,
+                    })
+                    .collect();
+                (rock, hailstones)
+            })
+    }
+
+    /// Tries every rock velocity in a box around the generated ones, without
+    /// the search narrowing them down, and keeps the rocks hitting every
+    /// hailstone.
+    fn naive_rocks(hailstones: &[Hailstone]) -> Vec<[Q; 3]> {
+        iproduct!(-10..10, -10..10, -10..10)
+            .filter_map(|(x, y, z)| {
+                let vel = [x, y, z].map(Q::from);
+                let pos = rock_position(hailstones, vel)?;
+                hits_all(hailstones, pos, vel).then_some(pos)
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn part2_matches_naive((rock, hailstones) in hailstones()) {
+            let rock_pos = [rock.x, rock.y, rock.z].map(|c| Q::from(c as i128));
+            prop_assert_eq!(naive_rocks(&hailstones), vec![rock_pos]);
+            let expected = rock.x + rock.y + rock.z;
+            let input = (hailstones, Params::default());
+            prop_assert_eq!(Day::part2(&input).into(), Answer::from(expected));
+        }
+    }
+}
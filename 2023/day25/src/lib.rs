@@ -0,0 +1,83 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
+
+fn parse(line: &str) -> ParseResult<(&str, Vec<&str>)> {
+    let (left, right) = line.split_once_or_err(":")?;
+    let right = right.split_whitespace().collect::<Vec<&str>>();
+    Ok((left.trim(), right))
+}
+
+type Network<'a> = BTreeMap<&'a str, BTreeSet<&'a str>>;
+
+/// The components `source` reaches without crossing a cut of at most
+/// `max_cut` wires separating it from `sink`, if there is one: augmenting
+/// paths are added until none is left, so the components reached in the
+/// last search are the source's side of a minimum cut.
+fn cut_side<'a>(
+    network: &Network<'a>,
+    source: &'a str,
+    sink: &'a str,
+    max_cut: usize,
+) -> Option<HashSet<&'a str>> {
+    // Net flow along each wire, in both directions, as wires carry one unit.
+    let mut flow = HashMap::<(&str, &str), i32>::new();
+    for _ in 0..=max_cut {
+        let mut parents = HashMap::from([(source, source)]);
+        let mut todo = VecDeque::from([source]);
+        while let Some(n) = todo.pop_front() {
+            if n == sink {
+                break;
+            }
+            for &m in &network[n] {
+                if flow.get(&(n, m)).copied().unwrap_or(0) < 1 && !parents.contains_key(m) {
+                    parents.insert(m, n);
+                    todo.push_back(m);
+                }
+            }
+        }
+        if !parents.contains_key(sink) {
+            return Some(parents.into_keys().collect());
+        }
+        let mut n = sink;
+        while n != source {
+            let p = parents[n];
+            *flow.entry((p, n)).or_default() += 1;
+            *flow.entry((n, p)).or_default() -= 1;
+            n = p;
+        }
+    }
+    None
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Network<'a>;
+    type Params = ();
+
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let mut network = Network::new();
+        for line in input.lines() {
+            let (l, r) = parse(line)?;
+            r.iter().for_each(|n| {
+                network.entry(n).or_default().insert(l);
+            });
+            network.entry(l).or_default().extend(r);
+        }
+        Ok(network)
+    }
+
+    fn part1(network: &Self::Input<'_>) -> impl Into<Answer> {
+        // The first component is on one side of the three wires to cut, and
+        // some other is on the other side.
+        let mut components = network.keys().copied();
+        let source = components.next().expect("the network should not be empty");
+        let side = components
+            .find_map(|sink| cut_side(network, source, sink, 3))
+            .expect("three wires should split the network in two");
+        side.len() * (network.len() - side.len())
+    }
+}
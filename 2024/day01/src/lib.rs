@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
+
+fn parse(line: &str) -> ParseResult<(u32, u32)> {
+    let (a, b) = line.split_once_or_err(" ")?;
+    Ok((a.parse_as()?, b.trim_start().parse_as()?))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<u32>, Vec<u32>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let (mut l1, mut l2): (Vec<_>, Vec<_>) = input
+            .lines()
+            .map(parse)
+            .collect::<ParseResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        l1.sort();
+        l2.sort();
+        Ok((l1, l2))
+    }
+
+    fn part1((l1, l2): &Self::Input<'_>) -> impl Into<Answer> {
+        l1.iter().zip(l2).map(|(a, b)| a.abs_diff(*b)).sum::<u32>()
+    }
+
+    fn part2((l1, l2): &Self::Input<'_>) -> impl Into<Answer> {
+        let right_hits = l2.iter().fold(HashMap::new(), |mut m, &b| {
+            m.entry(b).and_modify(|e| *e += 1).or_insert(1);
+            m
+        });
+        l1.iter()
+            .map(|a| a * right_hits.get(a).unwrap_or(&0))
+            .sum::<u32>()
+    }
+}
@@ -1,9 +1,7 @@
-use std::io;
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
 
-fn parse(line: &str) -> Vec<i32> {
-    line.split_whitespace()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect::<Vec<_>>()
+fn parse(line: &str) -> ParseResult<Vec<i32>> {
+    line.split_whitespace().map(|s| s.parse_as()).collect()
 }
 
 fn check_increasing(v: &[i32]) -> Vec<usize> {
@@ -60,14 +58,21 @@ fn check2(v: &[i32]) -> bool {
     false
 }
 
-fn main() {
-    let r = io::stdin()
-        .lines()
-        .map(|l| parse(l.unwrap().as_str()))
-        .collect::<Vec<_>>();
-    let r1 = r.iter().filter(|a| check1(a)).count();
-    println!("{}", r1);
+pub struct Day;
 
-    let r2 = r.iter().filter(|a| check2(a)).count();
-    println!("{}", r2);
+impl Solution for Day {
+    type Input<'a> = Vec<Vec<i32>>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(parse).collect()
+    }
+
+    fn part1(r: &Self::Input<'_>) -> impl Into<Answer> {
+        r.iter().filter(|a| check1(a)).count()
+    }
+
+    fn part2(r: &Self::Input<'_>) -> impl Into<Answer> {
+        r.iter().filter(|a| check2(a)).count()
+    }
 }
@@ -0,0 +1,41 @@
+use aoc_common::{Answer, ParseResult, Solution};
+use regex::Regex;
+
+fn multiply_all(re: &Regex, s: &str) -> i32 {
+    re.captures_iter(s)
+        .map(|m| {
+            let a = m[1].parse::<i32>().unwrap();
+            let b = m[2].parse::<i32>().unwrap();
+            a * b
+        })
+        .sum::<i32>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = String;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        // Corrupted memory, anything which is not an instruction is skipped.
+        Ok(input.lines().collect::<Vec<_>>().join(""))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+        multiply_all(&re, input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+        let re_do = Regex::new(r"do\(\)").unwrap();
+        let re_dont = Regex::new(r"don't\(\)").unwrap();
+
+        re_do
+            .split(input)
+            .flat_map(|s| re_dont.split(s).take(1))
+            .map(|s| multiply_all(&re, s))
+            .sum::<i32>()
+    }
+}
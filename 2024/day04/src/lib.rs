@@ -1,6 +1,6 @@
-use std::io;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
 
-fn check_xmas(input: &[String]) -> usize {
+fn check_xmas(input: &[&str]) -> usize {
     let width = input[0].len() as isize;
     let height = input.len() as isize;
 
@@ -42,7 +42,7 @@ fn check_xmas(input: &[String]) -> usize {
         .sum()
 }
 
-fn check_xmas2(input: &[String]) -> usize {
+fn check_xmas2(input: &[&str]) -> usize {
     let width = input[0].len() as isize;
     let height = input.len() as isize;
 
@@ -72,12 +72,28 @@ fn check_xmas2(input: &[String]) -> usize {
         .count()
 }
 
-fn main() {
-    let input = io::stdin().lines().map(|l| l.unwrap()).collect::<Vec<_>>();
+pub struct Day;
 
-    let r1 = check_xmas(&input);
-    println!("{}", r1);
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
 
-    let r2 = check_xmas2(&input);
-    println!("{}", r2);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let Some(width) = lines.first().map(|l| l.len()) else {
+            return Err(ParseError::new(input, "expected a word search"));
+        };
+        if let Some(l) = lines.iter().find(|l| l.len() != width || !l.is_ascii()) {
+            return Err(ParseError::new(l, format!("expected {width} letters")));
+        }
+        Ok(lines)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        check_xmas(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        check_xmas2(input)
+    }
 }
@@ -0,0 +1,76 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use aoc_common::{Answer, InputExt, ParseExt, ParseResult, Solution};
+
+fn check(rules: &HashMap<u32, Vec<u32>>, updates: &[u32]) -> bool {
+    (0..updates.len() - 1).all(|i| {
+        let v0 = updates[i];
+        let v1 = updates[i + 1];
+
+        if let Some(r) = rules.get(&v0) {
+            r.contains(&v1)
+        } else {
+            false
+        }
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let [rules, updates] = input.split_blocks()?;
+
+        let rules = rules
+            .lines()
+            .map(|l| {
+                // 47|53
+                let (a, b) = l.split_once_or_err("|")?;
+                Ok((a.parse_as()?, b.parse_as()?))
+            })
+            .collect::<ParseResult<Vec<(u32, u32)>>>()?
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, (a, b)| {
+                let item: &mut Vec<u32> = acc.entry(a).or_default();
+                item.push(b);
+                acc
+            });
+        let updates = updates
+            .lines()
+            .map(|l| l.split(",").map(|t| t.parse_as()).collect())
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input<'_>) -> impl Into<Answer> {
+        updates
+            .iter()
+            .filter(|u| check(rules, u))
+            .map(|u| u[u.len() / 2])
+            .sum::<u32>()
+    }
+
+    fn part2((rules, updates): &Self::Input<'_>) -> impl Into<Answer> {
+        updates
+            .iter()
+            .filter(|u| !check(rules, u))
+            .map(|u| {
+                let mut u = u.clone();
+                u.sort_by(|a, b| {
+                    if let Some(r_a) = rules.get(a) {
+                        if r_a.contains(b) {
+                            return Ordering::Less;
+                        }
+                    }
+                    Ordering::Greater
+                });
+                assert!(check(rules, &u));
+                u
+            })
+            .map(|u| u[u.len() / 2])
+            .sum::<u32>()
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use aoc_common::parallel::prelude::*;
+use aoc_common::render::{self, Frame, Rgb};
+use aoc_common::{Answer, Coords, Direction, Grid, InputExt, ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guard {
+    p: Coords,
+    dir: Direction,
+}
+
+pub enum AdvanceResult {
+    Ok(Guard),
+    OutOfBounds,
+    LoopDetected,
+}
+
+impl Guard {
+    pub fn new(p: Coords, dir: Direction) -> Guard {
+        Guard { p, dir }
+    }
+
+    pub fn advance(
+        &self,
+        grid: &Grid<char>,
+        visited: &mut HashSet<Coords>,
+        loop_guard: &mut HashSet<(Coords, Direction)>,
+    ) -> AdvanceResult {
+        loop_guard.insert((self.p, self.dir));
+        if let Some(p) = grid.step(self.p, self.dir) {
+            let t = grid[p];
+            if t == '#' {
+                AdvanceResult::Ok(Guard {
+                    p: self.p,
+                    dir: self.dir.right(),
+                })
+            } else {
+                if loop_guard.contains(&(p, self.dir)) {
+                    return AdvanceResult::LoopDetected;
+                }
+                visited.insert(p);
+                loop_guard.insert((p, self.dir));
+                AdvanceResult::Ok(Guard { p, dir: self.dir })
+            }
+        } else {
+            AdvanceResult::OutOfBounds
+        }
+    }
+}
+
+const PALETTE: &[Rgb] = &[[16, 16, 32], [120, 120, 120], [60, 90, 160], [230, 190, 90]];
+
+fn draw(grid: &Grid<char>, guard: &Guard, visited: &HashSet<Coords>) -> Frame {
+    Frame::from_grid(grid, PALETTE, |p, &t| match t {
+        _ if p == guard.p => 3,
+        '#' => 1,
+        _ if visited.contains(&p) => 2,
+        _ => 0,
+    })
+}
+
+/// Walks the guard off the map, returning the visited tiles and every
+/// position the guard stood on after a move or a turn.
+fn patrol(grid: &Grid<char>, guard0: &Guard) -> (HashSet<Coords>, HashSet<Coords>) {
+    let mut guard = guard0.clone();
+    let mut visited = HashSet::new();
+    let mut loop_guard = HashSet::new();
+    let mut todo = HashSet::new();
+    loop {
+        guard = match guard.advance(grid, &mut visited, &mut loop_guard) {
+            AdvanceResult::Ok(guard) => guard,
+            AdvanceResult::OutOfBounds => break,
+            AdvanceResult::LoopDetected => panic!(),
+        };
+        render::record(|| draw(grid, &guard, &visited));
+        todo.insert(guard.p);
+    }
+    (visited, todo)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Grid<char>, Guard);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let grid = input.grid_with(|c| ".#^".contains(c).then_some(c))?;
+        let start = grid
+            .find('^')
+            .ok_or_else(|| ParseError::new(input, "expected a guard `^`"))?;
+        Ok((grid, Guard::new(start, Direction::Up)))
+    }
+
+    fn part1((grid, guard0): &Self::Input<'_>) -> impl Into<Answer> {
+        let (visited, _) = patrol(grid, guard0);
+        visited.len()
+    }
+
+    fn part2((grid, guard0): &Self::Input<'_>) -> impl Into<Answer> {
+        let (_, todo) = patrol(grid, guard0);
+        let todo = todo.into_iter().collect::<Vec<_>>();
+        todo.maybe_par_iter()
+            .map(|&c| {
+                let mut grid2 = grid.clone();
+                if grid2[c] == '^' {
+                    return false;
+                }
+                grid2[c] = '#';
+                let mut guard2 = guard0.clone();
+                let mut visited2 = HashSet::new();
+                let mut loop_guard2 = HashSet::new();
+                loop {
+                    guard2 = match guard2.advance(&grid2, &mut visited2, &mut loop_guard2) {
+                        AdvanceResult::Ok(guard) => guard,
+                        AdvanceResult::OutOfBounds => break,
+                        AdvanceResult::LoopDetected => return true,
+                    };
+                }
+                false
+            })
+            .filter(|&b| b)
+            .count()
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::VecDeque;
+
+use aoc_common::parallel::prelude::*;
+use aoc_common::{Answer, ParseError, ParseExt, ParseResult, Solution};
+
+#[derive(Clone, Copy)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+#[derive(Debug, Clone)]
+pub struct Equation<T = u64> {
+    left: T,
+    right: VecDeque<T>,
+}
+
+impl Equation {
+    pub fn execute(&self, op: Operator) -> Equation {
+        let mut e = self.clone();
+        let a = e.right.pop_front().unwrap();
+        let b = e.right.pop_front().unwrap();
+        let r = match op {
+            Operator::Add => a + b,
+            Operator::Multiply => a * b,
+            Operator::Concat => format!("{}{}", a, b).parse::<u64>().unwrap(),
+        };
+        e.right.push_front(r);
+        e
+    }
+}
+
+fn parse(s: &str) -> ParseResult<Equation> {
+    let (left, right) = s.split_once_or_err(": ")?;
+    let right = right
+        .split_whitespace()
+        .map(|t| t.parse_as())
+        .collect::<ParseResult<VecDeque<_>>>()?;
+    if right.is_empty() {
+        return Err(ParseError::after(s, "expected numbers"));
+    }
+    Ok(Equation {
+        left: left.parse_as()?,
+        right,
+    })
+}
+
+fn solve(e: &Equation, operators: &[Operator]) -> bool {
+    let mut todo = vec![e.clone()];
+    while let Some(e) = todo.pop() {
+        if e.right.len() == 1 {
+            if e.left == e.right[0] {
+                return true;
+            }
+            continue;
+        }
+        if e.right.is_empty() {
+            panic!("Invalid equation: {:?}", e);
+        }
+        operators.iter().for_each(|op| todo.push(e.execute(*op)))
+    }
+    false
+}
+
+fn partition(equations: &[Equation]) -> (Vec<&Equation>, Vec<&Equation>) {
+    equations
+        .maybe_par_iter()
+        .partition(|e| solve(e, [Operator::Add, Operator::Multiply].as_ref()))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Equation>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(parse).collect()
+    }
+
+    fn part1(equations: &Self::Input<'_>) -> impl Into<Answer> {
+        let (e_1, _) = partition(equations);
+        e_1.iter().map(|e| e.left).sum::<u64>()
+    }
+
+    fn part2(equations: &Self::Input<'_>) -> impl Into<Answer> {
+        let (e_1, e_2) = partition(equations);
+        let r1 = e_1.iter().map(|e| e.left).sum::<u64>();
+        let r2b = e_2
+            .maybe_par_iter()
+            .filter(|e| {
+                solve(
+                    e,
+                    [Operator::Add, Operator::Multiply, Operator::Concat].as_ref(),
+                )
+            })
+            .map(|e| e.left)
+            .sum::<u64>();
+        r1 + r2b
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Coords, Grid, InputExt, ParseResult, Solution};
+use itertools::Itertools;
+
+fn get_antinodes(a: Coords, b: Coords) -> Vec<Coords> {
+    let ab = b - a;
+    vec![a - ab, b + ab]
+}
+
+fn get_antinodes2(a: Coords, b: Coords, grid: &Grid<char>) -> Vec<Coords> {
+    let verify = |c: Coords| grid.is_valid(c).then_some(c);
+    let ab = b - a;
+    let get_c = |i: i64| verify(a - ab * i);
+    let get_d = |i: i64| verify(b + ab * i);
+
+    let mut v = vec![];
+
+    let mut ci = 0;
+    while let Some(c) = get_c(ci) {
+        v.push(c);
+        ci += 1;
+    }
+    let mut di = 0;
+    while let Some(d) = get_d(di) {
+        v.push(d);
+        di += 1;
+    }
+    v
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Grid<char>, HashMap<char, Vec<Coords>>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let grid = input.grid_with(|c| (c == '.' || c.is_ascii_alphanumeric()).then_some(c))?;
+
+        let antennas = grid.iter().filter(|&(_, &t)| t != '.').fold(
+            HashMap::<char, Vec<_>>::new(),
+            |mut acc, (p, &t)| {
+                acc.entry(t).or_default().push(p);
+                acc
+            },
+        );
+        Ok((grid, antennas))
+    }
+
+    fn part1((grid, antennas): &Self::Input<'_>) -> impl Into<Answer> {
+        let antinodes = antennas
+            .keys()
+            .flat_map(|k| {
+                antennas
+                    .get(k)
+                    .unwrap()
+                    .iter()
+                    .tuple_combinations()
+                    .flat_map(|(a, b)| get_antinodes(*a, *b))
+                    .filter(|&c| grid.is_valid(c))
+                    .collect_vec()
+            })
+            .collect::<HashSet<_>>();
+        antinodes.len()
+    }
+
+    fn part2((grid, antennas): &Self::Input<'_>) -> impl Into<Answer> {
+        let antinodes2 = antennas
+            .keys()
+            .flat_map(|k| {
+                antennas
+                    .get(k)
+                    .unwrap()
+                    .iter()
+                    .tuple_combinations()
+                    .flat_map(|(a, b)| get_antinodes2(*a, *b, grid))
+                    .collect_vec()
+            })
+            .collect::<HashSet<_>>();
+        antinodes2.len()
+    }
+}
@@ -1,7 +1,9 @@
-use std::{fmt, io};
+use std::fmt;
+
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
 
 #[derive(Clone, Copy)]
-struct Block {
+pub struct Block {
     n: u32,
     id: usize,
 }
@@ -95,31 +97,34 @@ fn checksum(blocks: Vec<Block>) -> usize {
     r
 }
 
-fn main() {
-    let mut input = String::new();
-    let r = io::stdin().read_line(&mut input);
-    if r.is_err() {
-        panic!("Invalid input");
-    }
+pub struct Day;
 
-    let disk_map = input
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .enumerate()
-        .map(|(i, n)| {
-            if i % 2 == 0 {
-                Block { n, id: i / 2 }
-            } else {
-                Block { n, id: 0 }
-            }
-        })
-        .collect::<Vec<_>>();
+impl Solution for Day {
+    type Input<'a> = Vec<Block>;
+    type Params = ();
 
-    let compact_blocks = compact(&disk_map);
-    let r1 = checksum(compact_blocks);
-    println!("{}", r1);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let line = input.lines().next().unwrap_or_default();
+        line.char_indices()
+            .enumerate()
+            .map(|(i, (j, c))| {
+                let n = c
+                    .to_digit(10)
+                    .ok_or_else(|| ParseError::new(&line[j..], "expected a digit"))?;
+                if i % 2 == 0 {
+                    Ok(Block { n, id: i / 2 })
+                } else {
+                    Ok(Block { n, id: 0 })
+                }
+            })
+            .collect()
+    }
 
-    let compact_blocks2 = compact2(&disk_map);
-    let r2 = checksum(compact_blocks2);
-    println!("{}", r2);
+    fn part1(disk_map: &Self::Input<'_>) -> impl Into<Answer> {
+        checksum(compact(disk_map))
+    }
+
+    fn part2(disk_map: &Self::Input<'_>) -> impl Into<Answer> {
+        checksum(compact2(disk_map))
+    }
 }
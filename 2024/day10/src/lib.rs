@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Coords, Grid, InputExt, ParseResult, Solution};
+
+fn find_trailheads(grid: &Grid<u8>) -> Vec<Coords> {
+    grid.find_all(0).collect()
+}
+
+fn score_trailhead(grid: &Grid<u8>, trailhead: Coords) -> (usize, usize) {
+    let mut peaks = HashSet::new();
+    let mut rating = 0;
+    let mut todo = vec![(trailhead, 0)];
+    while let Some((p, height)) = todo.pop() {
+        if height == 9 {
+            peaks.insert(p);
+            rating += 1;
+            continue;
+        }
+        grid.neighbours4(p)
+            .filter(|&c| grid[c] == height + 1)
+            .for_each(|c| {
+                todo.push((c, height + 1));
+            });
+    }
+    (peaks.len(), rating)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(usize, usize)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let grid = input.grid_with(|c| c.to_digit(10).map(|d| d as u8))?;
+
+        Ok(find_trailheads(&grid)
+            .into_iter()
+            .map(|t| score_trailhead(&grid, t))
+            .collect::<Vec<_>>())
+    }
+
+    fn part1(trailheads: &Self::Input<'_>) -> impl Into<Answer> {
+        trailheads.iter().map(|t| t.0).sum::<usize>()
+    }
+
+    fn part2(trailheads: &Self::Input<'_>) -> impl Into<Answer> {
+        trailheads.iter().map(|t| t.1).sum::<usize>()
+    }
+}
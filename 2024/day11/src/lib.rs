@@ -1,7 +1,9 @@
-use std::{collections::HashMap, io};
+use std::collections::HashMap;
 
-#[derive(Debug)]
-struct Rock {
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Rock {
     value: u64,
     count: u64,
 }
@@ -11,7 +13,7 @@ fn blink_rock(rock: u64) -> Vec<u64> {
         return vec![1];
     }
     let s = rock.to_string();
-    if s.len() % 2 == 0 {
+    if s.len().is_multiple_of(2) {
         let r1 = s[0..s.len() / 2].parse::<u64>().unwrap();
         let r2 = s[s.len() / 2..].parse::<u64>().unwrap();
         return vec![r1, r2];
@@ -45,25 +47,37 @@ fn blink(rocks: Vec<Rock>) -> Vec<Rock> {
         .collect()
 }
 
-fn main() {
-    let input = io::stdin().lines().next().unwrap().unwrap();
-    let rocks = input
-        .split_whitespace()
-        .map(|s| Rock {
-            value: s.parse::<u64>().unwrap(),
-            count: 1,
-        })
-        .collect::<Vec<_>>();
-
-    let mut rocks = rocks;
-    for _ in 0..25 {
+fn blink_times(rocks: &[Rock], times: usize) -> u64 {
+    let mut rocks = rocks.to_vec();
+    for _ in 0..times {
         rocks = blink(rocks);
     }
-    let r1 = rocks.iter().map(|r| r.count).sum::<u64>();
-    println!("{}", r1);
-    for _ in 0..50 {
-        rocks = blink(rocks);
+    rocks.iter().map(|r| r.count).sum::<u64>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Rock>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .split_whitespace()
+            .map(|s| {
+                Ok(Rock {
+                    value: s.parse_as()?,
+                    count: 1,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(rocks: &Self::Input<'_>) -> impl Into<Answer> {
+        blink_times(rocks, 25)
+    }
+
+    fn part2(rocks: &Self::Input<'_>) -> impl Into<Answer> {
+        blink_times(rocks, 75)
     }
-    let r2 = rocks.iter().map(|r| r.count).sum::<u64>();
-    println!("{}", r2);
 }
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Coords, Grid, InputExt, ParseResult, Solution};
+use itertools::Itertools;
+
+fn trace_region(grid: &mut Grid<char>, p: Coords) -> Option<(u64, u64, u64)> {
+    let t0 = grid[p];
+    if t0 == '.' || t0.is_ascii_lowercase() {
+        return None;
+    }
+    let tt = t0.to_ascii_lowercase();
+    let mut area = 0;
+    let mut perimeter = 0;
+    let mut todo = vec![p];
+    let mut perimeter_edges = HashMap::new();
+    while let Some(p) = todo.pop() {
+        let t = grid[p];
+        if t == tt {
+            continue;
+        }
+        if t != t0 {
+            panic!("trace_region: t({}) != t0({}) {:?}", t, t0, p);
+        }
+        grid[p] = tt;
+        area += 1;
+
+        let edges = vec![(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter(|&(dx, dy)| match grid.get(p + Coords::new(dx, dy)) {
+                Some(&tp) => tp != t0 && tp != tt,
+                None => true,
+            })
+            .collect_vec();
+        perimeter += edges.len() as u64;
+        todo.extend(grid.neighbours4(p).filter(|&n| grid[n] == t0));
+        edges.into_iter().for_each(|e| {
+            perimeter_edges.entry(e).or_insert(vec![]).push((p.x, p.y));
+        });
+    }
+
+    fn merge_vertical_edges(mut acc: Vec<Vec<(i64, i64)>>, e: (i64, i64)) -> Vec<Vec<(i64, i64)>> {
+        if acc.is_empty() {
+            acc.push(vec![e]);
+        } else {
+            let e2 = acc.last().unwrap().last().unwrap();
+            if e2.0 == e.0 && e2.1 == e.1 - 1 {
+                acc.last_mut().unwrap().push(e);
+            } else {
+                acc.push(vec![e]);
+            }
+        }
+        acc
+    }
+    fn merge_horizontal_edges(
+        mut acc: Vec<Vec<(i64, i64)>>,
+        e: (i64, i64),
+    ) -> Vec<Vec<(i64, i64)>> {
+        if acc.is_empty() {
+            acc.push(vec![e]);
+        } else {
+            let e2 = acc.last().unwrap().last().unwrap();
+            if e2.1 == e.1 && e2.0 == e.0 - 1 {
+                acc.last_mut().unwrap().push(e);
+            } else {
+                acc.push(vec![e]);
+            }
+        }
+        acc
+    }
+
+    let mut left_edges = perimeter_edges.get(&(-1, 0)).unwrap().clone();
+    left_edges.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+    let left_edges = left_edges.into_iter().fold(vec![], merge_vertical_edges);
+
+    let mut right_edges = perimeter_edges.get(&(1, 0)).unwrap().clone();
+    right_edges.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+    let right_edges = right_edges.into_iter().fold(vec![], merge_vertical_edges);
+
+    let mut up_edges = perimeter_edges.get(&(0, -1)).unwrap().clone();
+    up_edges.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+    let up_edges = up_edges.into_iter().fold(vec![], merge_horizontal_edges);
+
+    let mut down_edges = perimeter_edges.get(&(0, 1)).unwrap().clone();
+    down_edges.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+    let down_edges = down_edges.into_iter().fold(vec![], merge_horizontal_edges);
+
+    let sides = (left_edges.len() + right_edges.len() + up_edges.len() + down_edges.len()) as u64;
+
+    Some((area, perimeter, sides))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(u64, u64, u64)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        // Plants are upper case, `trace_region` marks the visited ones.
+        let mut grid = input.grid_with(|c| c.is_ascii_uppercase().then_some(c))?;
+
+        Ok(grid
+            .coords()
+            .collect_vec()
+            .into_iter()
+            .filter_map(|p| trace_region(&mut grid, p))
+            .collect_vec())
+    }
+
+    fn part1(regions: &Self::Input<'_>) -> impl Into<Answer> {
+        regions.iter().map(|(a, p, _)| a * p).sum::<u64>()
+    }
+
+    fn part2(regions: &Self::Input<'_>) -> impl Into<Answer> {
+        regions.iter().map(|(a, _, s)| a * s).sum::<u64>()
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_common::{Answer, Coords, InputExt, ParseError, ParseExt, ParseResult, Rational, Solution};
+use itertools::Itertools;
+
+fn parse_coords(s: &str, prefix: &str) -> ParseResult<Coords> {
+    let rest = s.strip_prefix_or_err(prefix)?;
+    let [x, y] = rest.ints().collect::<ParseResult<Vec<_>>>()?[..] else {
+        return Err(ParseError::new(rest, "expected `X<x>, Y<y>`"));
+    };
+    Ok(Coords::new(x, y))
+}
+
+/// Presses of A and B reaching the prize, solving the 2x2 system
+/// `ac * a + bc * b = p` with Cramer's rule.
+fn solve(a: Coords, b: Coords, p: Coords) -> Option<(i64, i64)> {
+    let det = a.x * b.y - a.y * b.x;
+    if det == 0 {
+        return None;
+    }
+    let a_c = Rational::new(b.y * p.x - b.x * p.y, det).to_integer()?;
+    let b_c = Rational::new(a.x * p.y - a.y * p.x, det).to_integer()?;
+    (a_c >= 0 && b_c >= 0).then_some((a_c, b_c))
+}
+
+fn solve1(a: Coords, b: Coords, p: Coords) -> Option<i64> {
+    if let Some((ac, bc)) = solve(a, b, p) {
+        if ac <= 100 && bc <= 100 {
+            return Some(3 * ac + bc);
+        }
+    }
+    None
+}
+
+fn solve2(a: Coords, b: Coords, p: Coords) -> Option<i64> {
+    let p = p + Coords::new(10000000000000, 10000000000000);
+    if let Some((ac, bc)) = solve(a, b, p) {
+        Some(3 * ac + bc)
+    } else {
+        None
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(Coords, Coords, Coords)>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .paragraphs()
+            .map(|machine| {
+                let [a, b, p] = machine.lines().collect_vec()[..] else {
+                    return Err(ParseError::new(machine, "expected 3 lines per machine"));
+                };
+                Ok((
+                    parse_coords(a, "Button A: ")?,
+                    parse_coords(b, "Button B: ")?,
+                    parse_coords(p, "Prize: ")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        input
+            .iter()
+            .filter_map(|&(a, b, p)| solve1(a, b, p))
+            .sum::<i64>()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Into<Answer> {
+        input
+            .iter()
+            .filter_map(|&(a, b, p)| solve2(a, b, p))
+            .sum::<i64>()
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+
+use aoc_common::render::{self, Frame, Rgb};
+use aoc_common::{params, Answer, InputExt, ParseError, ParseResult, Solution};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Coords {
+    x: i64,
+    y: i64,
+}
+
+fn parse(s: &str) -> ParseResult<(Coords, Coords)> {
+    let [px, py, vx, vy] = s.ints().collect::<ParseResult<Vec<_>>>()?[..] else {
+        return Err(ParseError::new(s, "expected `p=<x>,<y> v=<x>,<y>`"));
+    };
+    Ok((Coords { x: px, y: py }, Coords { x: vx, y: vy }))
+}
+
+fn normalise(p: i64, s: i64) -> i64 {
+    let mut p = p;
+    while p < 0 {
+        p += s;
+    }
+    p % s
+}
+
+fn find_pos(rpos: Coords, rvel: Coords, size: Coords, t: i64) -> Coords {
+    Coords {
+        x: normalise(rpos.x + rvel.x * t, size.x),
+        y: normalise(rpos.y + rvel.y * t, size.y),
+    }
+}
+
+fn pick_quadrant(pos: Coords, size: Coords) -> Option<usize> {
+    let x1 = pos.x < size.x / 2;
+    let x2 = pos.x > size.x / 2;
+
+    let y1 = pos.y < size.y / 2;
+    let y2 = pos.y > size.y / 2;
+
+    match (x1, x2, y1, y2) {
+        (true, false, true, false) => Some(0),
+        (false, true, true, false) => Some(1),
+        (true, false, false, true) => Some(2),
+        (false, true, false, true) => Some(3),
+        _ => None,
+    }
+}
+
+const PALETTE: &[Rgb] = &[[16, 16, 32], [80, 200, 120]];
+
+fn draw(robots: &[(Coords, Coords)], size: Coords) -> Frame {
+    let robots = robots
+        .iter()
+        .map(|(p, _v)| (p.x as usize, p.y as usize))
+        .collect::<HashSet<_>>();
+    Frame::new(size.x as usize, size.y as usize, PALETTE, |x, y| {
+        robots.contains(&(x, y)) as u8
+    })
+}
+
+fn detect_tree(robots: &[(Coords, Coords)]) -> bool {
+    let threshold = 10;
+    robots
+        .iter()
+        .map(|(p, _v)| (p.x, p.y))
+        .sorted_by_key(|&(_x, y)| y)
+        .chunk_by(|&(_x, y)| y)
+        .into_iter()
+        .map(|(_y, g)| g.collect_vec())
+        .filter(|v| v.len() > threshold)
+        .any(|row| {
+            let consecutive = row
+                .iter()
+                .map(|&(x, _y)| x)
+                .sorted()
+                .fold(vec![], |mut acc, c| {
+                    if acc.is_empty() {
+                        acc.push(vec![c]);
+                    } else {
+                        let last = acc.last().unwrap().last().unwrap();
+                        if *last != c {
+                            if last + 1 == c {
+                                acc.last_mut().unwrap().push(c);
+                            } else {
+                                acc.push(vec![c]);
+                            }
+                        }
+                    }
+                    acc
+                });
+            consecutive.into_iter().any(|c| c.len() > threshold)
+        })
+}
+
+fn solve2(robots: &[(Coords, Coords)], size: Coords) -> Option<i64> {
+    let mut robots = Vec::from(robots);
+    for i in 1..(size.x * size.y) {
+        robots = robots
+            .into_iter()
+            .map(|(rpos, rvel)| (find_pos(rpos, rvel, size, 1), rvel))
+            .collect::<Vec<_>>();
+        render::record(|| draw(&robots, size));
+        if detect_tree(&robots) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+params! {
+    pub struct Params {
+        /// Size of the space the robots move in
+        pub width: i64 = 101,
+        pub height: i64 = 103,
+        /// Seconds after which the safety factor is computed
+        pub seconds: i64 = 100,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<(Coords, Coords)>, Params);
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> ParseResult<Self::Input<'a>> {
+        let robots = input.lines().map(parse).collect::<ParseResult<_>>()?;
+        Ok((robots, *params))
+    }
+
+    fn part1((robots, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let t = params.seconds;
+        let size = Coords {
+            x: params.width,
+            y: params.height,
+        };
+        robots
+            .iter()
+            .map(|&(rpos, rvel)| find_pos(rpos, rvel, size, t))
+            .filter_map(|rpos| pick_quadrant(rpos, size))
+            .fold(vec![0, 0, 0, 0], |mut acc, c| {
+                acc[c] += 1;
+                acc
+            })
+            .into_iter()
+            .product::<u64>()
+    }
+
+    fn part2((robots, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let size = Coords {
+            x: params.width,
+            y: params.height,
+        };
+        solve2(robots, size).unwrap()
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_common::render::{self, Frame, Rgb};
+use aoc_common::{Answer, Coords, Direction, Grid, InputExt, ParseError, ParseResult, Solution};
+use itertools::Itertools;
+
+fn parse_direction(s: &str) -> ParseResult<Direction> {
+    match s {
+        "<" => Ok(Direction::Left),
+        ">" => Ok(Direction::Right),
+        "^" => Ok(Direction::Up),
+        "v" => Ok(Direction::Down),
+        _ => Err(ParseError::new(s, "expected `<`, `>`, `^` or `v`")),
+    }
+}
+
+fn gps_coords(c: Coords) -> i64 {
+    100 * c.y + c.x
+}
+
+fn move_tile(grid: &mut Grid<char>, p: Coords, d: Coords) {
+    let t = grid[p];
+    assert_eq!(grid[d], '.');
+    grid[d] = t;
+    grid[p] = '.';
+}
+
+fn push(grid: &mut Grid<char>, p: Coords, d: Direction) -> Option<Coords> {
+    let dest = p + d.to_coords();
+    let dest_tile = grid[dest];
+    match dest_tile {
+        '#' => None,
+        '.' => {
+            move_tile(grid, p, dest);
+            Some(dest)
+        }
+        'O' | '[' | ']' => match push(grid, dest, d) {
+            Some(_) => push(grid, p, d),
+            None => None,
+        },
+        _ => panic!("Unexpected tile"),
+    }
+}
+
+fn push_box(
+    grid: &mut Grid<char>,
+    p: Coords,
+    p2: Coords,
+    d: Direction,
+    dry: bool,
+) -> Option<Coords> {
+    let left = push2(grid, p, d, dry);
+    let right = push2(grid, p2, d, dry);
+
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            if !dry {
+                move_tile(grid, p, left);
+                move_tile(grid, p2, right);
+            }
+            Some(p)
+        }
+        _ => None,
+    }
+}
+
+fn push2(grid: &mut Grid<char>, p: Coords, d: Direction, dry: bool) -> Option<Coords> {
+    if d == Direction::Left || d == Direction::Right {
+        return push(grid, p, d);
+    }
+    let dest = p + d.to_coords();
+    let dest_tile = grid[dest];
+    match dest_tile {
+        '#' => None,
+        '.' => Some(dest),
+        '[' => push_box(grid, dest, dest + Coords::RIGHT, d, dry),
+        ']' => push_box(grid, dest, dest + Coords::LEFT, d, dry),
+        _ => panic!("Unexpected tile"),
+    }
+}
+
+fn transform_to_wide_grid(grid: &Grid<char>) -> Grid<char> {
+    let tiles = grid
+        .tiles
+        .iter()
+        .flat_map(|&t| match t {
+            '#' | '.' => [t, t],
+            '@' => ['@', '.'],
+            'O' => ['[', ']'],
+            _ => panic!("Unexpected tile"),
+        })
+        .collect_vec();
+    Grid {
+        width: grid.width * 2,
+        height: grid.height,
+        tiles,
+    }
+}
+
+const PALETTE: &[Rgb] = &[
+    [16, 16, 32],
+    [120, 120, 120],
+    [190, 140, 70],
+    [80, 200, 120],
+];
+
+fn draw(grid: &Grid<char>, robot_pos: Coords) -> Frame {
+    Frame::from_grid(grid, PALETTE, |p, &t| match t {
+        _ if p == robot_pos => 3,
+        '#' => 1,
+        'O' | '[' | ']' => 2,
+        _ => 0,
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Grid<char>, Vec<Direction>);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let [grid_lines, moves_lines] = input.split_blocks()?;
+
+        let grid = grid_lines.grid_with(|c| "#.O@".contains(c).then_some(c))?;
+        if grid.find('@').is_none() {
+            return Err(ParseError::new(grid_lines, "expected a robot `@`"));
+        }
+        let moves = moves_lines
+            .lines()
+            .flat_map(|l| l.char_indices().map(|(i, c)| &l[i..i + c.len_utf8()]))
+            .map(parse_direction)
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok((grid, moves))
+    }
+
+    fn part1((grid, moves): &Self::Input<'_>) -> impl Into<Answer> {
+        let mut grid = grid.clone();
+        let mut robot_pos = grid.find('@').unwrap();
+        grid[robot_pos] = '.';
+
+        for m in moves.iter() {
+            if let Some(new_pos) = push(&mut grid, robot_pos, *m) {
+                robot_pos = new_pos;
+            }
+            render::record(|| draw(&grid, robot_pos));
+        }
+
+        grid.find_all('O').map(gps_coords).sum::<i64>()
+    }
+
+    fn part2((grid, moves): &Self::Input<'_>) -> impl Into<Answer> {
+        let mut grid = transform_to_wide_grid(grid);
+        let mut robot_pos = grid.find('@').unwrap();
+        grid[robot_pos] = '.';
+
+        for &m in moves {
+            if let Some(new_pos) = push2(&mut grid, robot_pos, m, true) {
+                push2(&mut grid, robot_pos, m, false);
+                robot_pos = new_pos;
+            }
+            render::record(|| draw(&grid, robot_pos));
+        }
+
+        grid.find_all('[').map(gps_coords).sum::<i64>()
+    }
+}
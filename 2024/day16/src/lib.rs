@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use aoc_common::search::shortest_paths;
+use aoc_common::{Answer, Direction, Grid, InputExt, ParseError, ParseResult, Solution};
+
+/// Finds every cheapest path from `S` to `E`, returning the lowest score
+/// and the number of tiles that lie on at least one of those paths.
+fn search(grid: &Grid<char>) -> (i64, usize) {
+    let start = grid.find('S').unwrap();
+    let end = grid.find('E').unwrap();
+
+    let paths = shortest_paths([(start, Direction::Right)], |&(pos, dir)| {
+        let ahead = pos + dir.to_coords();
+        [
+            grid.get(ahead)
+                .is_some_and(|&t| t != '#')
+                .then_some(((ahead, dir), 1)),
+            Some(((pos, dir.left()), 1000)),
+            Some(((pos, dir.right()), 1000)),
+        ]
+        .into_iter()
+        .flatten()
+    });
+    let best_score = Direction::ALL
+        .into_iter()
+        .filter_map(|dir| paths.costs.get(&(end, dir)).copied())
+        .min()
+        .unwrap();
+    let best_ends = Direction::ALL
+        .into_iter()
+        .map(|dir| (end, dir))
+        .filter(|e| paths.costs.get(e) == Some(&best_score));
+    let best_path_tiles = paths
+        .states_on_paths(best_ends)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>();
+
+    (best_score, best_path_tiles.len())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (i64, usize);
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let grid = input.grid_with(|c| "#.SE".contains(c).then_some(c))?;
+        if grid.find('S').is_none() || grid.find('E').is_none() {
+            return Err(ParseError::new(
+                input,
+                "expected a start `S` and an end `E`",
+            ));
+        }
+        Ok(search(&grid))
+    }
+
+    fn part1((best_score, _): &Self::Input<'_>) -> impl Into<Answer> {
+        *best_score
+    }
+
+    fn part2((_, best_path_tiles): &Self::Input<'_>) -> impl Into<Answer> {
+        *best_path_tiles
+    }
+}
@@ -1,11 +1,14 @@
-use std::{collections::VecDeque, io};
+use std::collections::VecDeque;
 
-fn parse_register(s: &str) -> u64 {
-    s.split_whitespace().last().unwrap().parse().unwrap()
+use aoc_common::{Answer, InputExt, ParseError, ParseExt, ParseResult, Solution};
+
+fn parse_register(s: &str, name: char) -> ParseResult<u64> {
+    s.strip_prefix_or_err(&format!("Register {name}: "))?
+        .parse_as()
 }
 
 #[derive(Clone, Debug)]
-struct State {
+pub struct State {
     register_a: u64,
     register_b: u64,
     register_c: u64,
@@ -179,30 +182,50 @@ fn solve2(program: &[u64]) -> Option<u64> {
     None
 }
 
-fn main() {
-    let lines = io::stdin().lines().map(|l| l.unwrap()).collect::<Vec<_>>();
-    let register_a = parse_register(lines[0].as_str());
-    let register_b = parse_register(lines[1].as_str());
-    let register_c = parse_register(lines[2].as_str());
-    let program0 = lines[4].split_whitespace().last().unwrap();
-    let program = program0
-        .split(',')
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (State, Vec<u64>);
+    type Params = ();
 
-    let s0 = State::new()
-        .with_register_a(register_a)
-        .with_register_b(register_b)
-        .with_register_c(register_c);
-    let s = solve(s0, &program);
-    let r1 = s.output;
-    println!("{}", r1);
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let [registers, program] = input.split_blocks()?;
+        let lines = registers.lines().collect::<Vec<_>>();
+        let [a, b, c] = lines[..] else {
+            return Err(ParseError::new(registers, "expected 3 registers"));
+        };
+        let register_a = parse_register(a, 'A')?;
+        let register_b = parse_register(b, 'B')?;
+        let register_c = parse_register(c, 'C')?;
+        let program = program
+            .trim_end()
+            .strip_prefix_or_err("Program: ")?
+            .split(',')
+            .map(|s| match s.parse_as()? {
+                n @ 0..=7 => Ok(n),
+                _ => Err(ParseError::new(s, "expected a 3-bit number")),
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
 
-    let r2 = solve2(&program).unwrap();
-    println!("{}", r2);
-    // verify
-    let s2 = solve(State::new().with_register_a(r2), &program);
-    assert_eq!(s2.output, program0);
+        let s0 = State::new()
+            .with_register_a(register_a)
+            .with_register_b(register_b)
+            .with_register_c(register_c);
+        Ok((s0, program))
+    }
+
+    fn part1((s0, program): &Self::Input<'_>) -> impl Into<Answer> {
+        solve(s0.clone(), program).output
+    }
+
+    fn part2((_, program): &Self::Input<'_>) -> impl Into<Answer> {
+        let r2 = solve2(program).unwrap();
+        // verify
+        let s2 = solve(State::new().with_register_a(r2), program);
+        let expected = program.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        assert_eq!(s2.output, expected.join(","));
+        r2
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,74 @@
+use aoc_common::search::bfs;
+use aoc_common::{params, Answer, Coords, Grid, ParseError, ParseExt, ParseResult, Solution};
+use itertools::Itertools;
+
+fn parse(s: &str, memory_dimension: i64) -> ParseResult<Coords> {
+    let (x, y) = s.split_once_or_err(",")?;
+    let p = Coords::new(x.parse_as()?, y.parse_as()?);
+    if !(0..memory_dimension).contains(&p.x) || !(0..memory_dimension).contains(&p.y) {
+        return Err(ParseError::new(
+            s,
+            "expected a position within the memory space",
+        ));
+    }
+    Ok(p)
+}
+
+fn solve(memory: &Grid<char>) -> Option<usize> {
+    let start = Coords::ZERO;
+    let end = Coords::new(memory.width - 1, memory.height - 1);
+    if memory[start] != '.' {
+        return None;
+    }
+    let next = |&p: &Coords| memory.neighbours4(p).filter(|&n| memory[n] == '.');
+    bfs([start], next, |&p| p == end).map(|(_, steps)| steps)
+}
+
+params! {
+    pub struct Params {
+        /// Width and height of the memory space
+        pub memory_dimension: i64 = 71,
+        /// Number of bytes fallen before the first part looks for the exit
+        pub bytes_count: usize = 1024,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<Coords>, Params);
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> ParseResult<Self::Input<'a>> {
+        let bytes = input
+            .lines()
+            .map(|l| parse(l, params.memory_dimension))
+            .collect::<ParseResult<_>>()?;
+        Ok((bytes, *params))
+    }
+
+    fn part1((bytes, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let dimension = params.memory_dimension;
+        let mut memory = Grid::new(dimension, dimension, '.');
+        bytes
+            .iter()
+            .take(params.bytes_count)
+            .for_each(|&p| memory[p] = '#');
+
+        solve(&memory).unwrap()
+    }
+
+    fn part2((bytes, params): &Self::Input<'_>) -> impl Into<Answer> {
+        let dimension = params.memory_dimension;
+        let r2_i = (0..bytes.len()).collect_vec().partition_point(|&i| {
+            let mut memory = Grid::new(dimension, dimension, '.');
+            bytes.iter().take(i).for_each(|&p| memory[p] = '#');
+            solve(&memory).is_some()
+        });
+        bytes[r2_i - 1]
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_common::memo::Memo;
+use aoc_common::parallel::prelude::*;
+use aoc_common::{Answer, InputExt, ParseResult, Solution};
+
+fn solve(towels: &[&str], design: &str) -> usize {
+    let count = |count: &mut dyn FnMut(&str) -> usize, design: &str| {
+        if design.is_empty() {
+            return 1;
+        }
+        towels
+            .iter()
+            .filter(|&&t| design.starts_with(t))
+            .map(|&t| count(&design[t.len()..]))
+            .sum()
+    };
+    Memo::<String, usize>::new().call(design, &count)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<usize>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let [towels, designs] = input.split_blocks()?;
+
+        let towels = towels.split(",").map(|x| x.trim()).collect::<Vec<_>>();
+
+        let designs = designs.lines().collect::<Vec<_>>();
+        Ok(designs
+            .maybe_par_iter()
+            .map(|d| solve(&towels, d))
+            .collect::<Vec<_>>())
+    }
+
+    fn part1(solutions: &Self::Input<'_>) -> impl Into<Answer> {
+        solutions.iter().filter(|&&x| x > 0).count()
+    }
+
+    fn part2(solutions: &Self::Input<'_>) -> impl Into<Answer> {
+        solutions.iter().sum::<usize>()
+    }
+}
@@ -0,0 +1,89 @@
+use aoc_common::search::bfs_distances;
+use aoc_common::{Answer, Coords, Grid, InputExt, ParseError, ParseResult, Solution};
+use itertools::{iproduct, Itertools};
+
+fn evaluate_track(grid: &Grid<char>, start: Coords) -> Grid<Option<usize>> {
+    let distances = bfs_distances([start], |&p| {
+        grid.neighbours4(p).filter(|&n| grid[n] != '#')
+    });
+    Grid::from_fn(grid.width, grid.height, |p| distances.get(&p).copied())
+}
+
+fn find_cheats(track: &Grid<Option<usize>>, max_len: usize) -> Vec<usize> {
+    fn combos(track: &Grid<Option<usize>>, c: Coords, max_len: usize) -> Vec<(Coords, Coords)> {
+        let max_len = max_len as i64;
+        iproduct!(-max_len..=max_len, -max_len..=max_len)
+            .map(|(x, y)| Coords::new(x, y))
+            .filter(|d| (2..=max_len).contains(&d.manhattan()))
+            .filter_map(|d| {
+                let c2 = c + d;
+                if track.is_valid(c2) {
+                    Some((c, c2))
+                } else {
+                    None
+                }
+            })
+            .map(|(c, c2)| {
+                if c.y < c2.y {
+                    return (c, c2);
+                }
+                if c.y == c2.y && c.x <= c2.x {
+                    return (c, c2);
+                }
+                (c2, c)
+            })
+            .collect_vec()
+    }
+
+    iproduct!(1..track.height - 1, 1..track.width - 1)
+        .map(|(y, x)| Coords { x, y })
+        .filter(|&c| track[c].is_some())
+        .flat_map(|c| combos(track, c, max_len))
+        .unique()
+        .filter_map(|(c, c2)| {
+            if let Some(p1) = track[c] {
+                if let Some(p2) = track[c2] {
+                    let len = c.manhattan_distance(c2);
+                    let s = p1.abs_diff(p2) - len as usize;
+                    if s > 0 {
+                        return Some(s);
+                    }
+                }
+            }
+            None
+        })
+        .collect_vec()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid<Option<usize>>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let grid = input.grid_with(|c| "#.SE".contains(c).then_some(c))?;
+        let (Some(start), Some(_)) = (grid.find('S'), grid.find('E')) else {
+            return Err(ParseError::new(
+                input,
+                "expected a start `S` and an end `E`",
+            ));
+        };
+
+        Ok(evaluate_track(&grid, start))
+    }
+
+    fn part1(track: &Self::Input<'_>) -> impl Into<Answer> {
+        find_cheats(track, 2)
+            .into_iter()
+            .filter(|&c| c >= 100)
+            .count()
+    }
+
+    fn part2(track: &Self::Input<'_>) -> impl Into<Answer> {
+        find_cheats(track, 20)
+            .into_iter()
+            .filter(|&c| c >= 100)
+            .count()
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use aoc_common::memo::Memo;
+use aoc_common::{Answer, Coords, ParseExt, ParseResult, Solution};
+
+type Keypad = HashMap<char, Coords>;
+
+fn make_numeric_keypad() -> Keypad {
+    let mut keypad = Keypad::new();
+    keypad.insert('7', Coords { x: 0, y: 0 });
+    keypad.insert('8', Coords { x: 1, y: 0 });
+    keypad.insert('9', Coords { x: 2, y: 0 });
+
+    keypad.insert('4', Coords { x: 0, y: 1 });
+    keypad.insert('5', Coords { x: 1, y: 1 });
+    keypad.insert('6', Coords { x: 2, y: 1 });
+
+    keypad.insert('1', Coords { x: 0, y: 2 });
+    keypad.insert('2', Coords { x: 1, y: 2 });
+    keypad.insert('3', Coords { x: 2, y: 2 });
+
+    keypad.insert('X', Coords { x: 0, y: 3 });
+    keypad.insert('0', Coords { x: 1, y: 3 });
+    keypad.insert('A', Coords { x: 2, y: 3 });
+    keypad
+}
+
+fn make_directional_keypad() -> Keypad {
+    let mut keypad = Keypad::new();
+    keypad.insert('X', Coords { x: 0, y: 0 });
+    keypad.insert('^', Coords { x: 1, y: 0 });
+    keypad.insert('A', Coords { x: 2, y: 0 });
+
+    keypad.insert('<', Coords { x: 0, y: 1 });
+    keypad.insert('v', Coords { x: 1, y: 1 });
+    keypad.insert('>', Coords { x: 2, y: 1 });
+    keypad
+}
+
+fn solve(
+    keypads: &[&Keypad],
+    password: &str,
+    memo: &mut Memo<(Coords, Coords, usize), usize>,
+) -> usize {
+    fn find_paths(keypad: &Keypad, from: Coords, to: Coords) -> Vec<String> {
+        let pos_x = *keypad.get(&'X').unwrap();
+        let mut r = vec![];
+        let mut todo = vec![(from, "".to_string())];
+        while let Some((p, path)) = todo.pop() {
+            if p == to {
+                r.push(path + "A");
+                continue;
+            }
+            let d = (to - p).signum();
+            if d.x != 0 {
+                let p2 = p + Coords::new(d.x, 0);
+                if p2 != pos_x {
+                    todo.push((p2, path.clone() + if d.x < 0 { "<" } else { ">" }));
+                }
+            }
+            if d.y != 0 {
+                let p2 = p + Coords::new(0, d.y);
+                if p2 != pos_x {
+                    todo.push((p2, path.clone() + if d.y < 0 { "^" } else { "v" }));
+                }
+            }
+        }
+        r
+    }
+
+    if keypads.is_empty() || password.is_empty() {
+        return password.len();
+    }
+    let keypad = keypads[0];
+    let mut p = keypad.get(&'A').unwrap();
+    let mut sequence_len = 0;
+    for b in password.chars() {
+        let p2 = keypad.get(&b).unwrap();
+        sequence_len += memo.get_or_insert_with(&(*p, *p2, keypads.len()), |memo| {
+            find_paths(keypad, *p, *p2)
+                .into_iter()
+                .map(|p| solve(&keypads[1..], &p, memo))
+                .min()
+                .unwrap()
+        });
+        p = p2;
+    }
+    sequence_len
+}
+
+fn complexity(passwords: &[&str], keypads: &[&Keypad]) -> usize {
+    passwords
+        .iter()
+        .map(|password| (password, solve(keypads, password, &mut Memo::new())))
+        .map(|(password, sequence_len)| {
+            let numeric = password[0..password.len() - 1].parse::<usize>().unwrap();
+            numeric * sequence_len
+        })
+        .sum::<usize>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                // Codes are digits followed by `A`, see `complexity`.
+                l.strip_suffix_or_err("A")?.parse_as::<usize>()?;
+                Ok(l)
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        let numeric_keypad = make_numeric_keypad();
+        let directional_keypad = make_directional_keypad();
+        complexity(
+            lines,
+            &[&numeric_keypad, &directional_keypad, &directional_keypad],
+        )
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        let numeric_keypad = make_numeric_keypad();
+        let directional_keypad = make_directional_keypad();
+        let mut v1 = vec![&numeric_keypad];
+        let mut v2 = vec![&directional_keypad; 25];
+        v1.append(&mut v2);
+        complexity(lines, &v1)
+    }
+}
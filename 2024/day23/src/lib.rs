@@ -1,7 +1,6 @@
-use std::{
-    collections::{BTreeSet, HashMap, HashSet},
-    io,
-};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use aoc_common::{Answer, ParseExt, ParseResult, Solution};
 
 fn find_groups<'a>(
     connections: &'a HashMap<&'a str, HashSet<&'a str>>,
@@ -61,24 +60,33 @@ fn find_largest_group<'a>(connections: &'a HashMap<&'a str, HashSet<&'a str>>) -
     largest_group
 }
 
-fn main() {
-    let lines = io::stdin().lines().map(|l| l.unwrap()).collect::<Vec<_>>();
-    let connections = lines
-        .iter()
-        .map(|l| l.split('-').collect::<Vec<_>>())
-        .map(|v| (v[0], v[1]))
-        .fold(HashMap::<&str, HashSet<&str>>::new(), |mut acc, (a, b)| {
-            acc.entry(a).or_default().insert(b);
-            acc.entry(b).or_default().insert(a);
-            acc
-        });
-    let groups = find_groups(&connections);
-    let r1 = groups
-        .iter()
-        .filter(|(a, b, c)| a.starts_with('t') || b.starts_with('t') || c.starts_with('t'))
-        .count();
-    println!("{}", r1);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = HashMap<&'a str, HashSet<&'a str>>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        input.lines().map(|l| l.split_once_or_err("-")).try_fold(
+            HashMap::<&str, HashSet<&str>>::new(),
+            |mut acc, connection| {
+                let (a, b) = connection?;
+                acc.entry(a).or_default().insert(b);
+                acc.entry(b).or_default().insert(a);
+                Ok(acc)
+            },
+        )
+    }
+
+    fn part1(connections: &Self::Input<'_>) -> impl Into<Answer> {
+        let groups = find_groups(connections);
+        groups
+            .iter()
+            .filter(|(a, b, c)| a.starts_with('t') || b.starts_with('t') || c.starts_with('t'))
+            .count()
+    }
 
-    let r2 = find_largest_group(&connections).join(",");
-    println!("{}", r2);
+    fn part2(connections: &Self::Input<'_>) -> impl Into<Answer> {
+        find_largest_group(connections).join(",")
+    }
 }
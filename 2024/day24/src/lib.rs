@@ -1,22 +1,24 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    io,
-};
-
-fn parse_gate(s: &str) -> (String, u8) {
-    let (name, value) = s.split_once(": ").unwrap();
-    (name.to_string(), value.parse().unwrap())
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{Answer, InputExt, ParseError, ParseExt, ParseResult, Solution};
+
+fn parse_gate(s: &str) -> ParseResult<(String, u8)> {
+    let (name, value) = s.split_once_or_err(": ")?;
+    match value {
+        "0" | "1" => Ok((name.to_string(), value.parse_as()?)),
+        _ => Err(ParseError::new(value, "expected `0` or `1`")),
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum ConnectionType {
+pub enum ConnectionType {
     And,
     Xor,
     Or,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct Connection {
+pub struct Connection {
     a: String,
     b: String,
     op: ConnectionType,
@@ -62,19 +64,23 @@ fn simulate(gates: &HashMap<String, u8>, c: Connection) -> Option<u8> {
     None
 }
 
-fn parse_connection(s: &str) -> (Connection, String) {
-    let t = s.split_whitespace().collect::<Vec<_>>();
-    let g1 = t[0].min(t[2]).to_string();
-    let g2 = t[0].max(t[2]).to_string();
-    let r = t[4];
-    let op = t[1];
+fn parse_connection(s: &str) -> ParseResult<(Connection, String)> {
+    let (inputs, r) = s.split_once_or_err(" -> ")?;
+    let [a, op, b] = inputs.split(' ').collect::<Vec<_>>()[..] else {
+        return Err(ParseError::new(
+            inputs,
+            "expected `<gate> <operator> <gate>`",
+        ));
+    };
+    let g1 = a.min(b).to_string();
+    let g2 = a.max(b).to_string();
     let c = match op {
         "AND" => Connection::and(g1, g2),
         "XOR" => Connection::xor(g1, g2),
         "OR" => Connection::or(g1, g2),
-        _ => panic!("Unknown operator: {}", op),
+        _ => return Err(ParseError::new(op, "expected `AND`, `XOR` or `OR`")),
     };
-    (c, r.to_string())
+    Ok((c, r.to_string()))
 }
 
 fn evaluate(
@@ -235,57 +241,61 @@ fn check_full_adder(
     Ok((ab_s.1.clone(), c_out.1.clone()))
 }
 
-fn main() {
-    let lines = io::stdin().lines().map(|l| l.unwrap()).collect::<Vec<_>>();
-    let idx = lines
-        .iter()
-        .enumerate()
-        .find(|(_, l)| l.is_empty())
-        .map(|(i, _)| i)
-        .unwrap();
-    let gates = &lines[0..idx]
-        .iter()
-        .map(|l| parse_gate(l))
-        .collect::<HashMap<_, _>>();
-    let connections = &lines[idx + 1..]
-        .iter()
-        .map(|l| parse_connection(l))
-        .collect::<Vec<_>>();
+pub struct Day;
 
-    let gates1 = evaluate(gates, connections);
-    let r1 = decode_gates(&gates1, 'z');
-    println!("{}", r1);
+impl Solution for Day {
+    type Input<'a> = (HashMap<String, u8>, Vec<(Connection, String)>);
+    type Params = ();
 
-    let x = decode_gates(gates, 'x');
-    let y = decode_gates(gates, 'y');
-    let expected = x + y;
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let [gates, connections] = input.split_blocks()?;
+        let gates = gates
+            .lines()
+            .map(parse_gate)
+            .collect::<ParseResult<HashMap<_, _>>>()?;
+        let connections = connections
+            .lines()
+            .map(parse_connection)
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok((gates, connections))
+    }
 
-    let len = gates.iter().filter(|(n, _v)| n.starts_with('x')).count();
+    fn part1((gates, connections): &Self::Input<'_>) -> impl Into<Answer> {
+        let gates1 = evaluate(gates, connections);
+        decode_gates(&gates1, 'z')
+    }
 
-    let (_s00, c00) = check_half_adder("x00", "y00", connections);
-    let mut carry = c00;
-    let mut to_swap = Vec::new();
-    let mut connections42 = connections.clone();
-    for i in 1..len - 1 {
-        match check_full_adder(carry.clone(), i, connections) {
-            Ok((_s, c)) => {
-                carry = c;
-            }
-            Err((g1, g2)) => {
-                let connections2 = swap_gate_outputs(connections, &g1, &g2);
-                connections42 = swap_gate_outputs(&connections42, &g1, &g2);
-                to_swap.push(g1);
-                to_swap.push(g2);
-                let (_s, c) = check_full_adder(carry.clone(), i, &connections2).unwrap();
-                carry = c;
+    fn part2((gates, connections): &Self::Input<'_>) -> impl Into<Answer> {
+        let x = decode_gates(gates, 'x');
+        let y = decode_gates(gates, 'y');
+        let expected = x + y;
+
+        let len = gates.iter().filter(|(n, _v)| n.starts_with('x')).count();
+
+        let (_s00, c00) = check_half_adder("x00", "y00", connections);
+        let mut carry = c00;
+        let mut to_swap = Vec::new();
+        let mut connections42 = connections.clone();
+        for i in 1..len - 1 {
+            match check_full_adder(carry.clone(), i, connections) {
+                Ok((_s, c)) => {
+                    carry = c;
+                }
+                Err((g1, g2)) => {
+                    let connections2 = swap_gate_outputs(connections, &g1, &g2);
+                    connections42 = swap_gate_outputs(&connections42, &g1, &g2);
+                    to_swap.push(g1);
+                    to_swap.push(g2);
+                    let (_s, c) = check_full_adder(carry.clone(), i, &connections2).unwrap();
+                    carry = c;
+                }
             }
         }
-    }
-    let gates = evaluate(gates, &connections42);
-    let fixed = decode_gates(&gates, 'z');
-    assert_eq!(fixed.abs_diff(expected), 0);
+        let gates = evaluate(gates, &connections42);
+        let fixed = decode_gates(&gates, 'z');
+        assert_eq!(fixed.abs_diff(expected), 0);
 
-    to_swap.sort();
-    let r2 = to_swap.join(",");
-    println!("{}", r2);
+        to_swap.sort();
+        to_swap.join(",")
+    }
 }
@@ -0,0 +1,54 @@
+use aoc_common::{Answer, Grid, InputExt, ParseError, ParseResult, Solution};
+use itertools::Itertools;
+
+fn is_lock(g: &Grid<char>) -> bool {
+    g.row(0).iter().all(|&t| t == '#')
+}
+
+fn to_heights(g: &Grid<char>) -> Vec<usize> {
+    g.columns()
+        .map(|c| c.filter(|&&t| t == '#').count() - 1)
+        .collect()
+}
+
+fn overlaps(lock: &[usize], key: &[usize], max_height: usize) -> bool {
+    lock.iter()
+        .zip(key.iter())
+        .any(|(l, k)| *l + *k > max_height)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<Grid<char>>, Vec<Grid<char>>);
+    type Params = ();
+
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        let schematics = input
+            .paragraphs()
+            .map(|s| {
+                let g = s.grid_with(|c| ".#".contains(c).then_some(c))?;
+                let is_key = |g: &Grid<char>| g.row(g.height - 1).iter().all(|&t| t == '#');
+                if g.height == 0 || !is_lock(&g) && !is_key(&g) {
+                    return Err(ParseError::new(s, "expected a lock or a key"));
+                }
+                Ok(g)
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok(schematics.into_iter().partition(is_lock))
+    }
+
+    fn part1((locks, keys): &Self::Input<'_>) -> impl Into<Answer> {
+        let max_height = locks[0].height as usize - 2;
+        let locks_heights = locks.iter().map(to_heights).collect_vec();
+        let keys_heights = keys.iter().map(to_heights).collect_vec();
+        locks_heights
+            .iter()
+            .cartesian_product(keys_heights.iter())
+            .map(|(l, k)| overlaps(l, k, max_height))
+            .filter(|overlaps| !overlaps)
+            .count()
+    }
+}
@@ -0,0 +1,78 @@
+//! Generates one test per puzzle example, see `src/examples.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The `examples/*.txt` files of every `<year>/dayNN` directory, with their year and day.
+fn examples(root: &Path) -> Vec<(u16, u8, PathBuf)> {
+    let mut found = vec![];
+    for year in fs::read_dir(root).unwrap().flatten() {
+        let Some(y) = year
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        println!("cargo:rerun-if-changed={}", year.path().display());
+        for day in fs::read_dir(year.path()).unwrap().flatten() {
+            let name = day.file_name();
+            let Some(d) = name
+                .to_str()
+                .and_then(|s| s.strip_prefix("day")?.parse::<u8>().ok())
+            else {
+                continue;
+            };
+            let Ok(files) = fs::read_dir(day.path().join("examples")) else {
+                continue;
+            };
+            for file in files.flatten() {
+                let path = file.path();
+                // Empty examples are the placeholders `aoc new` leaves.
+                let empty = file.metadata().is_ok_and(|m| m.len() == 0);
+                if path.extension().is_some_and(|e| e == "txt") && !empty {
+                    found.push((y, d, path));
+                }
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = manifest_dir.parent().unwrap();
+
+    let mut out = String::new();
+    for (year, day, input) in examples(root) {
+        let expected = input.with_extension("expected");
+        assert!(
+            expected.exists(),
+            "{} has no {}",
+            input.display(),
+            expected.display()
+        );
+        let params = input.with_extension("params");
+        let params = if params.exists() {
+            format!("include_str!({params:?})")
+        } else {
+            "\"\"".to_string()
+        };
+        let name = input.file_stem().unwrap().to_str().unwrap();
+        let ident = name.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+        writeln!(
+            out,
+            "#[test]\n\
+             fn y{year}_day{day:02}_{ident}() {{\n    \
+                 check({year}, {day}, include_str!({input:?}), include_str!({expected:?}), {params});\n\
+             }}\n"
+        )
+        .unwrap();
+    }
+
+    let dest = PathBuf::from(env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(dest, out).unwrap();
+}
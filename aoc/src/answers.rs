@@ -0,0 +1,83 @@
+//! Known-correct answers, stored in `answers.txt` next to each day's input.
+//!
+//! Every part starts with a `<part>: <answer>` line. Answers spanning several
+//! lines, such as letters drawn on a screen, leave the header line empty and
+//! continue on the following lines:
+//!
+//! ```text
+//! 1: 14540
+//! 2:
+//! ####.#..#.
+//! #....#..#.
+//! ```
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        let mut multi_line = None;
+        for (i, line) in s.lines().enumerate() {
+            let header = line
+                .split_once(':')
+                .and_then(|(p, rest)| Some((p.parse::<u8>().ok()?, rest)))
+                .filter(|(p, _)| (1..=2).contains(p));
+            match (header, &mut multi_line) {
+                (Some((part, rest)), _) => {
+                    let slot = &mut answers.parts[part as usize - 1];
+                    if slot.is_some() {
+                        return Err(format!("line {}: part {part} given twice", i + 1));
+                    }
+                    let rest = rest.trim();
+                    if rest.is_empty() {
+                        *slot = Some(String::new());
+                        multi_line = Some(part);
+                    } else {
+                        *slot = Some(rest.to_string());
+                        multi_line = None;
+                    }
+                }
+                (None, Some(part)) => {
+                    let answer = answers.parts[*part as usize - 1].as_mut().unwrap();
+                    if !answer.is_empty() {
+                        answer.push('\n');
+                    }
+                    answer.push_str(line);
+                }
+                (None, None) if line.trim().is_empty() => {}
+                (None, None) => {
+                    return Err(format!("line {}: expected `<part>: <answer>`", i + 1));
+                }
+            }
+        }
+        for answer in answers.parts.iter_mut().flatten() {
+            answer.truncate(answer.trim_end().len());
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts[part as usize - 1].as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let a = Answers::parse("1: 14540\n2:\n#..#\n.##.\n\n").unwrap();
+        assert_eq!(a.get(1), Some("14540"));
+        assert_eq!(a.get(2), Some("#..#\n.##."));
+
+        let a = Answers::parse("1: 42\n").unwrap();
+        assert_eq!(a.get(2), None);
+
+        assert!(Answers::parse("42\n").is_err());
+        assert!(Answers::parse("1: 1\n1: 2\n").is_err());
+    }
+}
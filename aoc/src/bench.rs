@@ -0,0 +1,283 @@
+//! Benchmarks the solutions and keeps a history of their timings.
+//!
+//! Every benchmark run appends one line per day to a CSV file:
+//!
+//! ```text
+//! timestamp,year,day,parse_ns,part1_ns,part2_ns
+//! 1733011200,2022,19,41230,1102345678,12420345678
+//! ```
+//!
+//! `timestamp` is the start of the run in seconds since the Unix epoch and
+//! `part2_ns` is empty for days without a second part. The latest line of a
+//! day is what the next run compares against.
+//!
+//! Built with the `parallel` feature, each day is also timed with a single
+//! thread, to show the speedup the parallel iterators give. The history keeps
+//! the parallel timings.
+
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::parallel;
+use aoc_common::ParseResult;
+
+use crate::inputs::Inputs;
+use crate::runner::{diagnose, Day, Report};
+use crate::table::{Align, Table};
+
+const HEADER: &str = "timestamp,year,day,parse_ns,part1_ns,part2_ns";
+
+/// Slowdowns smaller than this are noise, however large in percent.
+const NOISE: Duration = Duration::from_millis(1);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Timing {
+    parse: Duration,
+    part1: Duration,
+    part2: Option<Duration>,
+}
+
+impl Timing {
+    fn from_report(report: &Report) -> Timing {
+        let part = |n| report.parts.iter().find(|p| p.part == n).map(|p| p.elapsed);
+        Timing {
+            parse: report.parse,
+            part1: part(1).unwrap(),
+            part2: part(2),
+        }
+    }
+
+    /// Keeps the fastest time of each phase, the least disturbed by noise.
+    fn min(self, other: Timing) -> Timing {
+        Timing {
+            parse: self.parse.min(other.parse),
+            part1: self.part1.min(other.part1),
+            part2: self.part2.zip(other.part2).map(|(a, b)| a.min(b)),
+        }
+    }
+
+    fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2.unwrap_or_default()
+    }
+}
+
+/// The fastest timing of `runs` runs of `day` on `text`.
+fn time(day: &Day, text: &str, runs: u32) -> ParseResult<Timing> {
+    (0..runs)
+        .map(|_| (day.run)(text, None, &[]).map(|r| Timing::from_report(&r)))
+        .reduce(|a, b| Ok(a?.min(b?)))
+        .unwrap()
+}
+
+/// Reads the latest timing of every day in the history, if there is one.
+fn load_history(path: &Path) -> Result<HashMap<(u16, u8), Timing>, String> {
+    let s = match fs::read_to_string(path) {
+        Ok(s) => s,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+    };
+    let mut latest = HashMap::new();
+    for (i, line) in s.lines().enumerate().skip(1) {
+        let error = || format!("{}:{}: invalid line `{line}`", path.display(), i + 1);
+        let fields = line.split(',').collect::<Vec<_>>();
+        let [_, year, day, parse, part1, part2] = fields[..] else {
+            return Err(error());
+        };
+        let nanos = |s: &str| s.parse().map(Duration::from_nanos).map_err(|_| error());
+        let timing = Timing {
+            parse: nanos(parse)?,
+            part1: nanos(part1)?,
+            part2: if part2.is_empty() {
+                None
+            } else {
+                Some(nanos(part2)?)
+            },
+        };
+        let key = (
+            year.parse().map_err(|_| error())?,
+            day.parse().map_err(|_| error())?,
+        );
+        latest.insert(key, timing);
+    }
+    Ok(latest)
+}
+
+fn append_history(path: &Path, timestamp: u64, timings: &[(&Day, Timing)]) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("cannot write {}: {e}", path.display());
+    let new = !path.exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(error)?;
+    let mut out = String::new();
+    if new {
+        out.push_str(HEADER);
+        out.push('\n');
+    }
+    for (day, t) in timings {
+        let part2 = t.part2.map(|d| d.as_nanos().to_string());
+        out.push_str(&format!(
+            "{timestamp},{},{},{},{},{}\n",
+            day.year,
+            day.day,
+            t.parse.as_nanos(),
+            t.part1.as_nanos(),
+            part2.unwrap_or_default()
+        ));
+    }
+    file.write_all(out.as_bytes()).map_err(error)
+}
+
+/// Runs every day of `days` on its default input `runs` times, prints the
+/// fastest timings next to the previous run from `history`, then appends them
+/// to it.
+///
+/// A day whose total time grew by more than `threshold` percent, and by more
+/// than `NOISE`, is flagged.
+pub fn bench(
+    days: &[&Day],
+    inputs: &Inputs,
+    runs: u32,
+    history: &Path,
+    threshold: f64,
+) -> Result<(), String> {
+    let previous = load_history(history)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut columns = vec![
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Parse", Align::Right),
+        ("Part 1", Align::Right),
+        ("Part 2", Align::Right),
+        ("Total", Align::Right),
+    ];
+    if parallel::ENABLED {
+        columns.extend([("Serial", Align::Right), ("Speedup", Align::Right)]);
+    }
+    columns.extend([
+        ("Previous", Align::Right),
+        ("Change", Align::Right),
+        ("", Align::Left),
+    ]);
+    let mut table = Table::new(&columns);
+    let mut timings = vec![];
+    let (mut failed, mut regressions) = (0, 0);
+    for &day in days {
+        let found = inputs.resolve(day).and_then(|mut found| {
+            let input = found.remove(0);
+            input.read().map(|text| (input, text))
+        });
+        let (input, text) = match found {
+            Ok(found) => found,
+            Err(e) => {
+                eprintln!("{} day {}: {e}", day.year, day.day);
+                failed += 1;
+                continue;
+            }
+        };
+        let timing = time(day, &text, runs).and_then(|timing| {
+            let serial = if parallel::ENABLED {
+                Some(parallel::serially(|| time(day, &text, runs))?)
+            } else {
+                None
+            };
+            Ok((timing, serial))
+        });
+        let (timing, serial) = match timing {
+            Ok(timing) => timing,
+            Err(e) => {
+                let message = diagnose(&e, &text, &input.path);
+                eprintln!("{} day {}: {message}", day.year, day.day);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let (prev, change, flag) = match previous.get(&(day.year, day.day)) {
+            Some(p) => {
+                let ratio = timing.total().as_secs_f64() / p.total().as_secs_f64();
+                let change = (ratio - 1.0) * 100.0;
+                let regressed = change > threshold && timing.total() > p.total() + NOISE;
+                if regressed {
+                    regressions += 1;
+                }
+                (
+                    format!("{:.2?}", p.total()),
+                    format!("{change:+.0}%"),
+                    if regressed { "REGRESSION" } else { "" },
+                )
+            }
+            None => (String::new(), String::new(), ""),
+        };
+        let mut row = vec![
+            day.year.to_string(),
+            day.day.to_string(),
+            format!("{:.2?}", timing.parse),
+            format!("{:.2?}", timing.part1),
+            timing.part2.map(|d| format!("{d:.2?}")).unwrap_or_default(),
+            format!("{:.2?}", timing.total()),
+        ];
+        if let Some(serial) = serial {
+            let speedup = serial.total().as_secs_f64() / timing.total().as_secs_f64();
+            row.extend([format!("{:.2?}", serial.total()), format!("{speedup:.1}x")]);
+        }
+        row.extend([prev, change, flag.to_string()]);
+        table.push(row);
+        timings.push((day, timing));
+    }
+
+    print!("{table}");
+    let total = timings.iter().map(|(_, t)| t.total()).sum::<Duration>();
+    println!("Total time: {total:.2?}, {regressions} regression(s) over {threshold}%");
+    append_history(history, timestamp, &timings)?;
+    if failed > 0 {
+        return Err(format!("{failed} day(s) could not be run"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.csv", std::process::id()));
+        let day = |day| Day {
+            year: 2022,
+            day,
+            has_part2: day != 25,
+            check_params: |_| unreachable!(),
+            run: |_, _, _| unreachable!(),
+        };
+        let (d1, d25) = (day(1), day(25));
+        let t = |ns| Timing {
+            parse: Duration::from_nanos(ns),
+            part1: Duration::from_nanos(ns * 2),
+            part2: Some(Duration::from_nanos(ns * 3)),
+        };
+        let t25 = Timing {
+            part2: None,
+            ..t(7)
+        };
+
+        append_history(&path, 1, &[(&d1, t(10)), (&d25, t25)]).unwrap();
+        append_history(&path, 2, &[(&d1, t(20))]).unwrap();
+        let history = load_history(&path).unwrap();
+        let lines = fs::read_to_string(&path).unwrap().lines().count();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines, 4);
+        assert_eq!(history[&(2022, 1)], t(20));
+        assert_eq!(history[&(2022, 25)], t25);
+        assert_eq!(t(20).min(t(10)).total(), Duration::from_nanos(60));
+    }
+}
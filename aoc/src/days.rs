@@ -0,0 +1,90 @@
+//! Registry of all solutions known to the runner, in chronological order.
+
+use crate::runner::{day, Day};
+
+pub const DAYS: &[Day] = &[
+    day!(2020, 1, aoc_2020_day01),
+    day!(2020, 2, aoc_2020_day02),
+    day!(2020, 3, aoc_2020_day03),
+    day!(2020, 4, aoc_2020_day04),
+    day!(2020, 5, aoc_2020_day05),
+    day!(2020, 6, aoc_2020_day06),
+    day!(2020, 7, aoc_2020_day07),
+    day!(2020, 8, aoc_2020_day08),
+    day!(2020, 9, aoc_2020_day09),
+    day!(2022, 1, aoc_2022_day01),
+    day!(2022, 2, aoc_2022_day02),
+    day!(2022, 3, aoc_2022_day03),
+    day!(2022, 4, aoc_2022_day04),
+    day!(2022, 5, aoc_2022_day05),
+    day!(2022, 6, aoc_2022_day06),
+    day!(2022, 7, aoc_2022_day07),
+    day!(2022, 8, aoc_2022_day08),
+    day!(2022, 9, aoc_2022_day09),
+    day!(2022, 10, aoc_2022_day10),
+    day!(2022, 11, aoc_2022_day11),
+    day!(2022, 12, aoc_2022_day12),
+    day!(2022, 13, aoc_2022_day13),
+    day!(2022, 14, aoc_2022_day14),
+    day!(2022, 15, aoc_2022_day15),
+    day!(2022, 16, aoc_2022_day16),
+    day!(2022, 17, aoc_2022_day17),
+    day!(2022, 18, aoc_2022_day18),
+    day!(2022, 19, aoc_2022_day19),
+    day!(2022, 20, aoc_2022_day20),
+    day!(2022, 21, aoc_2022_day21),
+    day!(2022, 22, aoc_2022_day22),
+    day!(2022, 23, aoc_2022_day23),
+    day!(2022, 24, aoc_2022_day24),
+    day!(2022, 25, aoc_2022_day25),
+    day!(2023, 1, aoc_2023_day01),
+    day!(2023, 2, aoc_2023_day02),
+    day!(2023, 3, aoc_2023_day03),
+    day!(2023, 4, aoc_2023_day04),
+    day!(2023, 5, aoc_2023_day05),
+    day!(2023, 6, aoc_2023_day06),
+    day!(2023, 7, aoc_2023_day07),
+    day!(2023, 8, aoc_2023_day08),
+    day!(2023, 9, aoc_2023_day09),
+    day!(2023, 10, aoc_2023_day10),
+    day!(2023, 11, aoc_2023_day11),
+    day!(2023, 12, aoc_2023_day12),
+    day!(2023, 13, aoc_2023_day13),
+    day!(2023, 14, aoc_2023_day14),
+    day!(2023, 15, aoc_2023_day15),
+    day!(2023, 16, aoc_2023_day16),
+    day!(2023, 17, aoc_2023_day17),
+    day!(2023, 18, aoc_2023_day18),
+    day!(2023, 19, aoc_2023_day19),
+    day!(2023, 20, aoc_2023_day20),
+    day!(2023, 21, aoc_2023_day21),
+    day!(2023, 22, aoc_2023_day22),
+    day!(2023, 23, aoc_2023_day23),
+    day!(2023, 24, aoc_2023_day24),
+    day!(2023, 25, aoc_2023_day25),
+    day!(2024, 1, aoc_2024_day01),
+    day!(2024, 2, aoc_2024_day02),
+    day!(2024, 3, aoc_2024_day03),
+    day!(2024, 4, aoc_2024_day04),
+    day!(2024, 5, aoc_2024_day05),
+    day!(2024, 6, aoc_2024_day06),
+    day!(2024, 7, aoc_2024_day07),
+    day!(2024, 8, aoc_2024_day08),
+    day!(2024, 9, aoc_2024_day09),
+    day!(2024, 10, aoc_2024_day10),
+    day!(2024, 11, aoc_2024_day11),
+    day!(2024, 12, aoc_2024_day12),
+    day!(2024, 13, aoc_2024_day13),
+    day!(2024, 14, aoc_2024_day14),
+    day!(2024, 15, aoc_2024_day15),
+    day!(2024, 16, aoc_2024_day16),
+    day!(2024, 17, aoc_2024_day17),
+    day!(2024, 18, aoc_2024_day18),
+    day!(2024, 19, aoc_2024_day19),
+    day!(2024, 20, aoc_2024_day20),
+    day!(2024, 21, aoc_2024_day21),
+    day!(2024, 22, aoc_2024_day22),
+    day!(2024, 23, aoc_2024_day23),
+    day!(2024, 24, aoc_2024_day24),
+    day!(2024, 25, aoc_2024_day25),
+];
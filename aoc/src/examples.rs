@@ -0,0 +1,40 @@
+//! Runs every day against the puzzle examples in its `examples` directory.
+//!
+//! `examples/<name>.txt` holds an example input and `examples/<name>.expected`
+//! the answers it should give, in the `answers.txt` format. Parts without an
+//! expected answer are not run, as some examples only apply to one part. An
+//! optional `examples/<name>.params` gives the puzzle parameters of the
+//! example, one `<name>=<value>` per line. The build script generates a test
+//! for each example file.
+
+use aoc_common::params::parse_override;
+
+use crate::answers::Answers;
+use crate::days::DAYS;
+
+fn check(year: u16, day: u8, input: &str, expected: &str, params: &str) {
+    let day = DAYS
+        .iter()
+        .find(|d| d.year == year && d.day == day)
+        .unwrap_or_else(|| panic!("no solution for {year} day {day}"));
+    let expected = Answers::parse(expected).unwrap();
+    let params = params
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(parse_override)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    (day.check_params)(&params).unwrap();
+    let parts = day
+        .parts()
+        .iter()
+        .filter_map(|&p| Some((p, expected.get(p)?)))
+        .collect::<Vec<_>>();
+    assert!(!parts.is_empty(), "no expected answer for any part");
+    for (part, answer) in parts {
+        let report = (day.run)(input, Some(part), &params).unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(report.parts[0].answer.to_string(), answer, "part {part}");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
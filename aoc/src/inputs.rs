@@ -0,0 +1,201 @@
+//! Locating the puzzle inputs of a day.
+//!
+//! A day can have several inputs, e.g. one per account. They are looked up in
+//! the input cache directory, `inputs/` by default:
+//!
+//! ```text
+//! inputs/2023/01.txt          the default input of 2023 day 1
+//! inputs/2023/01/alice.txt    another input, named `alice`
+//! ```
+//!
+//! The day's own `2023/day01/input.txt` is used as the default input when the
+//! cache has none. The known answers of an input sit next to it, in a file with
+//! the `.answers` extension (`answers.txt` for `input.txt`).
+//!
+//! A day without any input can be fetched into the cache by a `Fetch`
+//! implementation; `Offline` is the only one so far.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::runner::Day;
+
+pub const DEFAULT: &str = "default";
+
+pub struct Input {
+    pub name: String,
+    pub path: PathBuf,
+    pub answers: PathBuf,
+}
+
+impl Input {
+    pub fn new(name: &str, path: PathBuf) -> Input {
+        let answers = if path.file_name().is_some_and(|f| f == "input.txt") {
+            path.with_file_name("answers.txt")
+        } else {
+            path.with_extension("answers")
+        };
+        Input {
+            name: name.to_string(),
+            path,
+            answers,
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        fs::read_to_string(&self.path)
+            .map_err(|e| format!("cannot read {}: {e}", self.path.display()))
+    }
+}
+
+/// Downloads the input of a day.
+pub trait Fetch {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+/// Never downloads anything.
+pub struct Offline;
+
+impl Fetch for Offline {
+    fn fetch(&self, _year: u16, _day: u8) -> Result<String, String> {
+        Err("no input found, and fetching inputs is not supported".to_string())
+    }
+}
+
+pub struct Inputs {
+    /// Directory the `<year>/dayNN` directories are in.
+    root: PathBuf,
+    cache: PathBuf,
+    fetcher: Box<dyn Fetch>,
+}
+
+impl Inputs {
+    pub fn new(
+        root: impl Into<PathBuf>,
+        cache: impl Into<PathBuf>,
+        fetcher: Box<dyn Fetch>,
+    ) -> Self {
+        Inputs {
+            root: root.into(),
+            cache: cache.into(),
+            fetcher,
+        }
+    }
+
+    fn cached_path(&self, day: &Day) -> PathBuf {
+        self.cache
+            .join(day.year.to_string())
+            .join(format!("{:02}.txt", day.day))
+    }
+
+    /// Every input `day` has, the default one first and the others by name.
+    pub fn list(&self, day: &Day) -> Vec<Input> {
+        let mut inputs = vec![];
+        let cached = self.cached_path(day);
+        let local = self.root.join(day.dir()).join("input.txt");
+        if let Some(path) = [cached.clone(), local].into_iter().find(|p| p.is_file()) {
+            inputs.push(Input::new(DEFAULT, path));
+        }
+
+        let mut named = fs::read_dir(cached.with_extension(""))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+            .filter_map(|p| Some(Input::new(p.file_stem()?.to_str()?, p.clone())))
+            .filter(|i| i.name != DEFAULT)
+            .collect::<Vec<_>>();
+        named.sort_by(|a, b| a.name.cmp(&b.name));
+        inputs.extend(named);
+        inputs
+    }
+
+    /// Like `list`, but fetches the default input into the cache when there is
+    /// no input at all.
+    pub fn resolve(&self, day: &Day) -> Result<Vec<Input>, String> {
+        let inputs = self.list(day);
+        if !inputs.is_empty() {
+            return Ok(inputs);
+        }
+        let input = self.fetcher.fetch(day.year, day.day)?;
+        let path = self.cached_path(day);
+        write(&path, &input)?;
+        Ok(vec![Input::new(DEFAULT, path)])
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("cannot write {}: {e}", path.display());
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(error)?;
+    }
+    fs::write(path, contents).map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    /// Stands in for downloading, serving a fixed input and counting requests.
+    struct MockFetch {
+        input: &'static str,
+        requests: Rc<Cell<u32>>,
+    }
+
+    impl Fetch for MockFetch {
+        fn fetch(&self, _year: u16, _day: u8) -> Result<String, String> {
+            self.requests.set(self.requests.get() + 1);
+            Ok(self.input.to_string())
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let day = |day| Day {
+            year: 2023,
+            day,
+            has_part2: true,
+            check_params: |_| unreachable!(),
+            run: |_, _, _| unreachable!(),
+        };
+        let requests = Rc::new(Cell::new(0));
+        let inputs = Inputs::new(
+            &root,
+            root.join("cache"),
+            Box::new(MockFetch {
+                input: "fetched\n",
+                requests: requests.clone(),
+            }),
+        );
+        write(&root.join("2023/day01/input.txt"), "local\n").unwrap();
+        write(&root.join("cache/2023/01/bob.txt"), "bob\n").unwrap();
+        write(&root.join("cache/2023/01/alice.txt"), "alice\n").unwrap();
+
+        let names = |inputs: &[Input]| inputs.iter().map(|i| i.name.clone()).collect::<Vec<_>>();
+        let found = inputs.resolve(&day(1)).unwrap();
+        assert_eq!(names(&found), ["default", "alice", "bob"]);
+        assert_eq!(found[0].read().unwrap(), "local\n");
+        assert_eq!(found[0].answers, root.join("2023/day01/answers.txt"));
+        assert_eq!(found[1].answers, root.join("cache/2023/01/alice.answers"));
+
+        // The cache takes precedence over the day directory.
+        write(&root.join("cache/2023/01.txt"), "cached\n").unwrap();
+        assert_eq!(inputs.list(&day(1))[0].read().unwrap(), "cached\n");
+        assert_eq!(requests.get(), 0);
+
+        // A missing input is fetched once, then read from the cache.
+        for _ in 0..2 {
+            let found = inputs.resolve(&day(2)).unwrap();
+            assert_eq!(found[0].read().unwrap(), "fetched\n");
+        }
+        assert_eq!(requests.get(), 1);
+        assert!(root.join("cache/2023/02.txt").is_file());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
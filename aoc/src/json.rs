@@ -0,0 +1,73 @@
+//! The JSON output of `aoc run --format json`: one record per line and part,
+//! such as
+//!
+//! ```text
+//! {"year":2024,"day":18,"input":"default","part":2,"answer":{"x":6,"y":1},"elapsed_ns":1234}
+//! ```
+//!
+//! Integer answers are numbers, text answers strings and coordinates objects
+//! with `x` and `y`.
+
+use std::time::Duration;
+
+use aoc_common::Answer;
+
+fn string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(n) => n.to_string(),
+        Answer::String(s) => string(s),
+        Answer::Coords(c) => format!("{{\"x\":{},\"y\":{}}}", c.x, c.y),
+    }
+}
+
+/// The record of a part's answer on an input.
+pub fn record(
+    year: u16,
+    day: u8,
+    input: &str,
+    part: u8,
+    value: &Answer,
+    elapsed: Duration,
+) -> String {
+    format!(
+        "{{\"year\":{year},\"day\":{day},\"input\":{},\"part\":{part},\"answer\":{},\"elapsed_ns\":{}}}",
+        string(input),
+        answer(value),
+        elapsed.as_nanos()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Coords;
+
+    #[test]
+    fn records() {
+        let elapsed = Duration::from_micros(3);
+        assert_eq!(
+            record(2024, 18, "default", 2, &Coords::new(6, 1).into(), elapsed),
+            r#"{"year":2024,"day":18,"input":"default","part":2,"answer":{"x":6,"y":1},"elapsed_ns":3000}"#
+        );
+        assert_eq!(
+            record(2022, 10, "alice", 1, &13140.into(), elapsed),
+            r#"{"year":2022,"day":10,"input":"alice","part":1,"answer":13140,"elapsed_ns":3000}"#
+        );
+        assert_eq!(answer(&"say \"hi\"\n".into()), r#""say \"hi\"\n""#);
+    }
+}
@@ -0,0 +1,315 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc_common::{params, render};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+mod answers;
+mod bench;
+mod days;
+#[cfg(test)]
+mod examples;
+mod inputs;
+mod json;
+mod record;
+mod replay;
+mod runner;
+mod scaffold;
+mod table;
+mod verify;
+
+use inputs::{Input, Inputs, Offline};
+use runner::{diagnose, Day};
+use table::{Align, Table};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    /// Directory of cached inputs, holding `<year>/<day>.txt` and `<year>/<day>/<name>.txt`
+    #[arg(long, global = true, default_value = "inputs")]
+    inputs: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions and print their answers with timings
+    Run(RunArgs),
+    /// Check the answers of solutions against the known answers of their inputs
+    Verify(VerifyArgs),
+    /// Time the parse and both parts of solutions, comparing with the previous run
+    Bench(BenchArgs),
+    /// Replay the frames a part draws in the terminal, for the days that draw them
+    Replay(ReplayArgs),
+    /// Create the crate of a new day and register it with the workspace and the runner
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Year to run, e.g. 2023
+    #[arg(required_unless_present = "all")]
+    year: Option<u16>,
+    /// Day to run; all days of the year when omitted
+    day: Option<u8>,
+    /// Run only this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input from this file instead of the day's inputs
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Override a puzzle parameter, e.g. `--param row=10` to run 2022 day 15 on its example
+    #[arg(long = "param", value_name = "NAME=VALUE", requires = "day", value_parser = params::parse_override)]
+    params: Vec<(String, String)>,
+    /// Run every registered day
+    #[arg(long, conflicts_with_all = ["year", "day"])]
+    all: bool,
+    /// Print the answers as a table, or as JSON records one per line
+    #[arg(long, value_enum, default_value = "table")]
+    format: OutputFormat,
+    /// Record the frames the part draws into this directory, for the days that draw them
+    #[arg(long, value_name = "DIR", requires_all = ["day", "part"])]
+    record: Option<PathBuf>,
+    /// Write the recorded frames as a GIF or as PPM images
+    #[arg(long, value_enum, default_value = "gif", requires = "record")]
+    record_format: record::Format,
+    /// Keep only one in this many recorded frames
+    #[arg(long, default_value_t = 1, requires = "record", value_parser = clap::value_parser!(u32).range(1..))]
+    every: u32,
+    /// Draw each cell of a frame as a square of this many pixels
+    #[arg(long, default_value_t = 4, requires = "record", value_parser = clap::value_parser!(u32).range(1..))]
+    scale: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Year to verify; all years when omitted
+    year: Option<u16>,
+    /// Day to verify; all days of the year when omitted
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Year to benchmark; all years when omitted
+    year: Option<u16>,
+    /// Day to benchmark; all days of the year when omitted
+    day: Option<u8>,
+    /// Run each day this many times and keep the fastest timings
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// CSV file the timings are compared with and appended to
+    #[arg(long, default_value = "bench_history.csv")]
+    history: PathBuf,
+    /// Flag days whose total time grew by more than this percentage
+    #[arg(long, default_value_t = 20.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct ReplayArgs {
+    /// Year of the day to replay
+    year: u16,
+    /// Day to replay
+    day: u8,
+    /// Part to replay
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Read the input from this file instead of the day's default input
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Override a puzzle parameter, see `aoc run`
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+    params: Vec<(String, String)>,
+    /// Keep only one in this many frames
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    every: u32,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Year of the new day
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+    /// The new day
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    let days = days::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y))
+        .filter(|d| day.is_none_or(|n| d.day == n))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(match (year, day) {
+            (Some(y), Some(d)) => format!("no solution for {y} day {d}"),
+            (Some(y), None) => format!("no solutions for {y}"),
+            _ => "no solutions registered".to_string(),
+        });
+    }
+    Ok(days)
+}
+
+fn run(args: RunArgs, inputs: &Inputs) -> Result<(), String> {
+    let days = select(args.year, args.day)?;
+    for day in &days {
+        (day.check_params)(&args.params)
+            .map_err(|e| format!("{} day {}: {e}", day.year, day.day))?;
+    }
+
+    let mut table = Table::new(&[
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Input", Align::Left),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Time", Align::Right),
+    ]);
+    let mut total = Duration::ZERO;
+    let mut failed = 0;
+    let mut recordings = vec![];
+    for day in days {
+        let day_inputs = match &args.input {
+            Some(path) => Ok(vec![Input::new(&path.display().to_string(), path.clone())]),
+            None => inputs.resolve(day),
+        };
+        let day_inputs = match day_inputs {
+            Ok(day_inputs) => day_inputs,
+            Err(e) => {
+                eprintln!("{} day {}: {e}", day.year, day.day);
+                failed += 1;
+                continue;
+            }
+        };
+        for input in day_inputs {
+            let text = match input.read() {
+                Ok(text) => text,
+                Err(e) => {
+                    eprintln!("{} day {}: {e}", day.year, day.day);
+                    failed += 1;
+                    continue;
+                }
+            };
+            if args.record.is_some() {
+                render::start(args.every as usize);
+            }
+            let report = (day.run)(&text, args.part, &args.params);
+            let frames = render::stop();
+            if let (Some(dir), Ok(_)) = (&args.record, &report) {
+                let name = format!(
+                    "{}-day{:02}-{}-part{}",
+                    day.year,
+                    day.day,
+                    input.name,
+                    args.part.unwrap()
+                );
+                match record::write(dir, &name, &frames, args.record_format, args.scale as usize) {
+                    Ok(path) => recordings.push((frames.len(), path)),
+                    Err(e) => {
+                        eprintln!("{} day {}: cannot write {name}: {e}", day.year, day.day);
+                        failed += 1;
+                    }
+                }
+            }
+            let report = match report {
+                Ok(report) => report,
+                Err(e) => {
+                    let message = diagnose(&e, &text, &input.path);
+                    eprintln!("{} day {}: {message}", day.year, day.day);
+                    failed += 1;
+                    continue;
+                }
+            };
+            total += report.total();
+            for p in report.parts {
+                if args.format == OutputFormat::Json {
+                    let record =
+                        json::record(day.year, day.day, &input.name, p.part, &p.answer, p.elapsed);
+                    println!("{record}");
+                    continue;
+                }
+                table.push(vec![
+                    day.year.to_string(),
+                    day.day.to_string(),
+                    input.name.clone(),
+                    p.part.to_string(),
+                    p.answer.to_string(),
+                    format!("{:.2?}", p.elapsed),
+                ]);
+            }
+        }
+    }
+    // The JSON records are the whole output, for scripts to read.
+    if args.format == OutputFormat::Table {
+        print!("{table}");
+        println!("Total time: {total:.2?}");
+    }
+    for (count, path) in recordings {
+        eprintln!("Recorded {count} frame(s) to {}", path.display());
+    }
+    if failed > 0 {
+        return Err(format!("{failed} input(s) could not be run"));
+    }
+    Ok(())
+}
+
+fn replay(args: ReplayArgs, inputs: &Inputs) -> Result<(), String> {
+    let day = select(Some(args.year), Some(args.day))?[0];
+    let context = format!("{} day {}", day.year, day.day);
+    (day.check_params)(&args.params).map_err(|e| format!("{context}: {e}"))?;
+    if !day.parts().contains(&args.part) {
+        return Err(format!("{context} has no part {}", args.part));
+    }
+    let input = match args.input {
+        Some(path) => Input::new(&path.display().to_string(), path),
+        None => inputs
+            .resolve(day)
+            .map_err(|e| format!("{context}: {e}"))?
+            .remove(0),
+    };
+    let text = input.read().map_err(|e| format!("{context}: {e}"))?;
+
+    render::start(args.every as usize);
+    let report = (day.run)(&text, Some(args.part), &args.params);
+    let frames = render::stop();
+    report.map_err(|e| format!("{context}: {}", diagnose(&e, &text, &input.path)))?;
+    if frames.is_empty() {
+        return Err(format!("{context} part {} draws no frames", args.part));
+    }
+    replay::replay(frames).map_err(|e| format!("cannot replay {context}: {e}"))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let inputs = Inputs::new(".", cli.inputs, Box::new(Offline));
+    let result = match cli.command {
+        Command::Run(args) => run(args, &inputs),
+        Command::Verify(args) => {
+            select(args.year, args.day).and_then(|days| verify::verify(&days, &inputs))
+        }
+        Command::Bench(args) => select(args.year, args.day).and_then(|days| {
+            bench::bench(&days, &inputs, args.runs, &args.history, args.threshold)
+        }),
+        Command::Replay(args) => replay(args, &inputs),
+        Command::New(args) => scaffold::new_day(Path::new("."), args.year, args.day).map(|dir| {
+            println!("Created {}", dir.display());
+        }),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
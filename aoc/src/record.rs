@@ -0,0 +1,49 @@
+//! Writes the frames days draw while `aoc run --record` records them.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+
+use aoc_common::render::{self, Frame};
+use clap::ValueEnum;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// A single animated GIF
+    Gif,
+    /// A directory with one PPM image per frame
+    Ppm,
+}
+
+/// Hundredths of a second each frame of a GIF is shown.
+const DELAY: u16 = 5;
+
+/// Writes `frames` in `dir` to a file named after the run they come from,
+/// returning its path.
+pub fn write(
+    dir: &Path,
+    name: &str,
+    frames: &[Frame],
+    format: Format,
+    scale: usize,
+) -> io::Result<PathBuf> {
+    // Inputs given with `--input` are named after their path.
+    let name = name.replace(|c: char| !c.is_alphanumeric() && c != '-', "_");
+    fs::create_dir_all(dir)?;
+    match format {
+        Format::Gif => {
+            let path = dir.join(name).with_extension("gif");
+            render::write_gif(frames, scale, DELAY, BufWriter::new(File::create(&path)?))?;
+            Ok(path)
+        }
+        Format::Ppm => {
+            let path = dir.join(name);
+            fs::create_dir_all(&path)?;
+            for (i, frame) in frames.iter().enumerate() {
+                let file = File::create(path.join(format!("{i:05}.ppm")))?;
+                frame.write_ppm(scale, BufWriter::new(file))?;
+            }
+            Ok(path)
+        }
+    }
+}
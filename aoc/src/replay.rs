@@ -0,0 +1,230 @@
+//! Plays back the frames a day recorded, in the terminal.
+//!
+//! The recorded frames are the history of the simulation, which is what lets
+//! the player step back as well as forward.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use aoc_common::render::Frame;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::{cursor, execute, terminal};
+
+const SPEEDS: [u32; 8] = [1, 2, 5, 10, 20, 50, 100, 200];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    TogglePlay,
+    Step,
+    StepBack,
+    Faster,
+    Slower,
+    First,
+    Last,
+    Quit,
+}
+
+impl Command {
+    fn from_key(key: KeyEvent) -> Option<Command> {
+        match key.code {
+            KeyCode::Char(' ') => Some(Command::TogglePlay),
+            KeyCode::Right | KeyCode::Char('l') => Some(Command::Step),
+            KeyCode::Left | KeyCode::Char('h') => Some(Command::StepBack),
+            KeyCode::Up | KeyCode::Char('+') => Some(Command::Faster),
+            KeyCode::Down | KeyCode::Char('-') => Some(Command::Slower),
+            KeyCode::Home => Some(Command::First),
+            KeyCode::End => Some(Command::Last),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Position in the frames, and how they are being played.
+pub struct Player {
+    frames: Vec<Frame>,
+    index: usize,
+    playing: bool,
+    speed: usize,
+}
+
+impl Player {
+    pub fn new(frames: Vec<Frame>) -> Player {
+        Player {
+            frames,
+            index: 0,
+            playing: false,
+            speed: 3,
+        }
+    }
+
+    fn last(&self) -> usize {
+        self.frames.len().saturating_sub(1)
+    }
+
+    /// Frames shown per second while playing.
+    fn fps(&self) -> u32 {
+        SPEEDS[self.speed]
+    }
+
+    /// Applies `command`, returning false when the player should quit.
+    pub fn handle(&mut self, command: Command) -> bool {
+        match command {
+            Command::TogglePlay => {
+                if self.index == self.last() {
+                    self.index = 0;
+                }
+                self.playing = !self.playing;
+            }
+            Command::Step => {
+                self.playing = false;
+                self.index = (self.index + 1).min(self.last());
+            }
+            Command::StepBack => {
+                self.playing = false;
+                self.index = self.index.saturating_sub(1);
+            }
+            Command::Faster => self.speed = (self.speed + 1).min(SPEEDS.len() - 1),
+            Command::Slower => self.speed = self.speed.saturating_sub(1),
+            Command::First => self.index = 0,
+            Command::Last => self.index = self.last(),
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    /// Moves to the next frame while playing, stopping on the last one.
+    pub fn tick(&mut self) {
+        if self.playing {
+            self.index += 1;
+            if self.index >= self.last() {
+                self.index = self.last();
+                self.playing = false;
+            }
+        }
+    }
+
+    /// Draws the current frame from the top-left corner of a terminal of
+    /// `columns`×`lines` characters, with a status line below it.
+    pub fn draw(&self, columns: usize, lines: usize, mut w: impl Write) -> io::Result<()> {
+        write!(w, "\x1b[H\x1b[2J")?;
+        if let Some(frame) = self.frames.get(self.index) {
+            frame.write_ansi(columns, lines.saturating_sub(1), &mut w)?;
+        }
+        write!(
+            w,
+            "frame {}/{}  {}  {} fps  [space] play/pause  [←/→] step  [↑/↓] speed  [q] quit",
+            self.index + 1,
+            self.frames.len(),
+            if self.playing { "playing" } else { "paused" },
+            self.fps(),
+        )?;
+        w.flush()
+    }
+}
+
+/// Replays `frames` in the terminal until the user quits.
+pub fn replay(frames: Vec<Frame>) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let result = play(Player::new(frames), &mut stdout);
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn play(mut player: Player, stdout: &mut io::Stdout) -> io::Result<()> {
+    let mut next_tick = Instant::now();
+    loop {
+        let (columns, lines) = terminal::size()?;
+        player.draw(columns as usize, lines as usize, &mut *stdout)?;
+
+        let timeout = if player.playing {
+            next_tick.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(3600)
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                let command = (key.kind == KeyEventKind::Press)
+                    .then(|| Command::from_key(key))
+                    .flatten();
+                if command.is_some_and(|c| !player.handle(c)) {
+                    return Ok(());
+                }
+            }
+        } else {
+            player.tick();
+        }
+        if Instant::now() >= next_tick {
+            next_tick = Instant::now() + Duration::from_secs(1) / player.fps();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames(n: u8) -> Vec<Frame> {
+        let palette = (0..n).map(|i| [i, i, i]).collect::<Vec<_>>();
+        (0..n)
+            .map(|i| Frame::new(1, 1, &palette, |_, _| i))
+            .collect()
+    }
+
+    fn shown(player: &Player) -> String {
+        let mut out = vec![];
+        player.draw(80, 24, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn stepping() {
+        let mut player = Player::new(frames(3));
+        assert!(shown(&player).contains("frame 1/3  paused  10 fps"));
+        assert!(shown(&player).contains("\x1b[38;2;0;0;0m"));
+
+        player.handle(Command::Step);
+        player.handle(Command::Step);
+        player.handle(Command::Step);
+        assert!(shown(&player).contains("frame 3/3"));
+        assert!(shown(&player).contains("\x1b[38;2;2;2;2m"));
+
+        player.handle(Command::StepBack);
+        assert!(shown(&player).contains("frame 2/3"));
+        player.handle(Command::First);
+        player.handle(Command::StepBack);
+        assert!(shown(&player).contains("frame 1/3"));
+        player.handle(Command::Last);
+        assert!(shown(&player).contains("frame 3/3"));
+        assert!(!player.handle(Command::Quit));
+    }
+
+    #[test]
+    fn playing() {
+        let mut player = Player::new(frames(3));
+        player.tick();
+        assert!(shown(&player).contains("frame 1/3  paused"));
+
+        player.handle(Command::TogglePlay);
+        player.tick();
+        assert!(shown(&player).contains("frame 2/3  playing"));
+        player.tick();
+        assert!(shown(&player).contains("frame 3/3  paused"));
+
+        // Playing again from the end starts over.
+        player.handle(Command::TogglePlay);
+        assert!(shown(&player).contains("frame 1/3  playing"));
+        player.handle(Command::StepBack);
+        assert!(shown(&player).contains("frame 1/3  paused"));
+
+        player.handle(Command::Faster);
+        assert!(shown(&player).contains("20 fps"));
+        (0..10).for_each(|_| {
+            player.handle(Command::Slower);
+        });
+        assert!(shown(&player).contains(" 1 fps"));
+    }
+}
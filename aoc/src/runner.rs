@@ -0,0 +1,113 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc_common::params::Overrides;
+use aoc_common::{Answer, Params, ParseError, ParseResult, Solution};
+
+/// A registered solution, see the `days` module.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub has_part2: bool,
+    /// Checks `name=value` parameter overrides, which `run` expects to be valid.
+    pub check_params: fn(&Overrides) -> Result<(), String>,
+    pub run: fn(&str, Option<u8>, &Overrides) -> ParseResult<Report>,
+}
+
+impl Day {
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(format!("{}/day{:02}", self.year, self.day))
+    }
+
+    /// The parts this day solves, `[1]` or `[1, 2]`.
+    pub fn parts(&self) -> &'static [u8] {
+        if self.has_part2 {
+            &[1, 2]
+        } else {
+            &[1]
+        }
+    }
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        $crate::runner::Day {
+            year: $year,
+            day: $day,
+            has_part2: <$krate::Day as ::aoc_common::Solution>::HAS_PART2,
+            check_params: $crate::runner::check_params::<$krate::Day>,
+            run: $crate::runner::run::<$krate::Day>,
+        }
+    };
+}
+
+pub(crate) use day;
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub struct Report {
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+pub fn check_params<S: Solution>(overrides: &Overrides) -> Result<(), String> {
+    S::Params::with_overrides(overrides).map(|_| ())
+}
+
+/// Parses `input` with the parameters overridden by `overrides` and runs the
+/// selected part, or both parts when `part` is `None`.
+pub fn run<S: Solution>(
+    input: &str,
+    part: Option<u8>,
+    overrides: &Overrides,
+) -> ParseResult<Report> {
+    let params = S::Params::with_overrides(overrides).expect("parameters should be checked");
+    let start = Instant::now();
+    let parsed = S::parse_with(input, &params).map_err(|e| e.locate(input))?;
+    let parse = start.elapsed();
+
+    let mut parts = vec![];
+    if part.is_none_or(|p| p == 1) {
+        let start = Instant::now();
+        let answer = S::part1(&parsed).into();
+        parts.push(PartReport {
+            part: 1,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+    if S::HAS_PART2 && part.is_none_or(|p| p == 2) {
+        let start = Instant::now();
+        let answer = S::part2(&parsed).into();
+        parts.push(PartReport {
+            part: 2,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+    Ok(Report { parse, parts })
+}
+
+/// Describes why `input`, read from `path`, could not be parsed, adding hints
+/// for the usual problems with downloaded inputs.
+pub fn diagnose(error: &ParseError, input: &str, path: &Path) -> String {
+    let mut message = format!("{}: {error}", path.display());
+    if input.trim().is_empty() {
+        message.push_str("\n  note: the input is empty");
+    } else if input.contains('\r') {
+        message.push_str("\n  note: the input has Windows (CRLF) line endings, convert them to LF");
+    } else if !input.ends_with('\n') {
+        message.push_str("\n  note: the input does not end with a newline, it may be truncated");
+    }
+    message
+}
@@ -0,0 +1,301 @@
+//! Generates the crate of a new day and registers it with the workspace and
+//! the runner.
+//!
+//! `aoc new 2025 1` creates `2025/day01/` with a `Solution` to fill in, an
+//! empty `input.txt` and an empty example, `examples/1.txt` with its
+//! `examples/1.expected`. The build script skips empty examples, so the
+//! tests pass until the example is pasted in. The crate is then added to the
+//! workspace members if its year is new, to the dependencies of the runner and
+//! to `days::DAYS`. The members name the days of a year with `<year>/day*`,
+//! unless some of its days are not Rust crates, such as the Go days of 2016,
+//! in which case the new day is listed on its own.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const LIB_TEMPLATE: &str = "\
+use aoc_common::{Answer, ParseResult, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        lines.len()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> impl Into<Answer> {
+        lines.len()
+    }
+}
+";
+
+fn manifest(year: u16, day: u8) -> String {
+    format!(
+        "[package]\n\
+         name = \"aoc-{year}-day{day:02}\"\n\
+         version.workspace = true\n\
+         edition.workspace = true\n\
+         \n\
+         [dependencies]\n\
+         aoc-common.workspace = true\n"
+    )
+}
+
+/// Inserts `line` among the lines of `text` that have a key, before the first
+/// one with a greater key, so that sorted lines stay sorted.
+fn insert_sorted<K: Ord>(text: &str, line: &str, key: impl Fn(&str) -> Option<K>) -> String {
+    let new = key(line).expect("the inserted line should have a key");
+    let lines = text.lines().collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, key(l)?)))
+        .collect::<Vec<_>>();
+    let at = keyed
+        .iter()
+        .find(|(_, k)| *k > new)
+        .map(|&(i, _)| i)
+        .or_else(|| keyed.last().map(|&(i, _)| i + 1))
+        .unwrap_or(lines.len());
+    let mut out = lines[..at].to_vec();
+    out.push(line);
+    out.extend(&lines[at..]);
+    out.join("\n") + "\n"
+}
+
+/// The workspace member for `year` day `day`: every day of the year when all
+/// the day directories at `root` are crates, else the day alone.
+fn member(root: &Path, year: u16, day: u8) -> String {
+    let all_crates = fs::read_dir(root.join(year.to_string()))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("day"))
+        .all(|e| e.path().join("Cargo.toml").exists());
+    match all_crates {
+        true => format!("{year}/day*"),
+        false => format!("{year}/day{day:02}"),
+    }
+}
+
+/// Adds `member` to the members of the workspace manifest, unless it, or the
+/// days of its year, are already.
+fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let year_days = format!("{}/day*", member.split('/').next().unwrap());
+    let mut done = false;
+    let mut out = String::new();
+    for line in manifest.lines() {
+        if let Some(list) = line
+            .strip_prefix("members = [")
+            .and_then(|l| l.strip_suffix(']'))
+        {
+            let mut members = list
+                .split(',')
+                .map(|m| m.trim().trim_matches('"'))
+                .filter(|m| !m.is_empty())
+                .collect::<Vec<_>>();
+            if !members.contains(&member) && !members.contains(&year_days.as_str()) {
+                // After the days before it, leaving the other members be.
+                let days = members
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, m)| m.starts_with(|c: char| c.is_ascii_digit()))
+                    .collect::<Vec<_>>();
+                let at = days
+                    .iter()
+                    .find(|(_, m)| **m > member)
+                    .map(|&(i, _)| i)
+                    .or_else(|| days.last().map(|&(i, _)| i + 1))
+                    .unwrap_or(members.len());
+                members.insert(at, member);
+            }
+            let members = members
+                .iter()
+                .map(|m| format!("\"{m}\""))
+                .collect::<Vec<_>>();
+            out.push_str(&format!("members = [{}]\n", members.join(", ")));
+            done = true;
+        } else {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    if !done {
+        return Err("expected a one-line `members = [...]` in the workspace manifest".to_string());
+    }
+    Ok(out)
+}
+
+fn dependency_key(line: &str) -> Option<String> {
+    line.starts_with("aoc-20")
+        .then(|| line.split_whitespace().next().unwrap().to_string())
+}
+
+fn registration_key(line: &str) -> Option<(u16, u8)> {
+    let args = line.trim().strip_prefix("day!(")?;
+    let mut args = args.split(',').map(str::trim);
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+/// Creates the crate of `year` day `day` in the repository at `root` and
+/// registers it, returning its directory. Refuses to touch an existing day.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))
+    };
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|e| format!("cannot write {}: {e}", path.display()))
+    };
+
+    let dir = root.join(year.to_string()).join(format!("day{day:02}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let (workspace, runner, days) = (
+        root.join("Cargo.toml"),
+        root.join("aoc/Cargo.toml"),
+        root.join("aoc/src/days.rs"),
+    );
+    let registered = read(&days)?;
+    if registered
+        .lines()
+        .any(|l| registration_key(l) == Some((year, day)))
+    {
+        return Err(format!("{year} day {day} is already registered"));
+    }
+
+    // Prepare every edit before writing anything, so that a bad manifest
+    // leaves the repository as it was.
+    let workspace_text = add_member(&read(&workspace)?, &member(root, year, day))?;
+    let dependency = format!("aoc-{year}-day{day:02} = {{ path = \"../{year}/day{day:02}\" }}");
+    let runner_text = insert_sorted(&read(&runner)?, &dependency, dependency_key);
+    let registration = format!("    day!({year}, {day}, aoc_{year}_day{day:02}),");
+    let days_text = insert_sorted(&registered, &registration, registration_key);
+
+    // A failed write removes the new day, but not the registrations written
+    // before it.
+    let written = fs::create_dir_all(dir.join("src"))
+        .and_then(|_| fs::create_dir_all(dir.join("examples")))
+        .map_err(|e| format!("cannot create {}: {e}", dir.display()))
+        .and_then(|_| write(&dir.join("Cargo.toml"), &manifest(year, day)))
+        .and_then(|_| write(&dir.join("src/lib.rs"), LIB_TEMPLATE))
+        .and_then(|_| write(&dir.join("input.txt"), ""))
+        .and_then(|_| write(&dir.join("examples/1.txt"), ""))
+        .and_then(|_| write(&dir.join("examples/1.expected"), ""))
+        .and_then(|_| write(&workspace, &workspace_text))
+        .and_then(|_| write(&runner, &runner_text))
+        .and_then(|_| write(&days, &days_text));
+    if let Err(e) = written {
+        let _ = fs::remove_dir_all(&dir);
+        return Err(e);
+    }
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_insertion() {
+        let days =
+            "pub const DAYS: &[Day] = &[\n    day!(2024, 9, a),\n    day!(2024, 10, b),\n];\n";
+        let with = |line| insert_sorted(days, line, registration_key);
+        assert_eq!(
+            with("    day!(2024, 11, c),"),
+            "pub const DAYS: &[Day] = &[\n    day!(2024, 9, a),\n    day!(2024, 10, b),\n    day!(2024, 11, c),\n];\n"
+        );
+        assert_eq!(
+            with("    day!(2023, 25, c),"),
+            "pub const DAYS: &[Day] = &[\n    day!(2023, 25, c),\n    day!(2024, 9, a),\n    day!(2024, 10, b),\n];\n"
+        );
+
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"2022/day*\", \"2024/day*\"]\n";
+        assert_eq!(
+            add_member(manifest, "2023/day*").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"2022/day*\", \"2023/day*\", \"2024/day*\"]\n"
+        );
+        assert_eq!(add_member(manifest, "2024/day*").unwrap(), manifest);
+        assert_eq!(add_member(manifest, "2024/day03").unwrap(), manifest);
+        assert_eq!(
+            add_member(manifest, "2016/day10").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"2016/day10\", \"2022/day*\", \"2024/day*\"]\n"
+        );
+        assert!(add_member("[workspace]\n", "2024/day*").is_err());
+    }
+
+    /// A repository with 2024 day 1 in a temporary directory named after
+    /// `name`.
+    fn repository(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "members = [\"aoc\", \"2024/day*\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\naoc-2024-day01 = { path = \"../2024/day01\" }\naoc-common.workspace = true\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    day!(2024, 1, aoc_2024_day01),\n];\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn new_day() {
+        let root = repository("scaffold");
+        let dir = super::new_day(&root, 2025, 3).unwrap();
+        let again = super::new_day(&root, 2025, 3);
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        let (workspace, runner, days) = (
+            read("Cargo.toml"),
+            read("aoc/Cargo.toml"),
+            read("aoc/src/days.rs"),
+        );
+        let created = ["Cargo.toml", "src/lib.rs", "input.txt", "examples/1.txt"]
+            .iter()
+            .all(|f| dir.join(f).exists());
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(created);
+        assert!(again.unwrap_err().ends_with("already exists"));
+        assert_eq!(
+            workspace,
+            "members = [\"aoc\", \"2024/day*\", \"2025/day*\"]\n"
+        );
+        assert!(
+            runner.contains("day01\" }\naoc-2025-day03 = { path = \"../2025/day03\" }\naoc-common")
+        );
+        assert!(days.contains("day01),\n    day!(2025, 3, aoc_2025_day03),\n];"));
+    }
+
+    #[test]
+    fn new_day_among_other_languages() {
+        let root = repository("scaffold-mixed");
+        fs::create_dir_all(root.join("2016/day01")).unwrap();
+        fs::write(root.join("2016/day01/day01.go"), "package main\n").unwrap();
+
+        let created = super::new_day(&root, 2016, 10).map(|_| ());
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(created, Ok(()));
+        assert_eq!(
+            workspace,
+            "members = [\"aoc\", \"2016/day10\", \"2024/day*\"]\n"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Plain-text table with a header row, sized to fit its widest cells.
+pub struct Table {
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&'static str, Align)]) -> Self {
+        Table {
+            columns: columns.to_vec(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Multi-line cells (e.g. pictures drawn by a solution) only count
+        // their first line towards the column width; the rest is printed
+        // below the row.
+        let first_line = |cell: &str| cell.lines().next().unwrap_or("").to_string();
+        let widths = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| {
+                self.rows
+                    .iter()
+                    .map(|r| first_line(&r[i]).chars().count())
+                    .fold(name.len(), usize::max)
+            })
+            .collect::<Vec<_>>();
+
+        let write_row = |f: &mut fmt::Formatter<'_>, cells: Vec<String>| {
+            let line = cells
+                .iter()
+                .zip(&self.columns)
+                .zip(&widths)
+                .map(|((c, (_, align)), &w)| match align {
+                    Align::Left => format!("{c:<w$}"),
+                    Align::Right => format!("{c:>w$}"),
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, self.columns.iter().map(|(n, _)| n.to_string()).collect())?;
+        write_row(f, widths.iter().map(|&w| "-".repeat(w)).collect())?;
+        for row in &self.rows {
+            write_row(f, row.iter().map(|c| first_line(c)).collect())?;
+            for cell in row.iter().filter(|c| c.contains('\n')) {
+                for line in cell.lines().skip(1) {
+                    writeln!(f, "    {line}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        let mut t = Table::new(&[("Day", Align::Right), ("Answer", Align::Left)]);
+        t.push(vec!["1".into(), "12345678".into()]);
+        t.push(vec!["10".into(), "#.\n.#".into()]);
+        assert_eq!(
+            t.to_string(),
+            "Day  Answer\n\
+             ---  --------\n  \
+             1  12345678\n \
+             10  #.\n    \
+             .#\n"
+        );
+    }
+}
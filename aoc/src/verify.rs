@@ -0,0 +1,131 @@
+use std::any::Any;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc_common::ParseResult;
+
+use crate::answers::Answers;
+use crate::inputs::Inputs;
+use crate::runner::{diagnose, Day};
+use crate::table::{Align, Table};
+
+enum Status {
+    Pass,
+    Wrong(String),
+    Missing,
+    Invalid,
+    Panic,
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Runs a single part for the text of its answer, turning a panic into an
+/// error carrying its message.
+fn run_part(day: &Day, input: &str, part: u8) -> Result<ParseResult<String>, String> {
+    // The panic is reported in the table, keep the default hook from also
+    // printing it in the middle of it.
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (day.run)(input, Some(part), &[])));
+    let _ = panic::take_hook();
+    result
+        .map(|report| report.map(|r| r.parts[0].answer.to_string()))
+        .map_err(|payload| panic_message(&*payload))
+}
+
+/// Runs every part of `days` on each of their inputs and compares the results
+/// with the stored answers.
+pub fn verify(days: &[&Day], inputs: &Inputs) -> Result<(), String> {
+    let mut table = Table::new(&[
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Input", Align::Left),
+        ("Part", Align::Right),
+        ("Result", Align::Left),
+        ("Answer", Align::Left),
+        ("Expected", Align::Left),
+    ]);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        let day_inputs = match inputs.resolve(day) {
+            Ok(day_inputs) => day_inputs,
+            Err(e) => {
+                eprintln!("{} day {}: {e}", day.year, day.day);
+                failed += day.parts().len();
+                continue;
+            }
+        };
+        for input in day_inputs {
+            let text = match input.read() {
+                Ok(text) => text,
+                Err(e) => {
+                    eprintln!("{} day {}: {e}", day.year, day.day);
+                    failed += day.parts().len();
+                    continue;
+                }
+            };
+            let answers = match fs::read_to_string(&input.answers) {
+                Ok(s) => {
+                    Answers::parse(&s).map_err(|e| format!("{}: {e}", input.answers.display()))?
+                }
+                Err(_) => Answers::default(),
+            };
+
+            for &part in day.parts() {
+                let (answer, status) = match (run_part(day, &text, part), answers.get(part)) {
+                    (Err(message), _) => (message, Status::Panic),
+                    (Ok(Err(e)), _) => (diagnose(&e, &text, &input.path), Status::Invalid),
+                    (Ok(Ok(a)), None) => (a, Status::Missing),
+                    (Ok(Ok(a)), Some(expected)) if a == expected => (a, Status::Pass),
+                    (Ok(Ok(a)), Some(expected)) => (a, Status::Wrong(expected.to_string())),
+                };
+                let (result, expected) = match status {
+                    Status::Pass => {
+                        passed += 1;
+                        ("ok", String::new())
+                    }
+                    Status::Wrong(expected) => {
+                        failed += 1;
+                        ("WRONG", expected)
+                    }
+                    Status::Missing => {
+                        missing += 1;
+                        ("missing", String::new())
+                    }
+                    Status::Invalid => {
+                        failed += 1;
+                        ("INVALID", String::new())
+                    }
+                    Status::Panic => {
+                        failed += 1;
+                        ("PANIC", String::new())
+                    }
+                };
+                table.push(vec![
+                    day.year.to_string(),
+                    day.day.to_string(),
+                    input.name.clone(),
+                    part.to_string(),
+                    result.to_string(),
+                    answer,
+                    expected,
+                ]);
+            }
+        }
+    }
+
+    print!("{table}");
+    println!("{passed} passed, {failed} failed, {missing} without a stored answer");
+    if failed > 0 {
+        return Err(format!("{failed} part(s) failed verification"));
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! The answer of a part, typed so that the runner can report it as data.
+
+use std::fmt;
+
+use crate::Coords;
+
+/// What a part of a puzzle answers. Its `Display` form is the one the puzzle
+/// accepts, and the one `answers.txt` stores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    /// Text such as a password, or the letters drawn on a screen.
+    String(String),
+    /// A position, written `x,y`.
+    Coords(Coords),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::String(s) => write!(f, "{s}"),
+            Answer::Coords(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(i128::try_from(n).expect("the answer should fit in an i128"))
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_string())
+    }
+}
+
+impl From<Coords> for Answer {
+    fn from(c: Coords) -> Self {
+        Answer::Coords(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(-7i32), Answer::Integer(-7));
+        assert_eq!(Answer::from("EHZFZHCZ").to_string(), "EHZFZHCZ");
+        assert_eq!(Answer::from(Coords::new(6, 1)).to_string(), "6,1");
+    }
+}
@@ -0,0 +1,109 @@
+//! Cycle detection, to skip ahead in simulations run for a huge number of steps.
+//!
+//! A deterministic simulation over finitely many states eventually repeats
+//! itself: after `start` steps it enters a cycle of `length` steps, which it
+//! then goes around forever.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before entering the cycle, µ.
+    pub start: usize,
+    /// Number of steps around the cycle, λ.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step in the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The value at step `n` of a quantity which grows by the same amount on
+    /// every turn around the cycle, such as the height of a tower. `value(i)`
+    /// gives its value at step `i`, for `i` up to `start + length`.
+    pub fn extrapolate(&self, value: impl Fn(usize) -> usize, n: usize) -> usize {
+        let i = self.equivalent_step(n);
+        let turns = (n - i) / self.length;
+        let growth = value(self.start + self.length) - value(self.start);
+        value(i) + turns * growth
+    }
+}
+
+/// Steps from `initial` until a state repeats, see `find_cycle_by_key`.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    find_cycle_by_key(initial, step, S::clone)
+}
+
+/// Steps from `initial` until two states have the same key, which should
+/// capture everything that decides how the simulation goes on. Returns the
+/// cycle with the states up to the repeat: `states[i]` is the state after `i`
+/// steps, and `states[start + length]` has the same key as `states[start]`.
+///
+/// Never returns if the keys don't repeat.
+pub fn find_cycle_by_key<S, K: Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut s = initial;
+    loop {
+        let i = states.len();
+        if let Some(start) = seen.insert(key(&s), i) {
+            states.push(s);
+            return (
+                Cycle {
+                    start,
+                    length: i - start,
+                },
+                states,
+            );
+        }
+        let next = step(&s);
+        states.push(s);
+        s = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 1009
+    }
+
+    #[test]
+    fn states() {
+        let (cycle, states) = find_cycle(3, step);
+        assert!(cycle.start > 0 && cycle.length > 1);
+        assert_eq!(states.len(), cycle.start + cycle.length + 1);
+        assert_eq!(states[cycle.start], states[cycle.start + cycle.length]);
+
+        let mut x = 3;
+        for n in 0..500 {
+            assert_eq!(states[cycle.equivalent_step(n)], x, "step {n}");
+            x = step(&x);
+        }
+    }
+
+    #[test]
+    fn extrapolate() {
+        // A running total of the states, keyed on the state alone.
+        let (cycle, states) =
+            find_cycle_by_key((3, 0), |&(x, t)| (step(&x), t + x as usize), |s| s.0);
+        let mut s = (3, 0);
+        for n in 0..500 {
+            assert_eq!(cycle.extrapolate(|i| states[i].1, n), s.1, "step {n}");
+            s = (step(&s.0), s.1 + s.0 as usize);
+        }
+    }
+}
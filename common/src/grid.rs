@@ -0,0 +1,491 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Coords, Direction, ParseError, ParseResult};
+
+const NEIGHBOURS4: [Coords; 4] = [
+    Coords::new(-1, 0),
+    Coords::new(1, 0),
+    Coords::new(0, -1),
+    Coords::new(0, 1),
+];
+
+const NEIGHBOURS8: [Coords; 8] = [
+    Coords::new(-1, -1),
+    Coords::new(0, -1),
+    Coords::new(1, -1),
+    Coords::new(-1, 0),
+    Coords::new(1, 0),
+    Coords::new(-1, 1),
+    Coords::new(0, 1),
+    Coords::new(1, 1),
+];
+
+/// Rectangular grid stored row by row.
+///
+/// Indexing with `grid[p]` panics outside of the grid, `get`/`get_mut` return `None` instead.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub width: i64,
+    pub height: i64,
+    pub tiles: Vec<T>,
+}
+
+impl<T> fmt::Display for Grid<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.rows().try_for_each(|row| {
+            row.iter().try_for_each(|t| write!(f, "{}", t))?;
+            writeln!(f)
+        })
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(grid: Vec<Vec<T>>) -> Grid<T> {
+        let width = grid.first().map_or(0, |r| r.len()) as i64;
+        let height = grid.len() as i64;
+        assert!(
+            grid.iter().all(|r| r.len() as i64 == width),
+            "All rows of a grid need to have the same length"
+        );
+        let tiles = grid.into_iter().flatten().collect::<Vec<_>>();
+        Grid {
+            width,
+            height,
+            tiles,
+        }
+    }
+}
+
+impl<T> Index<Coords> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Coords) -> &T {
+        assert!(self.is_valid(p), "{:?} is outside of the grid", p);
+        &self.tiles[self.idx(p)]
+    }
+}
+
+impl<T> IndexMut<Coords> for Grid<T> {
+    fn index_mut(&mut self, p: Coords) -> &mut T {
+        assert!(self.is_valid(p), "{:?} is outside of the grid", p);
+        let i = self.idx(p);
+        &mut self.tiles[i]
+    }
+}
+
+impl Grid<char> {
+    /// A grid of the characters of `lines`, which must all be as long as the first.
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> ParseResult<Grid<char>> {
+        Grid::parse_with(lines, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: i64, height: i64, default: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            tiles: vec![default; (width * height) as usize],
+        }
+    }
+
+    pub fn from_fn(width: i64, height: i64, mut f: impl FnMut(Coords) -> T) -> Grid<T> {
+        let tiles = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coords::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Like `parse`, mapping each character with `f`, which returns `None`
+    /// for characters that cannot be part of the grid.
+    pub fn parse_with<S: AsRef<str>>(
+        lines: &[S],
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<Grid<T>> {
+        let width = lines.first().map_or(0, |l| l.as_ref().chars().count());
+        let rows = lines
+            .iter()
+            .map(|l| {
+                let l = l.as_ref();
+                let row = l
+                    .char_indices()
+                    .map(|(i, c)| {
+                        f(c).ok_or_else(|| {
+                            ParseError::new(&l[i..i + c.len_utf8()], "unexpected character")
+                        })
+                    })
+                    .collect::<ParseResult<Vec<_>>>()?;
+                if row.len() != width {
+                    return Err(ParseError::new(l, format!("expected {width} tiles")));
+                }
+                Ok(row)
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok(Grid::from(rows))
+    }
+
+    fn idx(&self, p: Coords) -> usize {
+        (p.y * self.width + p.x) as usize
+    }
+
+    fn coords_of(&self, i: usize) -> Coords {
+        Coords::new(i as i64 % self.width, i as i64 / self.width)
+    }
+
+    pub fn is_valid(&self, p: Coords) -> bool {
+        p.x >= 0 && p.x < self.width && p.y >= 0 && p.y < self.height
+    }
+
+    pub fn get(&self, p: Coords) -> Option<&T> {
+        self.is_valid(p).then(|| &self.tiles[self.idx(p)])
+    }
+
+    pub fn get_mut(&mut self, p: Coords) -> Option<&mut T> {
+        if self.is_valid(p) {
+            let i = self.idx(p);
+            Some(&mut self.tiles[i])
+        } else {
+            None
+        }
+    }
+
+    /// All coordinates of the grid, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coords> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coords::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coords, &T)> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (self.coords_of(i), t))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            tiles: self.tiles.iter().map(f).collect(),
+        }
+    }
+
+    pub fn step(&self, p: Coords, dir: Direction) -> Option<Coords> {
+        let p = p + dir.to_coords();
+        self.is_valid(p).then_some(p)
+    }
+
+    /// Horizontally and vertically adjacent coordinates that lie within the grid.
+    pub fn neighbours4(&self, p: Coords) -> impl Iterator<Item = Coords> + '_ {
+        NEIGHBOURS4
+            .iter()
+            .map(move |&d| p + d)
+            .filter(|&n| self.is_valid(n))
+    }
+
+    /// Like `neighbours4`, but including the diagonals.
+    pub fn neighbours8(&self, p: Coords) -> impl Iterator<Item = Coords> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .map(move |&d| p + d)
+            .filter(|&n| self.is_valid(n))
+    }
+
+    /// Coordinates visited when walking from `p` (exclusive) in steps of `d` until leaving the grid.
+    pub fn ray(&self, p: Coords, d: Coords) -> impl Iterator<Item = Coords> + '_ {
+        assert_ne!(d, Coords::new(0, 0));
+        (1..)
+            .map(move |i| p + d * i)
+            .take_while(|&n| self.is_valid(n))
+    }
+
+    pub fn row(&self, y: i64) -> &[T] {
+        assert!(
+            y >= 0 && y < self.height,
+            "row {} is outside of the grid",
+            y
+        );
+        &self.tiles[(y * self.width) as usize..((y + 1) * self.width) as usize]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.tiles.chunks(self.width.max(1) as usize)
+    }
+
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> {
+        assert!(
+            x >= 0 && x < self.width,
+            "column {} is outside of the grid",
+            x
+        );
+        self.tiles
+            .iter()
+            .skip(x as usize)
+            .step_by(self.width as usize)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals running down and to the right, starting with the one in the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (1..self.height)
+            .rev()
+            .map(|y| Coords::new(0, y))
+            .chain((0..self.width).map(|x| Coords::new(x, 0)));
+        starts.map(|s| self.line(s, Coords::new(1, 1)))
+    }
+
+    /// Diagonals running down and to the left, starting with the one in the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| Coords::new(x, 0))
+            .chain((1..self.height).map(|y| Coords::new(self.width - 1, y)));
+        starts.map(|s| self.line(s, Coords::new(-1, 1)))
+    }
+
+    fn line(&self, start: Coords, d: Coords) -> impl Iterator<Item = &T> {
+        std::iter::once(start)
+            .chain(self.ray(start, d))
+            .map(|p| &self[p])
+    }
+
+    pub fn find(&self, t: T) -> Option<Coords>
+    where
+        T: PartialEq,
+    {
+        self.tiles
+            .iter()
+            .position(|x| *x == t)
+            .map(|i| self.coords_of(i))
+    }
+
+    pub fn find_all(&self, t: T) -> impl Iterator<Item = Coords> + '_
+    where
+        T: PartialEq,
+    {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(move |(_, x)| **x == t)
+            .map(|(i, _)| self.coords_of(i))
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Coords::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90° clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Coords::new(p.y, self.height - 1 - p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90° counter-clockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Coords::new(self.width - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    /// Mirrors the grid along its vertical axis, swapping left and right.
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Coords::new(self.width - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors the grid along its horizontal axis, swapping top and bottom.
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Coords::new(p.x, self.height - 1 - p.y)].clone()
+        })
+    }
+
+    pub fn as_torus(&self) -> Torus<'_, T> {
+        Torus { grid: self }
+    }
+}
+
+/// A grid repeated infinitely in all directions.
+///
+/// Every coordinate is valid, it maps back onto the underlying grid by wrapping around its edges.
+#[derive(Clone, Copy)]
+pub struct Torus<'a, T> {
+    pub grid: &'a Grid<T>,
+}
+
+impl<T> Torus<'_, T> {
+    /// Coordinates within the underlying grid.
+    pub fn wrap(&self, p: Coords) -> Coords {
+        Coords::new(
+            p.x.rem_euclid(self.grid.width),
+            p.y.rem_euclid(self.grid.height),
+        )
+    }
+
+    /// Which copy of the underlying grid `p` lies in, `(0, 0)` being the original.
+    pub fn tile_of(&self, p: Coords) -> Coords {
+        Coords::new(
+            p.x.div_euclid(self.grid.width),
+            p.y.div_euclid(self.grid.height),
+        )
+    }
+
+    pub fn neighbours4(&self, p: Coords) -> impl Iterator<Item = Coords> {
+        NEIGHBOURS4.into_iter().map(move |d| p + d)
+    }
+
+    pub fn neighbours8(&self, p: Coords) -> impl Iterator<Item = Coords> {
+        NEIGHBOURS8.into_iter().map(move |d| p + d)
+    }
+}
+
+impl<T> Index<Coords> for Torus<'_, T> {
+    type Output = T;
+
+    fn index(&self, p: Coords) -> &T {
+        &self.grid[self.wrap(p)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(&["abc", "def"]).unwrap()
+    }
+
+    fn to_string<'a>(iter: impl Iterator<Item = &'a char>) -> String {
+        iter.collect()
+    }
+
+    #[test]
+    fn access() {
+        let mut g = sample();
+        assert_eq!((g.width, g.height), (3, 2));
+        assert_eq!(g[Coords::new(2, 1)], 'f');
+        assert_eq!(g.get(Coords::new(3, 0)), None);
+        assert_eq!(g.get(Coords::new(0, -1)), None);
+        g[Coords::new(0, 1)] = 'x';
+        *g.get_mut(Coords::new(1, 1)).unwrap() = 'y';
+        assert_eq!(g.to_string(), "abc\nxyf\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Grid::parse(&["abc", "de"]).unwrap_err();
+        assert_eq!(e.to_string(), "expected 3 tiles, found `de`");
+        let digits = Grid::parse_with(&["12", "3x"], |c| c.to_digit(10));
+        assert_eq!(digits.unwrap_err().text, "x");
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let g = sample();
+        let _ = g[Coords::new(-1, 0)];
+    }
+
+    #[test]
+    fn neighbours() {
+        let g = sample();
+        let n4 = g.neighbours4(Coords::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(n4, vec![Coords::new(1, 0), Coords::new(0, 1)]);
+        assert_eq!(g.neighbours8(Coords::new(1, 0)).count(), 5);
+        assert_eq!(g.neighbours8(Coords::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn find() {
+        let g = Grid::parse(&["a.a", ".a."]).unwrap();
+        assert_eq!(g.find('a'), Some(Coords::new(0, 0)));
+        assert_eq!(g.find('b'), None);
+        assert_eq!(
+            g.find_all('a').collect::<Vec<_>>(),
+            vec![Coords::new(0, 0), Coords::new(2, 0), Coords::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let g = sample();
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+        assert_eq!(to_string(g.column(2)), "cf");
+        assert_eq!(
+            g.columns().map(to_string).collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(
+            g.diagonals().map(to_string).collect::<Vec<_>>(),
+            vec!["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            g.anti_diagonals().map(to_string).collect::<Vec<_>>(),
+            vec!["a", "bd", "ce", "f"]
+        );
+        assert_eq!(
+            g.ray(Coords::new(0, 0), Coords::new(1, 0))
+                .collect::<Vec<_>>(),
+            vec![Coords::new(1, 0), Coords::new(2, 0)]
+        );
+    }
+
+    #[test]
+    fn transformations() {
+        let g = sample();
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(g.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(g.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(
+            g.rotate_cw().rotate_cw(),
+            g.flip_horizontal().flip_vertical()
+        );
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+    }
+
+    #[test]
+    fn torus() {
+        let g = sample();
+        let t = g.as_torus();
+        assert_eq!(t[Coords::new(-1, 0)], 'c');
+        assert_eq!(t[Coords::new(4, 3)], 'e');
+        assert_eq!(t.wrap(Coords::new(-4, -1)), Coords::new(2, 1));
+        assert_eq!(t.tile_of(Coords::new(-4, 2)), Coords::new(-2, 1));
+        assert_eq!(t.neighbours4(Coords::new(0, 0)).count(), 4);
+    }
+}
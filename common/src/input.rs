@@ -0,0 +1,112 @@
+//! Splitting puzzle inputs into the pieces the days parse, without copying:
+//! every piece is a slice of the input, so a `ParseError` about it points at
+//! its place in the input.
+//!
+//! Lines come from `str::lines`. On top of it, `InputExt` gives the blocks of
+//! lines separated by blank lines, the integers in a text and grids.
+
+use std::str::FromStr;
+
+use crate::{Grid, ParseError, ParseExt, ParseResult};
+
+pub trait InputExt<'a> {
+    /// The blocks of lines separated by blank lines, without the newlines
+    /// around them.
+    fn paragraphs(self) -> impl Iterator<Item = &'a str>;
+
+    /// The `N` blocks of an input made of that many, such as the rules and the
+    /// updates of 2024 day 5.
+    fn split_blocks<const N: usize>(self) -> ParseResult<[&'a str; N]>;
+
+    /// Every integer in the text, with its sign, such as the `-3` and `12` of
+    /// `p=-3,12`. A `-` between two numbers, as in `1-3`, is a separator.
+    fn ints<T: FromStr>(self) -> impl Iterator<Item = ParseResult<T>>;
+
+    /// A grid of the characters of the lines, see `Grid::parse`.
+    fn grid(self) -> ParseResult<Grid<char>>;
+
+    /// Like `grid`, mapping each character with `f`, see `Grid::parse_with`.
+    fn grid_with<T>(self, f: impl FnMut(char) -> Option<T>) -> ParseResult<Grid<T>>;
+}
+
+impl<'a> InputExt<'a> for &'a str {
+    fn paragraphs(self) -> impl Iterator<Item = &'a str> {
+        self.split("\n\n")
+            .map(|p| p.trim_matches('\n'))
+            .filter(|p| !p.is_empty())
+    }
+
+    fn split_blocks<const N: usize>(self) -> ParseResult<[&'a str; N]> {
+        let blocks = self.paragraphs().collect::<Vec<_>>();
+        let expected = format!("expected {N} blocks separated by blank lines");
+        match blocks.len() {
+            n if n < N => Err(ParseError::after(self.trim_end(), expected)),
+            n if n > N => Err(ParseError::new(blocks[N], expected)),
+            _ => Ok(blocks.try_into().unwrap()),
+        }
+    }
+
+    fn ints<T: FromStr>(self) -> impl Iterator<Item = ParseResult<T>> {
+        let bytes = self.as_bytes();
+        let mut i = 0;
+        std::iter::from_fn(move || {
+            let start = (i..bytes.len()).find(|&j| {
+                bytes[j].is_ascii_digit()
+                    || bytes[j] == b'-'
+                        && bytes.get(j + 1).is_some_and(u8::is_ascii_digit)
+                        && (j == 0 || !bytes[j - 1].is_ascii_alphanumeric())
+            })?;
+            let end = (start + 1..bytes.len())
+                .find(|&j| !bytes[j].is_ascii_digit())
+                .unwrap_or(bytes.len());
+            i = end;
+            Some(self[start..end].parse_as())
+        })
+    }
+
+    fn grid(self) -> ParseResult<Grid<char>> {
+        Grid::parse(&self.lines().collect::<Vec<_>>())
+    }
+
+    fn grid_with<T>(self, f: impl FnMut(char) -> Option<T>) -> ParseResult<Grid<T>> {
+        Grid::parse_with(&self.lines().collect::<Vec<_>>(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paragraphs() {
+        let input = "a\nb\n\nc\n\n\nd\n";
+        assert_eq!(input.paragraphs().collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+        assert_eq!(input.split_blocks::<3>().unwrap(), ["a\nb", "c", "d"]);
+
+        let e = input.split_blocks::<2>().unwrap_err().locate(input);
+        assert_eq!(e.text, "d");
+        let e = input.split_blocks::<4>().unwrap_err().locate(input);
+        assert_eq!((e.text.as_str(), e.location.unwrap().line), ("", 7));
+    }
+
+    #[test]
+    fn ints() {
+        let ints = |s: &str| s.ints().collect::<ParseResult<Vec<i64>>>().unwrap();
+        assert_eq!(ints("p=-3,12 v=7,-42"), [-3, 12, 7, -42]);
+        assert_eq!(ints("1-3 a: abc"), [1, 3]);
+        assert_eq!(ints("Button A: X+94, Y+34"), [94, 34]);
+        assert_eq!(ints("-"), []);
+        let e = "1 -2".ints::<u32>().nth(1).unwrap().unwrap_err();
+        assert_eq!(e.text, "-2");
+    }
+
+    #[test]
+    fn grid() {
+        let g = "ab\ncd\n".grid().unwrap();
+        assert_eq!(
+            (g.width, g.height, g[crate::Coords::new(1, 1)]),
+            (2, 2, 'd')
+        );
+        assert!("12\n3x\n".grid_with(|c| c.to_digit(10)).is_err());
+    }
+}
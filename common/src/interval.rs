@@ -0,0 +1,413 @@
+//! Integer intervals, sets of them and piecewise-linear maps between them.
+//!
+//! Intervals are half-open, `start..end`, so adjacent intervals share a bound
+//! and lengths are plain differences. Puzzles giving inclusive ranges convert
+//! them with `Interval::inclusive`.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// Integer types usable as interval bounds, with `Default` as zero.
+pub trait Bound: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {
+    const ONE: Self;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {
+        $(
+            impl Bound for $t {
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_bound!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The integers from `start` up to, but not including, `end`. Empty when
+/// `end <= start`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+impl<T: Bound> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// The integers from `first` to `last`, both included.
+    pub fn inclusive(first: T, last: T) -> Self {
+        Interval::new(first, last + T::ONE)
+    }
+
+    /// The last integer of a non-empty interval.
+    pub fn last(&self) -> T {
+        self.end - T::ONE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Number of integers in the interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// Whether `other` lies entirely within this interval.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        other.is_empty() || self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The integers in both intervals, possibly an empty interval.
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Splits into the integers below `x` and those from `x` on, either of
+    /// which may be empty.
+    pub fn split_at(&self, x: T) -> (Interval<T>, Interval<T>) {
+        (
+            Interval::new(self.start, self.end.min(x)),
+            Interval::new(self.start.max(x), self.end),
+        )
+    }
+}
+
+/// A set of integers, kept as sorted, disjoint and non-adjacent intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// The intervals of the set, in increasing order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> T {
+        self.iter().fold(T::default(), |acc, i| acc + i.len())
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= x);
+        self.intervals.get(i).is_some_and(|i| i.contains(x))
+    }
+
+    /// Adds the integers of `interval`, merging it with the intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last].iter().fold(interval, |m, i| {
+            Interval::new(m.start.min(i.start), m.end.max(i.end))
+        });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut r = self.clone();
+        for i in other.iter() {
+            r.insert(i);
+        }
+        r
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let i = x.intersection(y);
+            if !i.is_empty() {
+                intervals.push(i);
+            }
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The integers of this set which are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let mut others = other.iter().peekable();
+        for mut i in self.iter() {
+            while let Some(o) = others.peek() {
+                if o.end <= i.start {
+                    others.next();
+                    continue;
+                }
+                if o.start >= i.end {
+                    break;
+                }
+                let (below, _) = i.split_at(o.start);
+                if !below.is_empty() {
+                    intervals.push(below);
+                }
+                i = i.split_at(o.end).1;
+                if i.is_empty() {
+                    break;
+                }
+                others.next();
+            }
+            if !i.is_empty() {
+                intervals.push(i);
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+/// A map sending each integer of a source interval to the integer at the same
+/// offset in its destination interval, and every other integer to itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PiecewiseLinearMap<T> {
+    /// Source intervals, sorted and disjoint, with where they start mapping to.
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Bound> PiecewiseLinearMap<T> {
+    pub fn new() -> Self {
+        PiecewiseLinearMap { pieces: vec![] }
+    }
+
+    /// Maps `source` onto the interval of the same length starting at `dest`.
+    /// Returns whether it was added, which it is not when `source` overlaps
+    /// an interval already in the map.
+    pub fn insert(&mut self, source: Interval<T>, dest: T) -> bool {
+        let i = self.pieces.partition_point(|(s, _)| s.end <= source.start);
+        if self.pieces.get(i).is_some_and(|(s, _)| s.overlaps(&source)) {
+            return false;
+        }
+        if !source.is_empty() {
+            self.pieces.insert(i, (source, dest));
+        }
+        true
+    }
+
+    /// The source intervals with where they start mapping to, in increasing order.
+    pub fn pieces(&self) -> &[(Interval<T>, T)] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, x: T) -> T {
+        let i = self.pieces.partition_point(|(s, _)| s.end <= x);
+        match self.pieces.get(i) {
+            Some(&(s, dest)) if s.contains(x) => x - s.start + dest,
+            _ => x,
+        }
+    }
+
+    /// The parts `interval` splits into along the pieces of the map, each
+    /// with where it starts mapping to.
+    fn split(&self, interval: Interval<T>) -> Vec<(Interval<T>, T)> {
+        let mut r = vec![];
+        let mut rest = interval;
+        let first = self
+            .pieces
+            .partition_point(|(s, _)| s.end <= interval.start);
+        for &(s, dest) in &self.pieces[first..] {
+            if rest.is_empty() || s.start >= rest.end {
+                break;
+            }
+            let (identity, from) = rest.split_at(s.start);
+            if !identity.is_empty() {
+                r.push((identity, identity.start));
+            }
+            let (mapped, after) = from.split_at(s.end);
+            r.push((mapped, mapped.start - s.start + dest));
+            rest = after;
+        }
+        if !rest.is_empty() {
+            r.push((rest, rest.start));
+        }
+        r
+    }
+
+    /// The integers `set` is mapped to.
+    pub fn image(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.iter()
+            .flat_map(|i| self.split(i))
+            .map(|(i, dest)| Interval::new(dest, dest + i.len()))
+            .collect()
+    }
+
+    /// The map applying this one, then `next`.
+    pub fn then(&self, next: &PiecewiseLinearMap<T>) -> PiecewiseLinearMap<T> {
+        // Outside of all the source intervals both maps are the identity.
+        let bounds = self.pieces.iter().chain(&next.pieces).map(|(s, _)| *s);
+        let Some(start) = bounds.clone().map(|s| s.start).min() else {
+            return PiecewiseLinearMap::new();
+        };
+        let end = bounds.map(|s| s.end).max().unwrap();
+
+        let mut r = PiecewiseLinearMap::new();
+        for (source, dest) in self.split(Interval::new(start, end)) {
+            let image = Interval::new(dest, dest + source.len());
+            for (part, dest2) in next.split(image) {
+                let part_source = source.start + (part.start - dest);
+                if dest2 != part_source {
+                    r.push(Interval::new(part_source, part_source + part.len()), dest2);
+                }
+            }
+        }
+        r
+    }
+
+    /// Appends a piece after all the others, merging it with the last one
+    /// when it continues it.
+    fn push(&mut self, source: Interval<T>, dest: T) {
+        if let Some((last, last_dest)) = self.pieces.last_mut() {
+            if last.end == source.start && *last_dest + last.len() == dest {
+                last.end = source.end;
+                return;
+            }
+        }
+        self.pieces.push((source, dest));
+    }
+}
+
+/// Panics if two of the source intervals overlap.
+impl<T: Bound> FromIterator<(Interval<T>, T)> for PiecewiseLinearMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, T)>>(iter: I) -> Self {
+        let mut map = PiecewiseLinearMap::new();
+        for (source, dest) in iter {
+            assert!(map.insert(source, dest), "overlapping source intervals");
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(s, e)| Interval::new(s, e))
+            .collect()
+    }
+
+    #[test]
+    fn intervals() {
+        let i = Interval::inclusive(2, 4);
+        assert_eq!(i, Interval::new(2, 5));
+        assert_eq!((i.len(), i.last()), (3, 4));
+        assert!(i.contains(4) && !i.contains(5));
+        assert!(i.contains_interval(&Interval::new(3, 5)));
+        assert!(!i.contains_interval(&Interval::new(3, 6)));
+        assert!(i.overlaps(&Interval::new(4, 9)));
+        assert!(!i.overlaps(&Interval::new(5, 9)));
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert_eq!(i.split_at(3), (Interval::new(2, 3), Interval::new(3, 5)));
+        assert!(i.split_at(1).0.is_empty());
+    }
+
+    #[test]
+    fn sets() {
+        let a = set(&[(5, 8), (0, 2), (2, 3), (10, 12)]);
+        assert_eq!(a.intervals(), set(&[(0, 3), (5, 8), (10, 12)]).intervals());
+        assert_eq!(a.len(), 8);
+        assert!(a.contains(7) && !a.contains(8) && !a.contains(-1));
+
+        let mut b = a.clone();
+        b.insert(Interval::new(3, 10));
+        assert_eq!(b, set(&[(0, 12)]));
+
+        let c = set(&[(1, 6), (11, 20)]);
+        assert_eq!(a.union(&c), set(&[(0, 8), (10, 20)]));
+        assert_eq!(a.intersection(&c), set(&[(1, 3), (5, 6), (11, 12)]));
+        assert_eq!(a.difference(&c), set(&[(0, 1), (6, 8), (10, 11)]));
+        assert_eq!(c.difference(&a), set(&[(3, 5), (12, 20)]));
+        assert!(a.difference(&set(&[(-5, 15)])).is_empty());
+    }
+
+    #[test]
+    fn maps() {
+        // The seed-to-soil and soil-to-fertilizer maps of 2023 day 5.
+        let m1 = [(98, 50, 2), (50, 52, 48)];
+        let m2 = [(15, 0, 37), (52, 37, 2), (0, 39, 15)];
+        let to_map = |m: &[(u64, u64, u64)]| {
+            m.iter()
+                .map(|&(s, d, l)| (Interval::new(s, s + l), d))
+                .collect::<PiecewiseLinearMap<u64>>()
+        };
+        let (m1, m2) = (to_map(&m1), to_map(&m2));
+        assert_eq!(m1.apply(79), 81);
+        assert_eq!(m1.apply(99), 51);
+        assert_eq!(m1.apply(100), 100);
+
+        let m = m1.then(&m2);
+        for x in 0..=110 {
+            assert_eq!(m.apply(x), m2.apply(m1.apply(x)), "{x}");
+        }
+
+        let seeds = IntervalSet::from(Interval::new(95, 105));
+        let expected = (95..105)
+            .map(|x| Interval::new(m.apply(x), m.apply(x) + 1))
+            .collect::<IntervalSet<_>>();
+        assert_eq!(m.image(&seeds), expected);
+        assert_eq!(m1.then(&PiecewiseLinearMap::new()), m1);
+
+        let mut m3 = m1.clone();
+        assert!(!m3.insert(Interval::new(40, 51), 0));
+        assert!(m3.insert(Interval::new(40, 50), 0));
+    }
+}
@@ -0,0 +1,31 @@
+pub mod answer;
+pub mod cycle;
+pub mod direction;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod memo;
+pub mod number;
+pub mod ocr;
+pub mod parallel;
+pub mod params;
+pub mod parse;
+pub mod render;
+pub mod scan;
+pub mod search;
+pub mod solution;
+pub mod vector;
+
+pub use answer::Answer;
+pub use direction::Direction;
+pub use grid::{Grid, Torus};
+pub use input::InputExt;
+pub use interval::{Interval, IntervalSet, PiecewiseLinearMap};
+pub use number::Rational;
+pub use params::Params;
+pub use parse::{Location, ParseError, ParseExt, ParseResult};
+pub use solution::Solution;
+pub use vector::{Int, Vec2, Vec3};
+
+/// Integer position on a grid, with `x` growing to the right and `y` growing down.
+pub type Coords = Vec2<i64>;
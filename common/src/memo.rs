@@ -0,0 +1,119 @@
+//! Memoisation of recursive functions, such as the ones counting arrangements.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Function computing the value for a key, making its recursive calls
+/// through the function it is given, see `Memo::call`.
+pub type Recursive<'a, Q, V> = dyn Fn(&mut dyn FnMut(&Q) -> V, &Q) -> V + 'a;
+
+/// Cache of the values of a function, counting how often it was hit.
+///
+/// Keys are looked up by reference, so a `Memo<String, _>` takes `&str` keys
+/// and only allocates the ones it stores.
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value cached for `key`, or else the value `compute` returns, which
+    /// is cached. `compute` gets the memo back, for recursive calls.
+    pub fn get_or_insert_with<Q>(&mut self, key: &Q, compute: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(v) = self.cache.get(key) {
+            self.hits += 1;
+            return v.clone();
+        }
+        self.misses += 1;
+        let v = compute(self);
+        self.cache.insert(key.to_owned(), v.clone());
+        v
+    }
+
+    /// Calls `f` on `key` through the cache. As a closure cannot call itself,
+    /// `f` gets a function making its recursive calls through the cache too.
+    pub fn call<Q>(&mut self, key: &Q, f: &Recursive<Q, V>) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.get_or_insert_with(key, |memo| f(&mut |k| memo.call(k, f), key))
+    }
+
+    /// Number of calls answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of calls computing their value.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        memo.get_or_insert_with(&n, |memo| {
+            if n < 2 {
+                n
+            } else {
+                fibonacci(n - 1, memo) + fibonacci(n - 2, memo)
+            }
+        })
+    }
+
+    #[test]
+    fn recursive_fn() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(90, &mut memo), 2880067194370816120);
+        assert_eq!((memo.misses(), memo.hits(), memo.len()), (91, 88, 91));
+        assert_eq!(fibonacci(50, &mut memo), 12586269025);
+        assert_eq!(memo.hits(), 89);
+    }
+
+    #[test]
+    fn closure() {
+        // Ways of splitting a string into pieces of one or two characters.
+        let mut memo = Memo::<String, u64>::new();
+        let splits = |split: &mut dyn FnMut(&str) -> u64, s: &str| match s.len() {
+            0 | 1 => 1,
+            _ => split(&s[1..]) + split(&s[2..]),
+        };
+        assert_eq!(memo.call("abcdefghij", &splits), 89);
+        assert_eq!(memo.misses(), 11);
+        assert_eq!(memo.call("cdefghij", &splits), 34);
+        assert_eq!(memo.misses(), 11);
+    }
+}
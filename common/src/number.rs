@@ -0,0 +1,278 @@
+//! Number theory: divisors, congruences and exact fractions.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::Int;
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple, always non-negative.
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        T::ZERO
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd<T: Int>(a: T, b: T) -> (T, T, T) {
+    if b == T::ZERO {
+        let s = if a < T::ZERO { T::NEG_ONE } else { T::ONE };
+        (a * s, s, T::ZERO)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// `a` modulo `m`, between 0 and `|m|`.
+pub fn modulo<T: Int>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::ZERO {
+        r + m.abs()
+    } else {
+        r
+    }
+}
+
+/// The `x` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse<T: Int>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(modulo(a, m), m);
+    (g == T::ONE).then(|| modulo(x, m))
+}
+
+/// Solves the system `x ≡ r (mod m)` for every `(r, m)` of `congruences`,
+/// returning the smallest non-negative solution and the modulus all
+/// solutions share, their lcm. The moduli need not be coprime, in which case
+/// there may be no solution.
+pub fn crt<T: Int>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), (r2, m2)| {
+            let (g, p, _) = extended_gcd(m1, m2);
+            let d = r2 - r1;
+            if d % g != T::ZERO {
+                return None;
+            }
+            // x = r1 + m1 * k, with m1 * k ≡ d (mod m2).
+            let m2g = m2 / g;
+            let k = modulo(modulo(d / g, m2g) * modulo(p, m2g), m2g);
+            let m = m1 / g * m2;
+            Some((modulo(r1 + m1 * k, m), m.abs()))
+        })
+}
+
+/// The smallest `x ≥ lower` with `x ≡ r (mod m)`.
+pub fn smallest_at_least<T: Int>(r: T, m: T, lower: T) -> T {
+    lower + modulo(r - lower, m)
+}
+
+/// Exact fraction, always in lowest terms with a positive denominator.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Int> Rational<T> {
+    pub fn new(numer: T, denom: T) -> Rational<T> {
+        assert!(denom != T::ZERO, "denominator of zero");
+        let g = gcd(numer, denom) * denom.signum();
+        Rational {
+            numer: numer / g,
+            denom: denom / g,
+        }
+    }
+
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::ONE
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numer)
+    }
+}
+
+impl<T: Int> From<T> for Rational<T> {
+    fn from(n: T) -> Rational<T> {
+        Rational {
+            numer: n,
+            denom: T::ONE,
+        }
+    }
+}
+
+impl<T: Int> fmt::Debug for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<T: Int> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<T: Int> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl<T: Int> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Int> Add for Rational<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let g = gcd(self.denom, rhs.denom);
+        Rational::new(
+            self.numer * (rhs.denom / g) + rhs.numer * (self.denom / g),
+            self.denom / g * rhs.denom,
+        )
+    }
+}
+
+impl<T: Int> Sub for Rational<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<T: Int> Mul for Rational<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Cross-reducing first keeps the products small.
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        if g1 == T::ZERO || g2 == T::ZERO {
+            return Rational::from(T::ZERO);
+        }
+        Rational::new(
+            (self.numer / g1) * (rhs.numer / g2),
+            (self.denom / g2) * (rhs.denom / g1),
+        )
+    }
+}
+
+impl<T: Int> Div for Rational<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.numer != T::ZERO, "division by zero");
+        self * Rational::new(rhs.denom, rhs.numer)
+    }
+}
+
+impl<T: Int> Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisors() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, -5), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        for a in -30..30i64 {
+            for b in -30..30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(g, gcd(a, b), "gcd({a}, {b})");
+                assert_eq!(a * x + b * y, g, "bezout({a}, {b})");
+            }
+        }
+
+        assert_eq!(modulo(-7, 3), 2);
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(3, 4), (4, 6)]), None);
+        assert_eq!(crt::<i64>([]), Some((0, 1)));
+        assert_eq!(smallest_at_least(2, 5, 10), 12);
+        assert_eq!(smallest_at_least(2, 5, 12), 12);
+        assert_eq!(smallest_at_least(-3, 5, -10), -8);
+
+        // Every pair of small congruences, against the brute-force answer.
+        for m1 in 1..12i64 {
+            for m2 in 1..12 {
+                for r1 in 0..m1 {
+                    for r2 in -m2..m2 {
+                        let expected = (0..m1 * m2)
+                            .find(|x| modulo(x - r1, m1) == 0 && modulo(x - r2, m2) == 0)
+                            .map(|x| (x, lcm(m1, m2)));
+                        assert_eq!(crt([(r1, m1), (r2, m2)]), expected, "{r1} {m1} {r2} {m2}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rationals() {
+        let r = |n, d| Rational::<i128>::new(n, d);
+        assert_eq!(r(2, -4), r(-1, 2));
+        assert_eq!(r(-1, 2).numer(), -1);
+        assert_eq!(r(-1, 2).denom(), 2);
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(2, 3) * r(3, 4), r(1, 2));
+        assert_eq!(r(0, 3) * r(3, 4), r(0, 1));
+        assert_eq!(r(2, 3) / r(-4, 3), r(-1, 2));
+        assert!(r(1, 3) < r(1, 2) && r(-1, 2) < r(-1, 3));
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(3, 6).to_integer(), None);
+        assert_eq!(Rational::from(7).to_string(), "7");
+        assert_eq!(r(3, -6).to_string(), "-1/2");
+    }
+}
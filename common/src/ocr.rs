@@ -0,0 +1,148 @@
+//! Reading the letters some puzzles draw on a screen instead of printing them.
+//!
+//! Two fonts show up: letters 6 pixels high, 4 wide (3 for `I`, 5 for `Y`),
+//! and letters 10 pixels high, 6 wide. Letters are separated by at least one blank column.
+
+use itertools::Itertools;
+
+use crate::{Coords, Grid};
+
+const SMALL: &[(char, &[&str])] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[rustfmt::skip]
+const LARGE: &[(char, &[&str])] = &[
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Reads the letters drawn by the lit pixels of `screen`, or `None` if they
+/// aren't all letters of the same known font.
+pub fn read(screen: &Grid<bool>) -> Option<String> {
+    let lit = |x: i64, y: i64| screen[Coords::new(x, y)];
+    let rows = (0..screen.height)
+        .filter(|&y| screen.row(y).contains(&true))
+        .collect::<Vec<_>>();
+    let (&top, &bottom) = (rows.first()?, rows.last()?);
+    let font = match bottom - top + 1 {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return None,
+    };
+
+    let lit_columns = (0..screen.width).map(|x| (x, (top..=bottom).any(|y| lit(x, y))));
+    lit_columns
+        .chunk_by(|&(_, lit)| lit)
+        .into_iter()
+        .filter(|&(lit, _)| lit)
+        .map(|(_, columns)| {
+            let columns = columns.map(|(x, _)| x).collect::<Vec<_>>();
+            let glyph = (top..=bottom).map(|y| {
+                columns
+                    .iter()
+                    .map(|&x| if lit(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            });
+            let glyph = glyph.collect::<Vec<_>>();
+            font.iter()
+                .find(|(_, g)| g.iter().eq(glyph.iter()))
+                .map(|&(c, _)| c)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(lines: &[String]) -> Grid<bool> {
+        Grid::parse(lines).unwrap().map(|&c| c == '#')
+    }
+
+    /// Draws every letter of `font`, separated by `gap` blank columns.
+    fn alphabet(font: &[(char, &[&str])], gap: usize) -> (String, Vec<String>) {
+        let letters = font.iter().map(|&(c, _)| c).collect();
+        let height = font[0].1.len();
+        let lines = (0..height)
+            .map(|y| font.iter().map(|(_, g)| g[y]).join(&".".repeat(gap)))
+            .map(|l| format!("..{l}.."))
+            .collect();
+        (letters, lines)
+    }
+
+    #[test]
+    fn fonts() {
+        for (font, gap) in [(SMALL, 1), (LARGE, 2)] {
+            let (letters, lines) = alphabet(font, gap);
+            assert_eq!(read(&screen(&lines)), Some(letters));
+        }
+    }
+
+    #[test]
+    fn screens() {
+        let lines = [
+            "####.#..#.####.####.####.#..#..##..####.",
+            "#....#..#....#.#.......#.#..#.#..#....#.",
+            "###..####...#..###....#..####.#......#..",
+            "#....#..#..#...#.....#...#..#.#.....#...",
+            "#....#..#.#....#....#....#..#.#..#.#....",
+            "####.#..#.####.#....####.#..#..##..####.",
+        ]
+        .map(String::from);
+        assert_eq!(read(&screen(&lines)), Some("EHZFZHCZ".to_string()));
+
+        let mut blank = lines.to_vec();
+        blank.insert(0, ".".repeat(40));
+        blank.push(".".repeat(40));
+        assert_eq!(read(&screen(&blank)), Some("EHZFZHCZ".to_string()));
+
+        let mut unknown = lines.to_vec();
+        unknown[0].replace_range(0..1, ".");
+        assert_eq!(read(&screen(&unknown)), None);
+        assert_eq!(read(&screen(&lines[..5])), None);
+        assert_eq!(read(&screen(&[".....".to_string()])), None);
+
+        // `I` is narrower than the letters around it.
+        let narrow = [
+            ".###..###...##..",
+            "..#...#..#.#..#.",
+            "..#...#..#.#....",
+            "..#...###..#....",
+            "..#...#....#..#.",
+            ".###..#.....##..",
+        ]
+        .map(String::from);
+        assert_eq!(read(&screen(&narrow)), Some("IPC".to_string()));
+    }
+}
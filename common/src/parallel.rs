@@ -0,0 +1,77 @@
+//! Iteration over independent items, in parallel with the `parallel` feature.
+//!
+//! Without the feature, `maybe_par_iter` is a plain `iter`, so the answers
+//! and the order of any side effects stay deterministic by default. The
+//! adapters the days use, such as `map`, `filter`, `sum` or `partition`,
+//! exist on both kinds of iterator, once `prelude` is imported.
+
+/// `MaybeParallel`, and the rayon traits behind the adapters with the
+/// `parallel` feature.
+pub mod prelude {
+    pub use super::MaybeParallel;
+    #[cfg(feature = "parallel")]
+    pub use rayon::prelude::*;
+}
+
+/// Whether the crate was built with the `parallel` feature.
+pub const ENABLED: bool = cfg!(feature = "parallel");
+
+/// Iterates over a slice on all cores with the `parallel` feature, serially
+/// without.
+pub trait MaybeParallel<'a> {
+    type Iter;
+
+    fn maybe_par_iter(&'a self) -> Self::Iter;
+}
+
+#[cfg(feature = "parallel")]
+impl<'a, T: Sync + 'a> MaybeParallel<'a> for [T] {
+    type Iter = rayon::slice::Iter<'a, T>;
+
+    fn maybe_par_iter(&'a self) -> Self::Iter {
+        rayon::prelude::IntoParallelRefIterator::par_iter(self)
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<'a, T: 'a> MaybeParallel<'a> for [T] {
+    type Iter = std::slice::Iter<'a, T>;
+
+    fn maybe_par_iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+/// Runs `f` with a single thread for the parallel iterators, as a serial
+/// baseline to compare against.
+#[cfg(feature = "parallel")]
+pub fn serially<R: Send>(f: impl FnOnce() -> R + Send) -> R {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .expect("cannot start a thread pool")
+        .install(f)
+}
+
+/// Runs `f`, which is serial already without the `parallel` feature.
+#[cfg(not(feature = "parallel"))]
+pub fn serially<R: Send>(f: impl FnOnce() -> R + Send) -> R {
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+    use super::*;
+
+    #[test]
+    fn same_results() {
+        let items = (1..=1000u64).collect::<Vec<_>>();
+        let squares = |items: &[u64]| items.maybe_par_iter().map(|x| x * x).collect::<Vec<_>>();
+        let expected = items.iter().map(|x| x * x).collect::<Vec<_>>();
+        assert_eq!(squares(&items), expected);
+        assert_eq!(serially(|| squares(&items)), expected);
+        let (even, odd): (Vec<u64>, Vec<u64>) = items.maybe_par_iter().partition(|&x| x % 2 == 0);
+        assert_eq!((even.len(), odd[..3].to_vec()), (500, vec![1, 3, 5]));
+    }
+}
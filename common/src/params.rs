@@ -0,0 +1,156 @@
+//! Puzzle parameters, values such as a grid size or a number of steps which
+//! the puzzle text gives separately for the examples and for the real input.
+//!
+//! A day with parameters declares them with the `params!` macro, which
+//! defaults them to the values of the real input. The runner overrides them
+//! from `name=value` pairs, given with `aoc run --param` or in the `.params`
+//! file next to an example.
+
+use std::str::FromStr;
+
+use crate::parse::describe;
+
+/// `name=value` pairs overriding the default parameters.
+pub type Overrides = [(String, String)];
+
+/// A set of named parameters with defaults, see `params!`.
+pub trait Params: Default {
+    /// Names of the parameters, in declaration order.
+    const NAMES: &'static [&'static str];
+
+    /// Sets the parameter `name` from its textual `value`.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// The defaults, with the `name=value` pairs of `overrides` applied in order.
+    fn with_overrides(overrides: &Overrides) -> Result<Self, String> {
+        let mut params = Self::default();
+        for (name, value) in overrides {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// No parameters, for the days whose puzzles have none.
+impl Params for () {
+    const NAMES: &'static [&'static str] = &[];
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter `{name}`, this puzzle has none"))
+    }
+}
+
+/// Splits a `name=value` pair, as given on the command line or in a `.params` file.
+pub fn parse_override(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected `<name>=<value>`, found `{s}`"))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+/// Parses the `value` of parameter `name`, for `params!`.
+#[doc(hidden)]
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| {
+        format!(
+            "parameter `{name}`: expected {}, found `{value}`",
+            describe::<T>()
+        )
+    })
+}
+
+/// Declares a `Params` struct whose fields have the given defaults:
+///
+/// ```
+/// aoc_common::params! {
+///     pub struct Params {
+///         /// Row to count the covered positions of
+///         pub row: i32 = 2000000,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                pub $field:ident: $ty:ty = $default:expr,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: $ty,
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::Params for $name {
+            const NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                match name {
+                    $(stringify!($field) => self.$field = $crate::params::parse_value(name, value)?,)*
+                    _ => {
+                        return Err(format!(
+                            "unknown parameter `{name}`, expected one of `{}`",
+                            <Self as $crate::Params>::NAMES.join("`, `")
+                        ))
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    params! {
+        pub struct Test {
+            pub size: i64 = 71,
+            pub bytes: usize = 1024,
+        }
+    }
+
+    #[test]
+    fn overrides() {
+        assert_eq!(Test::NAMES, ["size", "bytes"]);
+        let overrides = [parse_override("size=7").unwrap()];
+        let params = Test::with_overrides(&overrides).unwrap();
+        assert_eq!(
+            params,
+            Test {
+                size: 7,
+                bytes: 1024
+            }
+        );
+
+        let e = Test::with_overrides(&[parse_override("bytes = -1").unwrap()]).unwrap_err();
+        assert_eq!(
+            e,
+            "parameter `bytes`: expected an unsigned number, found `-1`"
+        );
+        let e = Test::default().set("steps", "6").unwrap_err();
+        assert_eq!(
+            e,
+            "unknown parameter `steps`, expected one of `size`, `bytes`"
+        );
+        assert!(<()>::with_overrides(&overrides).is_err());
+        assert!(parse_override("size").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! Errors in puzzle inputs, and helpers to report them.
+//!
+//! A `ParseError` keeps the offending text, which should be a slice of the
+//! input being parsed. The runner then finds its line and column from where
+//! that slice sits in the input (see `ParseError::locate`), so the parsers
+//! don't need to keep track of positions themselves.
+
+use std::any::type_name;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A 1-based position in the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// The offending text, empty when something is missing at the end of the input.
+    pub text: String,
+    /// Where `text` starts in the input, once located.
+    pub location: Option<Location>,
+    /// Address of `text` in the input it was sliced from.
+    addr: usize,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    /// An error about `text`, which should be a slice of the input.
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            text: text.to_string(),
+            location: None,
+            addr: text.as_ptr() as usize,
+        }
+    }
+
+    /// An error about something missing after `text`, e.g. a truncated line.
+    pub fn after(text: &str, message: impl Into<String>) -> Self {
+        ParseError::new(&text[text.len()..], message)
+    }
+
+    /// Finds where the offending text is in `input`, the whole input given to
+    /// the parser. Text which was copied out of the input is searched for
+    /// instead, and stays unlocated when it cannot be found.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = if (start..=start + input.len()).contains(&self.addr) {
+            Some(self.addr - start)
+        } else if !self.text.is_empty() {
+            input.find(&self.text)
+        } else {
+            None
+        };
+        self.location = offset.filter(|&o| input.is_char_boundary(o)).map(|o| {
+            let before = &input[..o];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            Location {
+                line: before.matches('\n').count() + 1,
+                column: before[line_start..].chars().count() + 1,
+            }
+        });
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(Location { line, column }) = self.location {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        write!(f, "{}", self.message)?;
+        let text = self.text.lines().next().unwrap_or("");
+        match text.char_indices().nth(40) {
+            _ if text.is_empty() => Ok(()),
+            Some((i, _)) => write!(f, ", found `{}…`", &text[..i]),
+            None => write!(f, ", found `{text}`"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Fallible counterparts of `str` methods, failing with a `ParseError` which
+/// points at the string.
+pub trait ParseExt<'a> {
+    /// Parses the whole string with `FromStr`.
+    fn parse_as<T: FromStr>(self) -> ParseResult<T>;
+    fn split_once_or_err(self, delimiter: &str) -> ParseResult<(&'a str, &'a str)>;
+    fn strip_prefix_or_err(self, prefix: &str) -> ParseResult<&'a str>;
+    fn strip_suffix_or_err(self, suffix: &str) -> ParseResult<&'a str>;
+}
+
+impl<'a> ParseExt<'a> for &'a str {
+    fn parse_as<T: FromStr>(self) -> ParseResult<T> {
+        self.parse()
+            .map_err(|_| ParseError::new(self, format!("expected {}", describe::<T>())))
+    }
+
+    fn split_once_or_err(self, delimiter: &str) -> ParseResult<(&'a str, &'a str)> {
+        self.split_once(delimiter).ok_or_else(|| {
+            ParseError::new(self, format!("expected `{}`", delimiter.escape_debug()))
+        })
+    }
+
+    fn strip_prefix_or_err(self, prefix: &str) -> ParseResult<&'a str> {
+        self.strip_prefix(prefix)
+            .ok_or_else(|| ParseError::new(self, format!("expected `{}`", prefix.escape_debug())))
+    }
+
+    fn strip_suffix_or_err(self, suffix: &str) -> ParseResult<&'a str> {
+        self.strip_suffix(suffix)
+            .ok_or_else(|| ParseError::after(self, format!("expected `{}`", suffix.escape_debug())))
+    }
+}
+
+/// Names of the types `parse_as` is used with, as they read in an error.
+pub(crate) fn describe<T>() -> String {
+    let type_name = type_name::<T>().rsplit("::").next().unwrap();
+    match type_name {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => "an unsigned number".to_string(),
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "f32" | "f64" => "a number".to_string(),
+        "char" => "a single character".to_string(),
+        _ => format!("a {type_name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate() {
+        let input = "1,2\n3,x\n";
+        let (_, y) = input
+            .lines()
+            .nth(1)
+            .unwrap()
+            .split_once_or_err(",")
+            .unwrap();
+        let e = y.parse_as::<u32>().unwrap_err().locate(input);
+        assert_eq!(e.location, Some(Location { line: 2, column: 3 }));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: expected an unsigned number, found `x`"
+        );
+
+        // A missing suffix is reported right after the text.
+        let truncated = "1,2\n3,x";
+        let e = truncated.strip_suffix_or_err("\n").unwrap_err();
+        assert_eq!(e.text, "");
+        assert_eq!(
+            e.locate(truncated).location,
+            Some(Location { line: 2, column: 4 })
+        );
+
+        // Text copied out of the input is searched for.
+        let e = ParseError::new(&String::from("x"), "unknown").locate(input);
+        assert_eq!(e.location, Some(Location { line: 2, column: 3 }));
+
+        let e = "ab; c".split_once_or_err(":").unwrap_err();
+        assert_eq!(e.to_string(), "expected `:`, found `ab; c`");
+    }
+}
@@ -0,0 +1,270 @@
+//! Rendering of simulation states into image frames, to watch a day at work.
+//!
+//! Days draw a `Frame` at each step with `record`, which only draws it while
+//! the runner records this thread, see `start` and `stop`. The frames are
+//! then written as an animated GIF or as one PPM image each, or replayed in a
+//! terminal.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io::{self, Write};
+
+use crate::{Coords, Grid};
+
+/// Colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// Image of a simulation state, each cell holding an index into its palette.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+    palette: Vec<Rgb>,
+}
+
+impl Frame {
+    /// Draws a frame by picking the palette index of each cell `(x, y)`.
+    pub fn new(
+        width: usize,
+        height: usize,
+        palette: &[Rgb],
+        mut cell: impl FnMut(usize, usize) -> u8,
+    ) -> Frame {
+        assert!(
+            !palette.is_empty() && palette.len() <= 256,
+            "a palette has between 1 and 256 colours"
+        );
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect::<Vec<_>>();
+        assert!(
+            cells.iter().all(|&c| (c as usize) < palette.len()),
+            "cells use colours outside of the palette"
+        );
+        Frame {
+            width,
+            height,
+            cells,
+            palette: palette.to_vec(),
+        }
+    }
+
+    /// Draws the tiles of `grid`, picking the palette index of each from its
+    /// position and value.
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        palette: &[Rgb],
+        mut cell: impl FnMut(Coords, &T) -> u8,
+    ) -> Frame {
+        Frame::new(
+            grid.width as usize,
+            grid.height as usize,
+            palette,
+            |x, y| {
+                let p = Coords::new(x as i64, y as i64);
+                cell(p, &grid[p])
+            },
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Palette indices of the pixels, each cell drawn as a `scale`×`scale` square.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        (0..self.height * scale)
+            .flat_map(|y| (0..self.width * scale).map(move |x| (x / scale, y / scale)))
+            .map(|(x, y)| self.cells[y * self.width + x])
+            .collect()
+    }
+
+    /// Writes the frame as a binary PPM image.
+    pub fn write_ppm(&self, scale: usize, mut w: impl Write) -> io::Result<()> {
+        write!(
+            w,
+            "P6\n{} {}\n255\n",
+            self.width * scale,
+            self.height * scale
+        )?;
+        let rgb = self
+            .pixels(scale)
+            .into_iter()
+            .flat_map(|c| self.palette[c as usize])
+            .collect::<Vec<_>>();
+        w.write_all(&rgb)
+    }
+
+    /// Writes the frame for a true-colour terminal, two rows of cells per line
+    /// of half blocks, cropped to `columns`×`lines` characters. Lines end with
+    /// `\r\n` to also show right in raw mode.
+    pub fn write_ansi(&self, columns: usize, lines: usize, mut w: impl Write) -> io::Result<()> {
+        let rgb = |x: usize, y: usize| {
+            (y < self.height).then(|| self.palette[self.cells[y * self.width + x] as usize])
+        };
+        for y in (0..self.height).step_by(2).take(lines) {
+            let mut last = None;
+            for x in 0..self.width.min(columns) {
+                let colours = (rgb(x, y), rgb(x, y + 1));
+                if last != Some(colours) {
+                    let [r, g, b] = colours.0.unwrap();
+                    write!(w, "\x1b[38;2;{r};{g};{b}m")?;
+                    match colours.1 {
+                        Some([r, g, b]) => write!(w, "\x1b[48;2;{r};{g};{b}m")?,
+                        None => write!(w, "\x1b[49m")?,
+                    }
+                    last = Some(colours);
+                }
+                write!(w, "▀")?;
+            }
+            write!(w, "\x1b[0m\r\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `frames` as a looping animated GIF, showing each for `delay`
+/// hundredths of a second. Smaller frames are drawn in the top-left corner.
+pub fn write_gif(frames: &[Frame], scale: usize, delay: u16, w: impl Write) -> io::Result<()> {
+    let dimension = |d: usize| {
+        u16::try_from(d * scale)
+            .map_err(|_| io::Error::other(format!("a GIF is at most {} pixels wide", u16::MAX)))
+    };
+    let width = dimension(frames.iter().map(Frame::width).max().unwrap_or(0))?;
+    let height = dimension(frames.iter().map(Frame::height).max().unwrap_or(0))?;
+    let mut encoder = gif::Encoder::new(w, width, height, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for frame in frames {
+        let gif_frame = gif::Frame {
+            width: dimension(frame.width)?,
+            height: dimension(frame.height)?,
+            delay,
+            dispose: gif::DisposalMethod::Background,
+            palette: Some(frame.palette.concat()),
+            buffer: Cow::Owned(frame.pixels(scale)),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+struct Recording {
+    every: usize,
+    steps: usize,
+    frames: Vec<Frame>,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Starts recording the frames of this thread, keeping one in `every`.
+pub fn start(every: usize) {
+    assert!(every > 0, "expected to keep at least every frame");
+    RECORDING.with_borrow_mut(|r| {
+        *r = Some(Recording {
+            every,
+            steps: 0,
+            frames: vec![],
+        })
+    });
+}
+
+/// Stops recording, returning the frames recorded since `start`.
+pub fn stop() -> Vec<Frame> {
+    RECORDING
+        .with_borrow_mut(Option::take)
+        .map_or(vec![], |r| r.frames)
+}
+
+/// Records the frame drawn by `draw`, which is only called when the frame is
+/// kept, so that drawing costs nothing outside of a recording.
+pub fn record(draw: impl FnOnce() -> Frame) {
+    RECORDING.with_borrow_mut(|r| {
+        if let Some(r) = r {
+            if r.steps % r.every == 0 {
+                r.frames.push(draw());
+            }
+            r.steps += 1;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PALETTE: &[Rgb] = &[[0, 0, 0], [255, 255, 255]];
+
+    fn checkerboard(n: usize) -> Frame {
+        Frame::new(2, 3, PALETTE, |x, y| ((x + y + n) % 2) as u8)
+    }
+
+    #[test]
+    fn ppm() {
+        let mut out = vec![];
+        checkerboard(0).write_ppm(2, &mut out).unwrap();
+        let (header, pixels) = out.split_at(11);
+        assert_eq!(header, b"P6\n4 6\n255\n");
+        assert_eq!(pixels.len(), 4 * 6 * 3);
+        assert_eq!(
+            &pixels[..12],
+            &[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn ansi() {
+        let mut out = vec![];
+        checkerboard(0).write_ansi(80, 24, &mut out).unwrap();
+        let black_on_white = "\x1b[38;2;0;0;0m\x1b[48;2;255;255;255m▀";
+        let white_on_black = "\x1b[38;2;255;255;255m\x1b[48;2;0;0;0m▀";
+        let last = "\x1b[38;2;0;0;0m\x1b[49m▀\x1b[38;2;255;255;255m\x1b[49m▀";
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("{black_on_white}{white_on_black}\x1b[0m\r\n{last}\x1b[0m\r\n")
+        );
+
+        let mut out = vec![];
+        checkerboard(0).write_ansi(1, 1, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("{black_on_white}\x1b[0m\r\n")
+        );
+    }
+
+    #[test]
+    fn gif() {
+        let mut out = vec![];
+        write_gif(&[checkerboard(0), checkerboard(1)], 3, 10, &mut out).unwrap();
+        assert!(out.starts_with(b"GIF89a"));
+        assert_eq!(&out[6..10], &[6, 0, 9, 0]);
+    }
+
+    #[test]
+    fn recording() {
+        let mut drawn = 0;
+        record(|| unreachable!("not recording"));
+
+        start(3);
+        for n in 0..10 {
+            record(|| {
+                drawn += 1;
+                checkerboard(n)
+            });
+        }
+        let frames = stop();
+        assert_eq!(drawn, 4);
+        assert_eq!(frames, [0, 3, 6, 9].map(checkerboard));
+        assert!(stop().is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+//! Parsing lines made of fixed text around a few values, in the manner of
+//! `scanf`, with the `scan!` macro.
+//!
+//! The pattern is the line with `{}` in place of each value. A value ends
+//! where the text following it in the pattern next occurs, or at the end of
+//! the line for a value which ends the pattern. Each value is then parsed as
+//! its type, and a mismatch points at the offending text.
+
+use crate::{ParseError, ParseExt, ParseResult};
+
+/// A type `scan!` can parse a value as: the numbers, `char`, `String` and
+/// `&str`, which borrows from the line.
+pub trait Scan<'a>: Sized {
+    fn scan(s: &'a str) -> ParseResult<Self>;
+}
+
+impl<'a> Scan<'a> for &'a str {
+    fn scan(s: &'a str) -> ParseResult<Self> {
+        Ok(s)
+    }
+}
+
+macro_rules! scan_with_from_str {
+    ($($t:ty),*) => {
+        $(
+            impl Scan<'_> for $t {
+                fn scan(s: &str) -> ParseResult<Self> {
+                    s.parse_as()
+                }
+            }
+        )*
+    };
+}
+
+scan_with_from_str!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String);
+
+/// Splits `text` into the `N` values of `pattern`, for `scan!`.
+#[doc(hidden)]
+pub fn values<'a, const N: usize>(text: &'a str, pattern: &str) -> ParseResult<[&'a str; N]> {
+    let literals = pattern.split("{}").collect::<Vec<_>>();
+    assert_eq!(
+        literals.len() - 1,
+        N,
+        "the pattern `{pattern}` should have one `{{}}` per type"
+    );
+    let expected = |literal: &str| format!("expected `{}`", literal.escape_debug());
+    let mut rest = text
+        .strip_prefix(literals[0])
+        .ok_or_else(|| ParseError::new(text, expected(literals[0])))?;
+    let mut values = [""; N];
+    for (value, &literal) in values.iter_mut().zip(&literals[1..]) {
+        let end = match literal {
+            "" => rest.len(),
+            _ => rest
+                .find(literal)
+                .ok_or_else(|| ParseError::new(rest, expected(literal)))?,
+        };
+        *value = &rest[..end];
+        rest = &rest[end + literal.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::new(rest, "expected the end of the line"));
+    }
+    Ok(values)
+}
+
+/// Parses `text` with a pattern, returning a tuple of its values with the
+/// given types:
+///
+/// ```
+/// let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+/// let (sx, sy, bx, by) = aoc_common::scan!(
+///     line,
+///     "Sensor at x={}, y={}: closest beacon is at x={}, y={}" => i32, i32, i32, i32
+/// )?;
+/// assert_eq!((sx, sy, bx, by), (2, -18, -2, 15));
+///
+/// let e = aoc_common::scan!("Valve AA has rate 0", "Valve {} has flow rate={}" => &str, u32);
+/// let e = e.unwrap_err();
+/// assert_eq!(e.to_string(), "expected ` has flow rate=`, found `AA has rate 0`");
+/// # Ok::<(), aoc_common::ParseError>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($text:expr, $pattern:literal => $($t:ty),+ $(,)?) => {
+        $crate::scan::values::<{ [$(stringify!($t)),+].len() }>($text, $pattern).and_then(
+            |values| {
+                let mut values = values.into_iter();
+                Ok(($(<$t as $crate::scan::Scan>::scan(values.next().unwrap())?,)+))
+            },
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn scan() {
+        let line = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore.";
+        let costs = scan!(
+            line,
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore." => u32, u32, u32
+        );
+        assert_eq!(costs, Ok((1, 4, 2)));
+
+        let line = "Valve BB has flow rate=13; tunnels lead to valves CC, AA";
+        let (name, rate, tunnels) =
+            scan!(line, "Valve {} has flow rate={}; {}" => &str, u32, &str).unwrap();
+        assert_eq!(
+            (name, rate, tunnels),
+            ("BB", 13, "tunnels lead to valves CC, AA")
+        );
+
+        let e = scan!("Valve BB has flow rate=x;", "Valve {} has flow rate={};" => &str, u32);
+        assert_eq!(e.unwrap_err().text, "x");
+        let e = scan!("Valve BB and more", "Valve {}" => char);
+        assert_eq!(
+            e.unwrap_err().to_string(),
+            "expected a single character, found `BB and more`"
+        );
+        let e = scan!("x=1, y=2!", "x={}, y={}!?" => i32, i32);
+        assert_eq!(e.unwrap_err().to_string(), "expected `!?`, found `2!`");
+        let e = scan!("x=1!", "x={}" => i32).map(|(x,)| x);
+        assert_eq!(e.unwrap_err().text, "1!");
+        let e = scan!("x=1; y=2;", "x={};" => i32).map(|(x,)| x);
+        assert_eq!(e.unwrap_err().text, " y=2;");
+    }
+}
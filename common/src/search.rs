@@ -0,0 +1,274 @@
+//! Shortest-path searches over any state type.
+//!
+//! The graph is given by a successor function: for BFS it returns the states
+//! one step away, for the weighted searches `(state, cost)` pairs with
+//! non-negative costs. Searches start from several states at once, which
+//! covers puzzles that may begin facing either of two ways.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Costs of the weighted searches, with `Default` as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Breadth-first search for the closest state satisfying `is_goal`, returning
+/// it with its number of steps.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::new();
+    let mut todo = VecDeque::new();
+    for s in starts {
+        if seen.insert(s.clone()) {
+            todo.push_back((s, 0));
+        }
+    }
+    while let Some((s, steps)) = todo.pop_front() {
+        if is_goal(&s) {
+            return Some((s, steps));
+        }
+        for next in successors(&s) {
+            if seen.insert(next.clone()) {
+                todo.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Number of steps to every state reachable from `starts`.
+pub fn bfs_distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut todo = VecDeque::new();
+    for s in starts {
+        if !distances.contains_key(&s) {
+            distances.insert(s.clone(), 0);
+            todo.push_back(s);
+        }
+    }
+    while let Some(s) = todo.pop_front() {
+        let steps = distances[&s] + 1;
+        for next in successors(&s) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps);
+                todo.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's search for the cheapest state satisfying `is_goal`, returning it
+/// with its cost.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search for the cheapest state satisfying `is_goal`, returning it with
+/// its cost. `heuristic` must never overestimate the cost left to a goal.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::new();
+    let mut todo = BinaryHeap::new();
+    for s in starts {
+        costs.insert(s.clone(), C::default());
+        todo.push(Entry {
+            priority: heuristic(&s),
+            cost: C::default(),
+            state: s,
+        });
+    }
+    while let Some(Entry { cost, state, .. }) = todo.pop() {
+        if costs[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some((state, cost));
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if costs.get(&next).is_none_or(|&c| cost < c) {
+                costs.insert(next.clone(), cost);
+                todo.push(Entry {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// The cheapest costs to every state reachable from some start, along with
+/// every way of reaching each of them at that cost.
+pub struct ShortestPaths<S, C> {
+    pub costs: HashMap<S, C>,
+    /// The states a state is reached from on its cheapest paths, none for the starts.
+    pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C> ShortestPaths<S, C> {
+    /// Every state lying on a cheapest path to one of `goals`, the goals included.
+    pub fn states_on_paths(&self, goals: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut found = HashSet::new();
+        let mut todo = goals.into_iter().collect::<Vec<_>>();
+        while let Some(s) = todo.pop() {
+            if let Some(previous) = self.predecessors.get(&s) {
+                todo.extend(previous.iter().filter(|p| !found.contains(*p)).cloned());
+            }
+            found.insert(s);
+        }
+        found
+    }
+}
+
+/// Dijkstra's search of every state reachable from `starts`, keeping all the
+/// cheapest paths.
+pub fn shortest_paths<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::new();
+    let mut predecessors = HashMap::<S, Vec<S>>::new();
+    let mut todo = BinaryHeap::new();
+    for s in starts {
+        costs.insert(s.clone(), C::default());
+        todo.push(Entry {
+            priority: C::default(),
+            cost: C::default(),
+            state: s,
+        });
+    }
+    while let Some(Entry { cost, state, .. }) = todo.pop() {
+        if costs[&state] < cost {
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            match costs.get(&next) {
+                Some(&c) if c < cost => {}
+                Some(&c) if c == cost => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    costs.insert(next.clone(), cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    todo.push(Entry {
+                        priority: cost,
+                        cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+    ShortestPaths {
+        costs,
+        predecessors,
+    }
+}
+
+/// A state waiting in the priority queue, the lowest `priority` first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A diamond 0 -> {1, 2} -> 3, with 3 -> 4 and a costlier shortcut 0 -> 4.
+    fn edges(s: &u32) -> Vec<(u32, u32)> {
+        match s {
+            0 => vec![(1, 1), (2, 1), (4, 5)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn searches() {
+        let next = |s: &u32| edges(s).into_iter().map(|(n, _)| n);
+        assert_eq!(bfs([0], next, |&s| s == 4), Some((4, 1)));
+        assert_eq!(bfs([0], next, |&s| s == 5), None);
+        let distances = bfs_distances([0], next);
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&3], 2);
+
+        assert_eq!(dijkstra([0], edges, |&s| s == 4), Some((4, 3)));
+        assert_eq!(astar([0], edges, |&s| 4 - s, |&s| s == 4), Some((4, 3)));
+        assert_eq!(dijkstra([1, 2], edges, |&s| s == 4), Some((4, 2)));
+    }
+
+    #[test]
+    fn all_shortest_paths() {
+        let paths = shortest_paths([0], edges);
+        assert_eq!(paths.costs[&4], 3);
+        assert_eq!(paths.states_on_paths([4]), HashSet::from([0, 1, 2, 3, 4]));
+        assert_eq!(paths.states_on_paths([1]), HashSet::from([0, 1]));
+    }
+}
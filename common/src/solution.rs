@@ -0,0 +1,33 @@
+use crate::{Answer, Params, ParseResult};
+
+/// A puzzle solution, split into parsing of the raw input and the two parts.
+///
+/// Each day implements this on a unit struct named `Day`, which is what the
+/// `aoc` runner dispatches to.
+pub trait Solution {
+    /// The parsed puzzle input, which may borrow from the input text.
+    type Input<'a>;
+
+    /// Values the puzzle gives separately for the examples and the real input,
+    /// `()` for most days. See the `params` module.
+    type Params: Params;
+
+    /// `false` for puzzles without a second part, such as the last day of each year.
+    const HAS_PART2: bool = true;
+
+    /// Fails on malformed input, see `ParseError` for how to point at the problem.
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>>;
+
+    /// Parses with parameters other than the defaults. Days with parameters
+    /// implement this, and `parse` with the default parameters.
+    fn parse_with<'a>(input: &'a str, _params: &Self::Params) -> ParseResult<Self::Input<'a>> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer>;
+
+    /// Only called when `HAS_PART2` is set.
+    fn part2(_input: &Self::Input<'_>) -> impl Into<Answer> {
+        String::new()
+    }
+}
@@ -0,0 +1,362 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Signed integer types usable as components of `Vec2` and `Vec3`.
+pub trait Int:
+    Copy
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const NEG_ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const NEG_ONE: Self = -1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize);
+
+/// A point or a direction in the plane.
+///
+/// The y axis points down, as it does for the rows of a grid, so `UP` is `(0, -1)`.
+/// The derived ordering compares `x` first, then `y`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point or a direction in space.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec2<U> {
+        Vec2::new(f(self.x), f(self.y))
+    }
+}
+
+impl<T: Int> Vec2<T> {
+    pub const ZERO: Self = Vec2::new(T::ZERO, T::ZERO);
+    pub const UP: Self = Vec2::new(T::ZERO, T::NEG_ONE);
+    pub const DOWN: Self = Vec2::new(T::ZERO, T::ONE);
+    pub const LEFT: Self = Vec2::new(T::NEG_ONE, T::ZERO);
+    pub const RIGHT: Self = Vec2::new(T::ONE, T::ZERO);
+    pub const UP_LEFT: Self = Vec2::new(T::NEG_ONE, T::NEG_ONE);
+    pub const UP_RIGHT: Self = Vec2::new(T::ONE, T::NEG_ONE);
+    pub const DOWN_LEFT: Self = Vec2::new(T::NEG_ONE, T::ONE);
+    pub const DOWN_RIGHT: Self = Vec2::new(T::ONE, T::ONE);
+
+    /// The four cardinal directions, clockwise starting with `UP`.
+    pub const CARDINALS: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+    /// The four diagonal directions, clockwise starting with `UP_RIGHT`.
+    pub const DIAGONALS: [Self; 4] = [
+        Self::UP_RIGHT,
+        Self::DOWN_RIGHT,
+        Self::DOWN_LEFT,
+        Self::UP_LEFT,
+    ];
+    /// All eight neighbouring directions, clockwise starting with `UP`.
+    pub const ADJACENT: [Self; 8] = [
+        Self::UP,
+        Self::UP_RIGHT,
+        Self::RIGHT,
+        Self::DOWN_RIGHT,
+        Self::DOWN,
+        Self::DOWN_LEFT,
+        Self::LEFT,
+        Self::UP_LEFT,
+    ];
+
+    /// Manhattan length of the vector, `|x| + |y|`.
+    pub fn manhattan(self) -> T {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (self - other).manhattan()
+    }
+
+    /// Chebyshev length of the vector, `max(|x|, |y|)`.
+    pub fn chebyshev(self) -> T {
+        self.x.abs().max(self.y.abs())
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        (self - other).chebyshev()
+    }
+
+    /// Rotates by 90° clockwise as seen on screen, e.g. `UP` becomes `RIGHT`.
+    pub fn turn_right(self) -> Self {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90° counter-clockwise as seen on screen, e.g. `UP` becomes `LEFT`.
+    pub fn turn_left(self) -> Self {
+        Vec2::new(self.y, -self.x)
+    }
+
+    pub fn signum(self) -> Self {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec3<U> {
+        Vec3::new(f(self.x), f(self.y), f(self.z))
+    }
+}
+
+impl<T: Int> Vec3<T> {
+    pub const ZERO: Self = Vec3::new(T::ZERO, T::ZERO, T::ZERO);
+    pub const X: Self = Vec3::new(T::ONE, T::ZERO, T::ZERO);
+    pub const Y: Self = Vec3::new(T::ZERO, T::ONE, T::ZERO);
+    pub const Z: Self = Vec3::new(T::ZERO, T::ZERO, T::ONE);
+
+    /// Unit vectors towards the six face-adjacent neighbours.
+    pub const CARDINALS: [Self; 6] = [
+        Self::X,
+        Vec3::new(T::NEG_ONE, T::ZERO, T::ZERO),
+        Self::Y,
+        Vec3::new(T::ZERO, T::NEG_ONE, T::ZERO),
+        Self::Z,
+        Vec3::new(T::ZERO, T::ZERO, T::NEG_ONE),
+    ];
+
+    pub fn manhattan(self) -> T {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (self - other).manhattan()
+    }
+
+    pub fn chebyshev(self) -> T {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        (self - other).chebyshev()
+    }
+
+    pub fn signum(self) -> Self {
+        Vec3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vec3::new(x, y, z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:?}, {:?})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_ops {
+    ($v:ident { $($c:ident),+ }) => {
+        impl<T: Int> Add for $v<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $v { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Int> Sub for $v<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $v { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        /// Component-wise multiplication.
+        impl<T: Int> Mul for $v<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                $v { $($c: self.$c * rhs.$c),+ }
+            }
+        }
+
+        /// Component-wise division.
+        impl<T: Int> Div for $v<T> {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                $v { $($c: self.$c / rhs.$c),+ }
+            }
+        }
+
+        impl<T: Int> Mul<T> for $v<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $v { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: Int> Div<T> for $v<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self {
+                $v { $($c: self.$c / rhs),+ }
+            }
+        }
+
+        impl<T: Int> Neg for $v<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $v { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: Int> AddAssign for $v<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Int> SubAssign for $v<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Int> MulAssign<T> for $v<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+impl_ops!(Vec2 { x, y });
+impl_ops!(Vec3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2::new(3i64, -4);
+        let b = Vec2::new(1, 2);
+        assert_eq!(a + b, Vec2::new(4, -2));
+        assert_eq!(a - b, Vec2::new(2, -6));
+        assert_eq!(a * 2, Vec2::new(6, -8));
+        assert_eq!(a / 2, Vec2::new(1, -2));
+        assert_eq!(a * b, Vec2::new(3, -8));
+        assert_eq!(-a, Vec2::new(-3, 4));
+        let mut c = a;
+        c += b;
+        c -= Vec2::RIGHT;
+        c *= 3;
+        assert_eq!(c, Vec2::new(9, -6));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(3i32, -4);
+        assert_eq!(a.manhattan(), 7);
+        assert_eq!(a.chebyshev(), 4);
+        assert_eq!(a.manhattan_distance(Vec2::new(1, 1)), 7);
+        assert_eq!(a.signum(), Vec2::new(1, -1));
+        let b = Vec3::new(1i64, -2, 3);
+        assert_eq!(b.manhattan(), 6);
+        assert_eq!(b.chebyshev_distance(Vec3::ZERO), 3);
+        assert_eq!((b + Vec3::Z * 2).signum(), Vec3::new(1, -1, 1));
+    }
+
+    #[test]
+    fn rotations() {
+        let d = Vec2::<i64>::UP;
+        assert_eq!(d.turn_right(), Vec2::RIGHT);
+        assert_eq!(d.turn_right().turn_right(), Vec2::DOWN);
+        assert_eq!(d.turn_left(), Vec2::LEFT);
+        for d in Vec2::<i64>::ADJACENT {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), -d);
+        }
+        assert_eq!(
+            Vec2::<i64>::CARDINALS.map(|d| d.turn_right()),
+            [Vec2::RIGHT, Vec2::DOWN, Vec2::LEFT, Vec2::UP]
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(Vec2::new(1, 2).to_string(), "1,2");
+        assert_eq!(format!("{:?}", Vec2::new(1, 2)), "(1, 2)");
+        assert_eq!(Vec3::new(1, 2, 3).to_string(), "1,2,3");
+    }
+}